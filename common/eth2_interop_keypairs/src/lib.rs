@@ -65,7 +65,7 @@ pub fn keypair(validator_index: usize) -> Keypair {
         )
     });
 
-    Keypair::from_components(sk.public_key(), sk)
+    Keypair::from_secret_key(sk)
 }
 
 #[derive(Serialize, Deserialize)]
@@ -101,7 +101,8 @@ impl TryInto<Keypair> for YamlKeypair {
                 .map_err(|e| format!("Failed to decode bytes into public key: {:?}", e))?
         };
 
-        Ok(Keypair::from_components(pk, sk))
+        Keypair::from_components(pk, sk)
+            .map_err(|e| format!("Public key does not match secret key: {:?}", e))
     }
 }
 
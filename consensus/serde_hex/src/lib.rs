@@ -30,6 +30,29 @@ impl<'de> Visitor<'de> for PrefixedHexVisitor {
     }
 }
 
+/// Accepts a hex string either with or without a single `0x` prefix.
+pub struct MaybePrefixedHexVisitor;
+
+impl<'de> Visitor<'de> for MaybePrefixedHexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hex string with optional 0x prefix")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let hex = if value.starts_with("0x") {
+            &value[2..]
+        } else {
+            value
+        };
+        Ok(hex::decode(hex).map_err(|e| de::Error::custom(format!("invalid hex ({:?})", e)))?)
+    }
+}
+
 pub struct HexVisitor;
 
 impl<'de> Visitor<'de> for HexVisitor {
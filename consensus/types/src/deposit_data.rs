@@ -45,6 +45,52 @@ impl DepositData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::generate_deterministic_keypair;
+    use tree_hash::TreeHash;
 
     ssz_and_tree_hash_tests!(DepositData);
+
+    #[test]
+    fn mainnet_deposit_domain() {
+        assert_eq!(
+            hex::encode(ChainSpec::mainnet().get_deposit_domain()),
+            "03000000f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9"
+        );
+    }
+
+    #[test]
+    fn verify_deposit_with_spec_domain() {
+        let spec = ChainSpec::mainnet();
+        let keypair = generate_deterministic_keypair(0);
+        let mut deposit_data = DepositData {
+            pubkey: keypair.pk.clone().into(),
+            withdrawal_credentials: Hash256::repeat_byte(1),
+            amount: spec.max_effective_balance,
+            signature: SignatureBytes::empty(),
+        };
+        deposit_data.signature = deposit_data.create_signature(&keypair.sk, &spec);
+
+        let signature = deposit_data.signature.decompress().unwrap();
+        let deposit_message_root = deposit_data.as_deposit_message().tree_hash_root();
+        assert_eq!(
+            bls::verify_deposit(
+                &keypair.pk,
+                &signature,
+                deposit_message_root,
+                spec.get_deposit_domain()
+            ),
+            Ok(())
+        );
+
+        // Mixing the `genesis_validators_root` into the domain (as for other messages) fails.
+        let domain = spec.compute_domain(
+            Domain::Deposit,
+            spec.genesis_fork_version,
+            Hash256::repeat_byte(1),
+        );
+        assert_eq!(
+            bls::verify_deposit(&keypair.pk, &signature, deposit_message_root, domain),
+            Err(bls::Error::InvalidSignature)
+        );
+    }
 }
@@ -0,0 +1,396 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+
+/// The number of signatures aggregated in each benchmark.
+const SIGNATURE_COUNTS: &[usize] = &[128, 512, 2048];
+
+/// Defines the benchmarks for some BLS backend.
+///
+/// All keys are derived deterministically, so no RNG is involved in any of the benchmarks.
+macro_rules! bench_suite {
+    ($impls: ident) => {
+        use super::*;
+        use bls::$impls::*;
+        use bls::Hash256;
+
+        fn secret_from_u64(i: u64) -> SecretKey {
+            let mut secret_bytes = [0; 32];
+            // Use i + 1 to avoid the all-zeros secret key.
+            secret_bytes[32 - 8..].copy_from_slice(&(i + 1).to_be_bytes());
+            SecretKey::deserialize(&secret_bytes).unwrap()
+        }
+
+        fn signatures(count: usize) -> Vec<Signature> {
+            let msg = Hash256::from_low_u64_be(42);
+            (0..count as u64)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect()
+        }
+
+        pub fn aggregation(c: &mut Criterion) {
+            let mut group = c.benchmark_group(concat!(stringify!($impls), "/aggregation"));
+
+            for &count in SIGNATURE_COUNTS {
+                let signatures = signatures(count);
+                group.throughput(Throughput::Elements(count as u64));
+
+                group.bench_with_input(
+                    BenchmarkId::new("add_assign", count),
+                    &signatures,
+                    |b, signatures| {
+                        b.iter(|| {
+                            let mut aggregate = AggregateSignature::infinity();
+                            for signature in signatures {
+                                aggregate.add_assign(signature);
+                            }
+                            black_box(aggregate)
+                        })
+                    },
+                );
+
+                group.bench_with_input(
+                    BenchmarkId::new("aggregate_into", count),
+                    &signatures,
+                    |b, signatures| {
+                        b.iter_batched(
+                            || signatures.clone(),
+                            |signatures| {
+                                let mut aggregate = AggregateSignature::empty();
+                                for signature in signatures {
+                                    signature.aggregate_into(&mut aggregate);
+                                }
+                                black_box(aggregate)
+                            },
+                            BatchSize::SmallInput,
+                        )
+                    },
+                );
+
+                group.bench_with_input(
+                    BenchmarkId::new("add_assign_multiple", count),
+                    &signatures,
+                    |b, signatures| {
+                        let signature_refs = signatures.iter().collect::<Vec<_>>();
+                        b.iter(|| {
+                            let mut aggregate = AggregateSignature::infinity();
+                            aggregate.add_assign_multiple(&signature_refs);
+                            black_box(aggregate)
+                        })
+                    },
+                );
+
+                group.bench_with_input(
+                    BenchmarkId::new("aggregate", count),
+                    &signatures,
+                    |b, signatures| b.iter(|| black_box(AggregateSignature::aggregate(signatures))),
+                );
+            }
+
+            group.finish();
+        }
+
+        pub fn pre_aggregated_verification(c: &mut Criterion) {
+            let mut group =
+                c.benchmark_group(concat!(stringify!($impls), "/fast_aggregate_verify"));
+            group.sample_size(10);
+
+            let msg = Hash256::from_low_u64_be(42);
+
+            for &count in SIGNATURE_COUNTS {
+                let pubkeys = (0..count as u64)
+                    .map(|i| secret_from_u64(i).public_key())
+                    .collect::<Vec<_>>();
+                let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+                let agg_pubkey = AggregatePublicKey::aggregate(&pubkey_refs).unwrap();
+                let signature = AggregateSignature::aggregate(&signatures(count));
+
+                group.bench_function(BenchmarkId::new("pubkeys", count), |b| {
+                    b.iter(|| black_box(signature.fast_aggregate_verify(msg, &pubkey_refs)))
+                });
+
+                let pubkey_bytes = pubkeys.iter().map(|pk| pk.serialize()).collect::<Vec<_>>();
+                let pubkey_byte_refs = pubkey_bytes.iter().map(|b| &b[..]).collect::<Vec<_>>();
+
+                group.bench_function(BenchmarkId::new("deserialize_then_verify", count), |b| {
+                    b.iter(|| {
+                        let pubkeys = pubkey_byte_refs
+                            .iter()
+                            .map(|bytes| PublicKey::deserialize_checked(bytes).unwrap())
+                            .collect::<Vec<_>>();
+                        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+                        black_box(signature.fast_aggregate_verify(msg, &pubkey_refs))
+                    })
+                });
+
+                group.bench_function(BenchmarkId::new("pubkey_bytes", count), |b| {
+                    b.iter(|| {
+                        black_box(signature.fast_aggregate_verify_bytes(msg, &pubkey_byte_refs))
+                    })
+                });
+
+                group.bench_function(BenchmarkId::new("pre_aggregated", count), |b| {
+                    b.iter(|| {
+                        black_box(signature.fast_aggregate_verify_pre_aggregated(msg, &agg_pubkey))
+                    })
+                });
+            }
+
+            group.finish();
+        }
+
+        pub fn bulk_verification(c: &mut Criterion) {
+            const BULK_COUNT: usize = 1_000;
+
+            let secrets = (0..BULK_COUNT as u64)
+                .map(secret_from_u64)
+                .collect::<Vec<_>>();
+            let pubkeys = secrets.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let committees = pubkeys.iter().map(|pk| vec![pk]).collect::<Vec<_>>();
+            let committee_refs = committees.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            let msgs = (0..BULK_COUNT as u64)
+                .map(Hash256::from_low_u64_be)
+                .collect::<Vec<_>>();
+            let bytes = secrets
+                .iter()
+                .zip(msgs.iter())
+                .flat_map(|(sk, msg)| sk.sign(*msg).serialize().to_vec())
+                .collect::<Vec<_>>();
+
+            let mut group = c.benchmark_group(concat!(stringify!($impls), "/bulk_verification"));
+            group.throughput(Throughput::Elements(BULK_COUNT as u64));
+            group.sample_size(10);
+
+            group.bench_function("decode_then_verify", |b| {
+                b.iter(|| {
+                    for ((bytes, msg), pubkeys) in bytes.chunks(96).zip(&msgs).zip(&committee_refs)
+                    {
+                        let signature = AggregateSignature::deserialize_checked(bytes).unwrap();
+                        assert!(signature.fast_aggregate_verify(*msg, pubkeys));
+                    }
+                })
+            });
+
+            group.bench_function("deserialize_many_and_verify", |b| {
+                b.iter(|| {
+                    black_box(
+                        AggregateSignature::deserialize_many_and_verify(
+                            &bytes,
+                            &msgs,
+                            &committee_refs,
+                        )
+                        .unwrap(),
+                    )
+                })
+            });
+
+            group.finish();
+        }
+
+        pub fn clone_from(c: &mut Criterion) {
+            const CLONE_COUNT: usize = 100_000;
+
+            let source = AggregateSignature::aggregate(&signatures(2));
+
+            let mut group = c.benchmark_group(concat!(stringify!($impls), "/clone_from"));
+            group.throughput(Throughput::Elements(CLONE_COUNT as u64));
+            group.sample_size(10);
+
+            group.bench_function("clone", |b| {
+                let mut scratch = AggregateSignature::infinity();
+                b.iter(|| {
+                    for _ in 0..CLONE_COUNT {
+                        scratch = black_box(&source).clone();
+                    }
+                    black_box(&scratch);
+                })
+            });
+
+            group.bench_function("clone_from", |b| {
+                let mut scratch = AggregateSignature::infinity();
+                b.iter(|| {
+                    for _ in 0..CLONE_COUNT {
+                        scratch.clone_from(black_box(&source));
+                    }
+                    black_box(&scratch);
+                })
+            });
+
+            group.finish();
+        }
+
+        pub fn prepared_verification(c: &mut Criterion) {
+            const VERIFICATION_COUNT: usize = 100;
+            const COMMITTEE_SIZE: usize = 128;
+
+            let msg = Hash256::from_low_u64_be(42);
+            let pubkeys = (0..COMMITTEE_SIZE as u64)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(&signatures(COMMITTEE_SIZE));
+
+            let mut group =
+                c.benchmark_group(concat!(stringify!($impls), "/prepared_verification"));
+            group.throughput(Throughput::Elements(VERIFICATION_COUNT as u64));
+            group.sample_size(10);
+
+            group.bench_function("fast_aggregate_verify", |b| {
+                b.iter(|| {
+                    for _ in 0..VERIFICATION_COUNT {
+                        assert!(signature.fast_aggregate_verify(msg, &pubkey_refs));
+                    }
+                })
+            });
+
+            group.bench_function("fast_aggregate_verify_prepared", |b| {
+                b.iter(|| {
+                    let prepared = bls::PreparedMessage::new(msg, bls::DST);
+                    for _ in 0..VERIFICATION_COUNT {
+                        assert!(signature.fast_aggregate_verify_prepared(&prepared, &pubkey_refs));
+                    }
+                })
+            });
+
+            group.finish();
+        }
+
+        pub fn committee_aggregation(c: &mut Criterion) {
+            const COMMITTEE_COUNT: usize = 64;
+            const COMMITTEE_SIZE: usize = 128;
+
+            let pubkeys = (0..(COMMITTEE_COUNT * COMMITTEE_SIZE) as u64)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+            let committees = pubkeys
+                .chunks(COMMITTEE_SIZE)
+                .map(|committee| committee.iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            let mut group =
+                c.benchmark_group(concat!(stringify!($impls), "/committee_aggregation"));
+            group.throughput(Throughput::Elements(COMMITTEE_COUNT as u64));
+
+            group.bench_function("sequential", |b| {
+                b.iter(|| {
+                    black_box(
+                        committees
+                            .iter()
+                            .map(|committee| AggregatePublicKey::aggregate(committee))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+            });
+
+            group.bench_function("aggregate_many", |b| {
+                b.iter(|| black_box(AggregatePublicKey::aggregate_many(&committees)))
+            });
+
+            group.finish();
+        }
+
+        #[cfg(feature = "pubkey-cache")]
+        pub fn pubkey_cache(c: &mut Criterion) {
+            use std::borrow::Cow;
+
+            let mut group = c.benchmark_group(concat!(stringify!($impls), "/pubkey_cache"));
+
+            for &count in SIGNATURE_COUNTS {
+                let pubkeys = (0..count as u64)
+                    .map(|i| secret_from_u64(i).public_key())
+                    .collect::<Vec<_>>();
+                let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+                let indices = (0..count).collect::<Vec<_>>();
+                let get_pubkey = |i: usize| pubkeys.get(i).map(Cow::Borrowed);
+                let cache = AggregatePublicKeyCache::new(1);
+                cache.get_or_aggregate(&indices, get_pubkey).unwrap();
+                group.throughput(Throughput::Elements(count as u64));
+
+                group.bench_function(BenchmarkId::new("aggregate", count), |b| {
+                    b.iter(|| black_box(AggregatePublicKey::aggregate(&pubkey_refs)))
+                });
+
+                group.bench_function(BenchmarkId::new("cached", count), |b| {
+                    b.iter(|| black_box(cache.get_or_aggregate(&indices, get_pubkey)))
+                });
+            }
+
+            group.finish();
+        }
+    };
+}
+
+mod blst {
+    bench_suite!(blst_implementations);
+
+    /// Decodes the signatures of a replayed chain segment, as they would be read back from the
+    /// database.
+    ///
+    /// Milagro does not support uncompressed signatures, so this is only run against `blst`.
+    pub fn state_replay(c: &mut Criterion) {
+        const REPLAY_COUNT: usize = 1_000;
+
+        let aggregates = signatures(REPLAY_COUNT)
+            .iter()
+            .map(|signature| AggregateSignature::aggregate(&[signature.clone()]))
+            .collect::<Vec<_>>();
+        let bytes = aggregates
+            .iter()
+            .map(|signature| signature.serialize())
+            .collect::<Vec<_>>();
+        let uncompressed_bytes = aggregates
+            .iter()
+            .map(|signature| signature.serialize_uncompressed())
+            .collect::<Vec<_>>();
+
+        let mut group = c.benchmark_group("blst_implementations/state_replay");
+        group.throughput(Throughput::Elements(REPLAY_COUNT as u64));
+        group.sample_size(10);
+
+        group.bench_function("deserialize_checked", |b| {
+            b.iter(|| {
+                for bytes in &bytes {
+                    black_box(AggregateSignature::deserialize_checked(bytes).unwrap());
+                }
+            })
+        });
+
+        group.bench_function("deserialize_uncompressed", |b| {
+            b.iter(|| {
+                for bytes in &uncompressed_bytes {
+                    black_box(AggregateSignature::deserialize_uncompressed(bytes).unwrap());
+                }
+            })
+        });
+
+        group.finish();
+    }
+}
+
+mod milagro {
+    bench_suite!(milagro_implementations);
+}
+
+criterion_group!(
+    benches,
+    blst::aggregation,
+    milagro::aggregation,
+    blst::pre_aggregated_verification,
+    milagro::pre_aggregated_verification,
+    blst::committee_aggregation,
+    milagro::committee_aggregation,
+    blst::bulk_verification,
+    milagro::bulk_verification,
+    blst::prepared_verification,
+    milagro::prepared_verification,
+    blst::state_replay,
+    blst::clone_from,
+    milagro::clone_from
+);
+#[cfg(feature = "pubkey-cache")]
+criterion_group!(pubkey_cache, blst::pubkey_cache, milagro::pubkey_cache);
+
+#[cfg(feature = "pubkey-cache")]
+criterion_main!(benches, pubkey_cache);
+#[cfg(not(feature = "pubkey-cache"))]
+criterion_main!(benches);
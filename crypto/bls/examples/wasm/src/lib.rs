@@ -0,0 +1,25 @@
+//! Verifies BLS signatures from JavaScript, using the `milagro` backend of the `bls` crate.
+//!
+//! Build with:
+//!
+//! ```ignore
+//! wasm-pack build --target web crypto/bls/examples/wasm
+//! ```
+
+use bls::{PublicKey, Signature};
+use wasm_bindgen::prelude::*;
+
+/// Returns `true` if `signature` is a valid signature by `pubkey` across `msg`.
+///
+/// Returns `false` if either `pubkey` or `signature` is not a valid compressed point in the
+/// correct subgroup.
+#[wasm_bindgen]
+pub fn verify(pubkey: &[u8], msg: &[u8], signature: &[u8]) -> bool {
+    match (
+        PublicKey::deserialize_checked(pubkey),
+        Signature::deserialize_checked(signature),
+    ) {
+        (Ok(pubkey), Ok(signature)) => signature.verify(&pubkey, msg),
+        _ => false,
+    }
+}
@@ -0,0 +1,77 @@
+// The `supranational` backend is the default and is overridden by any of the others, and
+// `fake_crypto` overrides `milagro`. `mock_crypto` cannot be combined with either of them.
+#[cfg(all(feature = "milagro", feature = "mock_crypto"))]
+compile_error!(
+    "the `milagro` and `mock_crypto` features of `bls` select conflicting backends; \
+     `mock_crypto` cannot be combined with another backend"
+);
+#[cfg(all(feature = "mock_crypto", feature = "fake_crypto"))]
+compile_error!(
+    "the `mock_crypto` and `fake_crypto` features of `bls` select conflicting backends; \
+     `mock_crypto` cannot be combined with another backend"
+);
+
+/// Describes the BLS backend which was compiled into this crate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackendInfo {
+    /// A short, human-readable name for the backend (e.g., `"blst"`, `"blst-portable"`,
+    /// `"milagro"`, `"fake_crypto"`, `"mock_crypto"`).
+    pub name: &'static str,
+    /// True if `blst` was built in portable mode (i.e., without ADX instructions).
+    pub portable: bool,
+    /// True if the CPU we are *running* on supports the ADX instructions.
+    pub cpu_has_adx: bool,
+    /// True if the CPU we are *running* on supports the SSE2 instructions.
+    pub cpu_has_sse2: bool,
+}
+
+/// Returns information about the BLS backend which was selected at compile time.
+///
+/// The pinned `blst` revision does not expose its own CPU detection, so the `cpu_has_*` fields
+/// are queried from the standard library instead. A non-portable `blst` build running on a CPU
+/// without ADX is likely to crash with an illegal instruction, so these fields are mostly useful
+/// for diagnosing throughput differences between machines.
+pub fn active_backend() -> BackendInfo {
+    BackendInfo {
+        name: enabled_backend(),
+        portable: cfg!(feature = "supranational-portable"),
+        cpu_has_adx: cpu_has_adx(),
+        cpu_has_sse2: cpu_has_sse2(),
+    }
+}
+
+/// Returns the name of the BLS backend which was selected at compile time (i.e.,
+/// `active_backend().name`), without querying the CPU.
+pub fn enabled_backend() -> &'static str {
+    if cfg!(feature = "fake_crypto") {
+        "fake_crypto"
+    } else if cfg!(feature = "milagro") {
+        "milagro"
+    } else if cfg!(feature = "mock_crypto") {
+        "mock_crypto"
+    } else if cfg!(feature = "supranational-portable") {
+        "blst-portable"
+    } else {
+        "blst"
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpu_has_adx() -> bool {
+    std::is_x86_feature_detected!("adx")
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpu_has_adx() -> bool {
+    false
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpu_has_sse2() -> bool {
+    std::is_x86_feature_detected!("sse2")
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpu_has_sse2() -> bool {
+    false
+}
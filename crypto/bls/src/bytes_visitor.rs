@@ -0,0 +1,73 @@
+use serde::de::{self, SeqAccess, Visitor};
+use std::fmt;
+
+#[cfg(feature = "lenient-hex")]
+pub use serde_hex::MaybePrefixedHexVisitor as HexStringVisitor;
+/// The `serde` visitor used for hex strings in human-readable formats (e.g., JSON).
+///
+/// The `0x` prefix is required, unless the `lenient-hex` feature is enabled.
+#[cfg(not(feature = "lenient-hex"))]
+pub use serde_hex::PrefixedHexVisitor as HexStringVisitor;
+
+/// A `serde` visitor which accepts either a hex string (as per `HexStringVisitor`) or an array of
+/// bytes, for signatures in human-readable formats (see `serde_byte_array`).
+pub struct HexOrByteArrayVisitor;
+
+impl<'de> Visitor<'de> for HexOrByteArrayVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hex string or an array of bytes")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        HexStringVisitor.visit_str(value)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        BytesVisitor.visit_seq(seq)
+    }
+}
+
+/// A `serde` visitor which accepts raw bytes, for use with non-human-readable formats (e.g.,
+/// bincode).
+pub struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    generic_signature::{GenericSignature, TSignature},
+    signing_root, Error, Hash256,
+};
+
+/// Returns `Ok(())` if `signature` is a valid deposit signature by `pubkey` across the
+/// `DepositMessage` with `deposit_message_root`.
+///
+/// The `deposit_domain` should be `ChainSpec::get_deposit_domain`, which is computed with the
+/// genesis fork version and *without* a `genesis_validators_root` (mixing it in, as is done for
+/// every other message, would reject valid deposits).
+///
+/// ## Errors
+///
+/// - `Error::EmptySignature` if `signature` is the "empty" value.
+/// - `Error::InvalidSignature` if the signature is invalid.
+pub fn verify_deposit<Pub, Sig>(
+    pubkey: &GenericPublicKey<Pub>,
+    signature: &GenericSignature<Pub, Sig>,
+    deposit_message_root: Hash256,
+    deposit_domain: Hash256,
+) -> Result<(), Error>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey + Clone,
+{
+    pubkey.verify_checked(
+        signature,
+        signing_root(deposit_message_root, deposit_domain),
+    )
+}
@@ -1,11 +1,33 @@
-use crate::{Error, PUBLIC_KEY_BYTES_LEN};
+use crate::{
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    Error, INFINITY_PUBLIC_KEY, PUBLIC_KEY_BYTES_LEN,
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_hex::encode as hex_encode;
+use ssz::{Decode, Encode};
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tree_hash::TreeHash;
 
 /// Implemented on some struct from a BLS library so it may be used internally in this crate.
-pub trait TAggregatePublicKey: Sized + Clone {
+pub trait TAggregatePublicKey<Pub>: Sized + Clone {
     /// Initialize `Self` to the infinity value which can then have other public keys aggregated
     /// upon it.
     fn infinity() -> Self;
 
+    /// Aggregates all of `pubkeys` into a new aggregate public key.
+    ///
+    /// The caller must ensure that `pubkeys` is not empty.
+    fn aggregate(pubkeys: &[&GenericPublicKey<Pub>]) -> Self;
+
+    /// Aggregates `other` onto `self`.
+    fn add_assign_aggregate(&mut self, other: &Self);
+
     /// Serialize `self` as compressed bytes.
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN];
 
@@ -13,6 +35,185 @@ pub trait TAggregatePublicKey: Sized + Clone {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
 }
 
-/*
- * Note: there is no immediate need for a `GenericAggregatePublicKey` struct.
- */
+/// A BLS aggregate public key that is generic across some BLS point (`AggPub`).
+///
+/// Useful for aggregating a fixed set of public keys once (e.g., a committee) and then verifying
+/// many signatures against the result with
+/// `GenericAggregateSignature::fast_aggregate_verify_pre_aggregated`.
+///
+/// Also useful for diagnosing a failed `GenericAggregateSignature::fast_aggregate_verify`, since
+/// the `Display` implementation shows the aggregate of the public keys which were verified.
+#[derive(Clone, PartialEq)]
+pub struct GenericAggregatePublicKey<Pub, AggPub> {
+    /// The underlying point which performs *actual* cryptographic operations.
+    point: AggPub,
+    _phantom: PhantomData<Pub>,
+}
+
+impl<Pub, AggPub> GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    /// Aggregates all of `pubkeys` into a new aggregate public key.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::NoPublicKeys` if `pubkeys` is empty.
+    /// - `Error::InvalidInfinityPublicKey` if any of `pubkeys` is the infinity public key.
+    pub fn aggregate(pubkeys: &[&GenericPublicKey<Pub>]) -> Result<Self, Error> {
+        if pubkeys.is_empty() {
+            Err(Error::NoPublicKeys)
+        } else if pubkeys.iter().any(|pubkey| pubkey.is_infinity) {
+            Err(Error::InvalidInfinityPublicKey)
+        } else {
+            Ok(Self {
+                point: AggPub::aggregate(pubkeys),
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Aggregates each of `committees` independently (e.g., all the committees attesting in a
+    /// block), returning the results in the same order as `committees`.
+    ///
+    /// Each result is as per `Self::aggregate`, so one invalid committee does not affect the
+    /// others. The committees are aggregated in parallel when the `rayon` feature is enabled.
+    pub fn aggregate_many(committees: &[Vec<&GenericPublicKey<Pub>>]) -> Vec<Result<Self, Error>>
+    where
+        Pub: Send + Sync,
+        AggPub: Send,
+    {
+        #[cfg(feature = "rayon")]
+        let iter = committees.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = committees.iter();
+
+        iter.map(|pubkeys| Self::aggregate(pubkeys)).collect()
+    }
+
+    /// Returns a reference to the underlying BLS point.
+    pub(crate) fn point(&self) -> &AggPub {
+        &self.point
+    }
+
+    /// Instantiates `Self` from a `point`, which must not be the point at infinity.
+    pub(crate) fn from_point(point: AggPub) -> Self {
+        Self {
+            point,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Serialize `self` as compressed bytes.
+    pub fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
+        self.point.serialize()
+    }
+
+    /// Deserialize `self` from compressed bytes.
+    ///
+    /// Returns `Error::InvalidInfinityPublicKey` if `bytes` is the `INFINITY_PUBLIC_KEY`.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != PUBLIC_KEY_BYTES_LEN {
+            Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: PUBLIC_KEY_BYTES_LEN,
+            })
+        } else if bytes == &INFINITY_PUBLIC_KEY[..] {
+            Err(Error::InvalidInfinityPublicKey)
+        } else {
+            Ok(Self {
+                point: AggPub::deserialize(bytes)?,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<Pub, AggPub> Encode for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_ssz_encode!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub, AggPub> Decode for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_ssz_decode!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub, AggPub> TreeHash for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_tree_hash!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub, AggPub> Serialize for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_serde_serialize!();
+}
+
+impl<'de, Pub, AggPub> Deserialize<'de> for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_serde_deserialize!();
+}
+
+impl<Pub, AggPub> FromStr for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_from_str!();
+}
+
+impl<Pub, AggPub> TryFrom<&str> for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_try_from_str!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub, AggPub> TryFrom<&[u8]> for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_try_from_slice!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub, AggPub> TryFrom<Vec<u8>> for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_try_from_vec!();
+}
+
+impl<Pub, AggPub> fmt::Debug for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_debug!();
+}
+
+impl<Pub, AggPub> fmt::Display for GenericAggregatePublicKey<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    impl_display!();
+}
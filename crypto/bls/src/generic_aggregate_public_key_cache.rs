@@ -0,0 +1,88 @@
+use crate::{
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    Error,
+};
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::borrow::Cow;
+
+/// An LRU cache of aggregate public keys, keyed by the validator indices of the public keys which
+/// were aggregated.
+///
+/// Useful when the same sets of validators (e.g., committees) are aggregated repeatedly, since a
+/// cache hit only requires sorting the indices rather than reading and aggregating the public keys.
+///
+/// The cache assumes that a validator index always refers to the same public key (as per the
+/// validator registry, which is append-only), so it must not be shared between sources which may
+/// map an index to different keys.
+pub struct GenericAggregatePublicKeyCache<Pub, AggPub> {
+    cache: Mutex<LruCache<Vec<usize>, GenericAggregatePublicKey<Pub, AggPub>>>,
+}
+
+impl<Pub, AggPub> GenericAggregatePublicKeyCache<Pub, AggPub>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    /// Instantiates an empty cache which holds at most `capacity` aggregate public keys, evicting
+    /// the least-recently used when full.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Returns the aggregate of the public keys of `validator_indices`, either from the cache or
+    /// by reading each public key with `get_pubkey` and aggregating them (in which case the result
+    /// is added to the cache).
+    ///
+    /// The order of `validator_indices` does not affect the cache key, since aggregation is
+    /// commutative.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::UnknownValidator` if `get_pubkey` returns `None` for any index.
+    /// - See `GenericAggregatePublicKey::aggregate`.
+    ///
+    /// Errors are not cached.
+    pub fn get_or_aggregate<'a, F>(
+        &self,
+        validator_indices: &[usize],
+        get_pubkey: F,
+    ) -> Result<GenericAggregatePublicKey<Pub, AggPub>, Error>
+    where
+        F: Fn(usize) -> Option<Cow<'a, GenericPublicKey<Pub>>>,
+        Pub: 'a,
+    {
+        let mut key = validator_indices.to_vec();
+        key.sort_unstable();
+
+        if let Some(agg_pubkey) = self.cache.lock().get(&key) {
+            return Ok(agg_pubkey.clone());
+        }
+
+        let pubkeys = validator_indices
+            .iter()
+            .map(|&index| get_pubkey(index).ok_or(Error::UnknownValidator(index)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let pubkey_refs = pubkeys
+            .iter()
+            .map(|pubkey| pubkey.as_ref())
+            .collect::<Vec<_>>();
+
+        let agg_pubkey = GenericAggregatePublicKey::aggregate(&pubkey_refs)?;
+        self.cache.lock().put(key, agg_pubkey.clone());
+        Ok(agg_pubkey)
+    }
+
+    /// Returns the number of aggregate public keys in the cache.
+    pub fn len(&self) -> usize {
+        self.cache.lock().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cache.lock().is_empty()
+    }
+}
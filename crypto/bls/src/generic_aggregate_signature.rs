@@ -1,17 +1,28 @@
 use crate::{
-    generic_aggregate_public_key::TAggregatePublicKey,
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
     generic_public_key::{GenericPublicKey, TPublicKey},
     generic_signature::{GenericSignature, TSignature},
-    Error, Hash256, INFINITY_SIGNATURE, SIGNATURE_BYTES_LEN,
+    generic_verified_signature::GenericVerifiedSignature,
+    Error, Hash256, ParticipationReport, SignableMessage, VerifyReport, INFINITY_SIGNATURE,
+    SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN,
 };
+#[cfg(feature = "supranational")]
+use crate::{PreparedMessage, DST};
+use rand::{CryptoRng, RngCore};
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
-use serde_hex::{encode as hex_encode, PrefixedHexVisitor};
+use serde_hex::encode as hex_encode;
 use ssz::{Decode, Encode};
+use std::convert::TryFrom;
 use std::fmt;
+use std::iter::Sum;
 use std::marker::PhantomData;
+use std::str::FromStr;
 use tree_hash::TreeHash;
 
+/// The number of members in a sync committee, as per the Altair specification.
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+
 /// The compressed bytes used to represent `GenericAggregateSignature::empty()`.
 pub const EMPTY_SIGNATURE_SERIALIZATION: [u8; SIGNATURE_BYTES_LEN] = [0; SIGNATURE_BYTES_LEN];
 
@@ -28,22 +39,141 @@ pub trait TAggregateSignature<Pub, AggPub, Sig>: Sized + Clone {
     /// Aggregates an aggregate signature onto `self`.
     fn add_assign_aggregate(&mut self, other: &Self);
 
+    /// Removes a signature which was previously aggregated onto `self` (i.e., adds its negation).
+    fn sub_assign(&mut self, other: &Sig);
+
+    /// Returns `true` if `self` is the point at infinity.
+    fn is_infinity(&self) -> bool;
+
+    /// Instantiates `Self` from a single signature.
+    ///
+    /// The default implementation aggregates `signature` onto `Self::infinity()`. Backends should
+    /// override it where the point can be converted directly.
+    fn from_signature(signature: Sig) -> Self {
+        let mut aggregate = Self::infinity();
+        aggregate.add_assign(&signature);
+        aggregate
+    }
+
+    /// Converts `self` into a signature with the same point.
+    ///
+    /// The default implementation round-trips through the compressed bytes. Backends should
+    /// override it where the point can be converted directly.
+    fn into_signature(self) -> Sig
+    where
+        Sig: TSignature<Pub>,
+    {
+        Sig::deserialize(&self.serialize()).expect("aggregate signature should be a valid point")
+    }
+
     /// Serialize `self` as compressed bytes.
     fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN];
 
+    /// Writes the compressed bytes of `self` into `out`, which must be exactly `SIGNATURE_BYTES_LEN`
+    /// bytes.
+    ///
+    /// The default implementation copies the result of `Self::serialize`.
+    fn serialize_into(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.serialize())
+    }
+
     /// Deserialize `self` from compressed bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
 
+    /// Deserialize `self` from compressed bytes, ensuring the point is in the G2 subgroup.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error>;
+
     /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed `msg`.
-    fn fast_aggregate_verify(&self, msg: Hash256, pubkeys: &[&GenericPublicKey<Pub>]) -> bool;
+    fn fast_aggregate_verify<M: SignableMessage>(
+        &self,
+        msg: M,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool;
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed the
+    /// message in `prepared`.
+    ///
+    /// Must return `false` unless `prepared` was hashed with `DST`. The default implementation
+    /// hashes the message again (i.e., it does not use the prepared point).
+    #[cfg(feature = "supranational")]
+    fn fast_aggregate_verify_prepared(
+        &self,
+        prepared: &PreparedMessage,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool {
+        prepared.dst() == DST && self.fast_aggregate_verify(prepared.message(), pubkeys)
+    }
 
     /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed their
     /// corresponding message in `msgs`.
+    fn aggregate_verify<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool;
+
+    /// Verify that `self` represents an aggregate signature where all of the public keys in
+    /// `agg_pubkey` have signed `msg`.
+    fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msg: M,
+        agg_pubkey: &GenericAggregatePublicKey<Pub, AggPub>,
+    ) -> bool;
+
+    /// Verify that `self` represents an aggregate signature where all of the public keys in each
+    /// `agg_pubkeys[i]` have signed `msgs[i]`.
+    fn aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        agg_pubkeys: &[&GenericAggregatePublicKey<Pub, AggPub>],
+    ) -> bool;
+
+    /// Verify that each `signatures[i]` represents an aggregate signature where all `pubkeys[i]`
+    /// have signed `msgs[i]`.
     ///
-    /// ## Notes
-    ///
-    /// This function only exists for EF tests, it's presently not used in production.
-    fn aggregate_verify(&self, msgs: &[Hash256], pubkeys: &[&GenericPublicKey<Pub>]) -> bool;
+    /// Performs a single randomized batch verification across all signatures, rather than
+    /// verifying each signature individually.
+    fn fast_aggregate_verify_multiple(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<Pub>]],
+    ) -> bool {
+        Self::fast_aggregate_verify_multiple_with_rng(
+            signatures,
+            msgs,
+            pubkeys,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Equivalent to `Self::fast_aggregate_verify_multiple`, except the random scalars for the
+    /// batch verification are drawn from `rng`.
+    fn fast_aggregate_verify_multiple_with_rng<R: RngCore + CryptoRng>(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<Pub>]],
+        rng: &mut R,
+    ) -> bool;
+
+    /// Equivalent to `Self::fast_aggregate_verify_multiple`, except the public keys for each
+    /// signature have already been aggregated into `agg_pubkeys[i]`.
+    fn fast_aggregate_verify_multiple_pre_aggregated(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        agg_pubkeys: &[&GenericAggregatePublicKey<Pub, AggPub>],
+    ) -> bool;
+}
+
+/// Implemented on some struct from a BLS library which supports serializing the G2 point in
+/// uncompressed form.
+pub trait TAggregateSignatureUncompressed<Pub, AggPub, Sig>:
+    TAggregateSignature<Pub, AggPub, Sig>
+{
+    /// Serialize `self` as uncompressed bytes.
+    fn serialize_uncompressed(&self) -> [u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN];
+
+    /// Deserialize `self` from uncompressed bytes.
+    fn deserialize_uncompressed(bytes: &[u8]) -> Result<Self, Error>;
 }
 
 /// A BLS aggregate signature that is generic across:
@@ -55,7 +185,13 @@ pub trait TAggregateSignature<Pub, AggPub, Sig>: Sized + Clone {
 ///
 /// Provides generic functionality whilst deferring all serious cryptographic operations to the
 /// generics.
-#[derive(Clone, PartialEq)]
+///
+/// ## Equality
+///
+/// `PartialEq` compares the encoding, so `Self::empty()` and `Self::infinity()` are *not* equal
+/// (they serialize as `NONE_SIGNATURE` and `INFINITY_SIGNATURE` respectively). Use
+/// `Self::semantically_eq` to treat them as equal.
+#[derive(PartialEq)]
 pub struct GenericAggregateSignature<Pub, AggPub, Sig, AggSig> {
     /// The underlying point which performs *actual* cryptographic operations.
     point: Option<AggSig>,
@@ -88,6 +224,17 @@ where
     /// This value can have another signature aggregated atop of it. When this happens, `self` is
     /// simply set to infinity before having the other signature aggregated onto it.
     ///
+    /// ## Aggregation
+    ///
+    /// The "empty" value is the identity for aggregation: aggregating it onto `self` is a no-op,
+    /// whilst aggregating anything onto it yields that value. For `add_assign`,
+    /// `add_assign_multiple` and `add_assign_aggregate`:
+    ///
+    /// - `empty + empty = empty`
+    /// - `empty + infinity = infinity`
+    /// - `infinity + empty = infinity`
+    /// - `infinity + infinity = infinity`
+    ///
     /// ## Notes
     ///
     /// This function is not necessarily useful from a BLS cryptography perspective, it mostly
@@ -103,22 +250,100 @@ where
         }
     }
 
+    /// Aggregates all of `signatures` into a new aggregate signature.
+    ///
+    /// Returns `Self::empty()` if `signatures` is empty. Any "empty" signatures are ignored.
+    pub fn aggregate<'a, I>(signatures: I) -> Self
+    where
+        I: IntoIterator<Item = &'a GenericSignature<Pub, Sig>>,
+        Pub: 'a,
+        Sig: 'a,
+    {
+        let mut aggregate = Self::empty();
+        for signature in signatures {
+            aggregate.add_assign(signature);
+        }
+        aggregate
+    }
+
     /// Returns `true` if `self` is equal to the "empty" value.
     ///
     /// E.g., `Self::empty().is_empty() == true`
+    ///
+    /// The "empty" value serializes as `NONE_SIGNATURE` and is *not* the signature at infinity.
     pub fn is_empty(&self) -> bool {
         self.point.is_none()
     }
 
+    /// Returns `true` if `self` is the signature at infinity (i.e., it serializes as
+    /// `INFINITY_SIGNATURE`).
+    ///
+    /// E.g., `Self::infinity().is_infinity() == true`, whilst
+    /// `Self::empty().is_infinity() == false`.
+    pub fn is_infinity(&self) -> bool {
+        self.is_infinity
+    }
+
+    /// Returns `true` if `self` and `other` are equal, treating `Self::empty()` and
+    /// `Self::infinity()` as equal since neither contributes anything when aggregated.
+    ///
+    /// Unlike `PartialEq`, this does not imply that `self` and `other` have the same encoding.
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        let is_identity = |sig: &Self| sig.is_empty() || sig.is_infinity();
+
+        (is_identity(self) && is_identity(other)) || self.serialize()[..] == other.serialize()[..]
+    }
+
     /// Returns a reference to the underlying BLS point.
     pub(crate) fn point(&self) -> Option<&AggSig> {
         self.point.as_ref()
     }
 
+    /// Converts `self` into a signature with the same point, or returns `None` if `self` is the
+    /// "empty" value.
+    ///
+    /// The point does not track how many signatures were aggregated, so the caller is responsible
+    /// for knowing that `self` only contains a single signature. Otherwise, the result is the
+    /// aggregate signature reinterpreted as a signature, which will not verify against any single
+    /// public key.
+    pub fn into_signature(self) -> Option<GenericSignature<Pub, Sig>> {
+        let is_infinity = self.is_infinity;
+        self.point
+            .map(|point| GenericSignature::from_point(point.into_signature(), is_infinity))
+    }
+
+    /// Returns a reference to the underlying BLS point, or `None` if `self` is the "empty" value.
+    ///
+    /// ## Unstable
+    ///
+    /// This exposes the point from the BLS backend (e.g., `blst`) directly. Neither the backend
+    /// types nor this method are covered by any stability guarantees; they may change in any
+    /// release. Only available with the `unstable-internals` feature.
+    #[cfg(feature = "unstable-internals")]
+    pub fn as_raw(&self) -> Option<&AggSig> {
+        self.point()
+    }
+
+    /// Instantiates `Self` from some underlying BLS point.
+    ///
+    /// ## Unstable
+    ///
+    /// See `Self::as_raw`. Only available with the `unstable-internals` feature.
+    #[cfg(feature = "unstable-internals")]
+    pub fn from_raw(point: AggSig) -> Self {
+        Self {
+            is_infinity: point.is_infinity(),
+            point: Some(point),
+            _phantom_pub: PhantomData,
+            _phantom_agg_pub: PhantomData,
+            _phantom_sig: PhantomData,
+        }
+    }
+
     /// Aggregates a signature onto `self`.
     pub fn add_assign(&mut self, other: &GenericSignature<Pub, Sig>) {
         if let Some(other_point) = other.point() {
-            self.is_infinity = self.is_infinity && other.is_infinity;
+            self.is_infinity = (self.is_empty() || self.is_infinity) && other.is_infinity;
             if let Some(self_point) = &mut self.point {
                 self_point.add_assign(other_point)
             } else {
@@ -129,10 +354,51 @@ where
         }
     }
 
+    /// Aggregates a signature onto `self`, consuming it. See `GenericSignature::aggregate_into`.
+    pub(crate) fn add_assign_owned(&mut self, other: GenericSignature<Pub, Sig>) {
+        let other_is_infinity = other.is_infinity;
+        if let Some(other_point) = other.into_point() {
+            self.is_infinity = (self.is_empty() || self.is_infinity) && other_is_infinity;
+            if let Some(self_point) = &mut self.point {
+                self_point.add_assign(&other_point)
+            } else {
+                self.point = Some(AggSig::from_signature(other_point))
+            }
+        }
+    }
+
+    /// Removes `other` from `self`, where `other` was previously aggregated onto `self` (e.g., to
+    /// remove a single contributor without re-aggregating the others).
+    ///
+    /// Has no effect if `other` is the "empty" value. If `self` is the "empty" value, the result
+    /// is the negation of `other`.
+    pub fn sub_assign(&mut self, other: &GenericSignature<Pub, Sig>) {
+        if let Some(other_point) = other.point() {
+            let self_point = self.point.get_or_insert_with(AggSig::infinity);
+            self_point.sub_assign(other_point);
+            self.is_infinity = self_point.is_infinity();
+        }
+    }
+
+    /// Aggregates all of `signatures` onto `self`.
+    ///
+    /// Equivalent to calling `Self::add_assign` with each of `signatures`, except `self` is only
+    /// initialized once. Any "empty" signatures are ignored.
+    pub fn add_assign_multiple(&mut self, signatures: &[&GenericSignature<Pub, Sig>]) {
+        for signature in signatures {
+            if let Some(other_point) = signature.point() {
+                self.is_infinity = (self.is_empty() || self.is_infinity) && signature.is_infinity;
+                self.point
+                    .get_or_insert_with(AggSig::infinity)
+                    .add_assign(other_point);
+            }
+        }
+    }
+
     /// Aggregates an aggregate signature onto `self`.
     pub fn add_assign_aggregate(&mut self, other: &Self) {
         if let Some(other_point) = other.point() {
-            self.is_infinity = self.is_infinity && other.is_infinity;
+            self.is_infinity = (self.is_empty() || self.is_infinity) && other.is_infinity;
             if let Some(self_point) = &mut self.point {
                 self_point.add_assign_aggregate(other_point)
             } else {
@@ -152,12 +418,105 @@ where
         }
     }
 
+    impl_serialize_into!(SIGNATURE_BYTES_LEN);
+
+    /// Writes the compressed bytes of `self` into `out`, which is exactly `SIGNATURE_BYTES_LEN`.
+    fn write_compressed(&self, out: &mut [u8]) {
+        if let Some(point) = &self.point {
+            point.serialize_into(out)
+        } else {
+            out.copy_from_slice(&EMPTY_SIGNATURE_SERIALIZATION)
+        }
+    }
+
+    impl_deserialize_from!(SIGNATURE_BYTES_LEN);
+
     /// Deserialize `self` from compressed bytes.
+    ///
+    /// The point is *not* checked to be in the G2 subgroup. This is the path taken by the SSZ
+    /// `Decode` impl, so it decodes the aggregate signatures which consensus receives from the
+    /// network (i.e., those in `Attestation` and `IndexedAttestation`, within gossip objects and
+    /// blocks). Those callers rely on verification to reject a point outside of G2 (`blst`
+    /// checks the subgroup during the pairing), so the result must not be used (e.g., aggregated
+    /// and republished) until it has been verified.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_with(bytes, AggSig::deserialize)
+    }
+
+    /// Deserialize many signatures which have been concatenated as compressed bytes (i.e.,
+    /// `N * SIGNATURE_BYTES_LEN` bytes), as per `Self::deserialize`.
+    ///
+    /// Returns `Error::InvalidByteLength` if `bytes` is not a multiple of `SIGNATURE_BYTES_LEN`.
+    pub fn deserialize_many(bytes: &[u8]) -> Result<Vec<Self>, Error> {
+        Self::deserialize_many_with(bytes, Self::deserialize)
+    }
+
+    /// Splits `bytes` into `SIGNATURE_BYTES_LEN` chunks and decodes each with `deserialize`.
+    fn deserialize_many_with<F>(bytes: &[u8], deserialize: F) -> Result<Vec<Self>, Error>
+    where
+        F: Fn(&[u8]) -> Result<Self, Error>,
+    {
+        if bytes.len() % SIGNATURE_BYTES_LEN != 0 {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_BYTES_LEN,
+            });
+        }
+
+        bytes.chunks(SIGNATURE_BYTES_LEN).map(deserialize).collect()
+    }
+
+    /// Equivalent to `Self::deserialize_checked`, except the bytes are taken as an array.
+    ///
+    /// `NONE_SIGNATURE` is decoded as `Self::empty()` and `INFINITY_SIGNATURE` as
+    /// `Self::infinity()`.
+    pub fn from_bytes_checked(bytes: [u8; SIGNATURE_BYTES_LEN]) -> Result<Self, Error> {
+        Self::deserialize_checked(&bytes)
+    }
+
+    /// Decodes `bytes` *without* validating the point, for literal test data and constants such
+    /// as `NONE_SIGNATURE` and `INFINITY_SIGNATURE`.
+    ///
+    /// The G2 subgroup check is skipped (as per `Self::deserialize`), so this must never be used
+    /// on bytes from the network or the database. Use `Self::from_bytes_checked` instead.
+    ///
+    /// Returns an error if `bytes` is not the encoding of a point on the curve.
+    pub fn from_bytes_unchecked(bytes: [u8; SIGNATURE_BYTES_LEN]) -> Result<Self, Error> {
+        Self::deserialize(&bytes)
+    }
+
+    /// Deserialize `self` from compressed bytes, returning `Error::InvalidSubgroup` if the point
+    /// is not in the G2 subgroup and `Error::NonCanonicalEncoding` if the bytes are not its
+    /// canonical encoding (see `TSignature::check_encoding`).
+    ///
+    /// For untrusted bytes which may be used before (or without) being verified. Within this
+    /// crate, `Self::from_bytes_checked` and `Self::deserialize_many_and_verify` use it; no
+    /// consensus code currently does, since SSZ decoding goes through `Self::deserialize`.
+    pub fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_with(bytes, |bytes| {
+            Sig::check_encoding(bytes)?;
+            AggSig::deserialize_checked(bytes)
+        })
+    }
+
+    /// Deserialize `self` from compressed bytes, using `deserialize` to decode any non-empty point.
+    ///
+    /// As per `GenericSignature`, the all-zeros encoding is decoded as `Self::empty()`.
+    fn deserialize_with<F>(bytes: &[u8], deserialize: F) -> Result<Self, Error>
+    where
+        F: Fn(&[u8]) -> Result<AggSig, Error>,
+    {
+        if bytes.len() != SIGNATURE_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_BYTES_LEN,
+            });
+        }
+
         let point = if bytes == &EMPTY_SIGNATURE_SERIALIZATION[..] {
             None
         } else {
-            Some(AggSig::deserialize(bytes)?)
+            Some(deserialize(bytes)?)
         };
 
         Ok(Self {
@@ -173,37 +532,317 @@ where
 impl<Pub, AggPub, Sig, AggSig> GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
 where
     Pub: TPublicKey + Clone,
-    AggPub: TAggregatePublicKey + Clone,
+    AggPub: TAggregatePublicKey<Pub> + Clone,
     Sig: TSignature<Pub>,
     AggSig: TAggregateSignature<Pub, AggPub, Sig>,
 {
     /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed `msg`.
-    pub fn fast_aggregate_verify(&self, msg: Hash256, pubkeys: &[&GenericPublicKey<Pub>]) -> bool {
+    pub fn fast_aggregate_verify<M: SignableMessage>(
+        &self,
+        msg: M,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool {
+        traced_verify!(
+            "fast_aggregate_verify",
+            pubkeys.len(),
+            self.fast_aggregate_verify_checked(msg, pubkeys).is_ok()
+        )
+    }
+
+    /// Deserializes the concatenated signatures in `bytes` (see `Self::deserialize_many`),
+    /// checking that each is in the G2 subgroup, then verifies that each `signatures[i]`
+    /// represents an aggregate signature where all `pubkeys[i]` have signed `msgs[i]`.
+    ///
+    /// The signatures are verified in a single randomized batch, rather than individually.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidByteLength` or a deserialization error if `bytes` cannot be decoded.
+    /// - `Error::InvalidMessageCount` if `msgs` or `pubkeys` is not the same length as the
+    ///     decoded signatures.
+    /// - Any error from `Self::fast_aggregate_verify_checked` for a signature which is verified
+    ///     individually, rather than in the batch (i.e., the "empty" or infinity signature, or one
+    ///     whose `pubkeys` are empty or contain the public key at infinity).
+    /// - `Error::InvalidSignature` if the batch is invalid.
+    pub fn deserialize_many_and_verify(
+        bytes: &[u8],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<Pub>]],
+    ) -> Result<Vec<Self>, Error> {
+        let signatures = Self::deserialize_many_with(bytes, Self::deserialize_checked)?;
+
+        for len in &[msgs.len(), pubkeys.len()] {
+            if *len != signatures.len() {
+                return Err(Error::InvalidMessageCount {
+                    got: *len,
+                    expected: signatures.len(),
+                });
+            }
+        }
+
+        let mut points = Vec::with_capacity(signatures.len());
+        let mut batch_msgs = Vec::with_capacity(signatures.len());
+        let mut batch_pubkeys = Vec::with_capacity(signatures.len());
+        for ((signature, msg), signing_keys) in signatures.iter().zip(msgs).zip(pubkeys) {
+            match signature.point() {
+                Some(point)
+                    if !signature.is_infinity
+                        && !signing_keys.is_empty()
+                        && signing_keys.iter().all(|pk| !pk.is_infinity) =>
+                {
+                    points.push(point);
+                    batch_msgs.push(*msg);
+                    batch_pubkeys.push(*signing_keys);
+                }
+                // Edge cases are verified individually, which also reports the reason.
+                _ => signature.fast_aggregate_verify_checked(*msg, signing_keys)?,
+            }
+        }
+
+        if points.is_empty()
+            || AggSig::fast_aggregate_verify_multiple(&points, &batch_msgs, &batch_pubkeys)
+        {
+            Ok(signatures)
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed `msg`.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify`, except the reason for a failed verification
+    /// is returned:
+    ///
+    /// - `Error::NoPublicKeys` if `pubkeys` is empty.
+    /// - `Error::EmptyAggregate` if `self` is the "empty" value.
+    /// - `Error::InvalidSignature` if the signature is invalid.
+    pub fn fast_aggregate_verify_checked<M: SignableMessage>(
+        &self,
+        msg: M,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> Result<(), Error> {
         if pubkeys.is_empty() {
-            return false;
+            return Err(Error::NoPublicKeys);
         }
 
         if self.is_infinity
             && pubkeys.len() == 1
             && pubkeys.first().map_or(false, |pk| pk.is_infinity)
         {
-            return true;
+            return Ok(());
         }
 
         match self.point.as_ref() {
-            Some(point) => point.fast_aggregate_verify(msg, pubkeys),
+            Some(point) => {
+                if point.fast_aggregate_verify(msg, pubkeys) {
+                    Ok(())
+                } else {
+                    Err(Error::InvalidSignature)
+                }
+            }
+            None => Err(Error::EmptyAggregate),
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed `msg`,
+    /// returning a `VerifyReport` describing the inputs alongside the result.
+    ///
+    /// The result is the same as `Self::fast_aggregate_verify`. This is much slower, since the
+    /// public keys are also aggregated separately for the report, so it should only be used for
+    /// debugging.
+    pub fn verify_report<M: SignableMessage>(
+        &self,
+        msg: M,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> VerifyReport {
+        VerifyReport {
+            num_pubkeys: pubkeys.len(),
+            has_infinity_pubkey: pubkeys.iter().any(|pubkey| pubkey.is_infinity()),
+            signature_is_empty: self.is_empty(),
+            aggregate_pubkey: GenericAggregatePublicKey::<Pub, AggPub>::aggregate(pubkeys)
+                .ok()
+                .map(|agg_pubkey| agg_pubkey.to_string()),
+            valid: self.fast_aggregate_verify(msg, pubkeys),
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where each member of `committee` whose
+    /// bit is set in `bits` has signed `msg` (e.g., an attestation and the bits of its
+    /// `aggregation_bits`).
+    ///
+    /// Returns `Ok(false)` if no bits are set.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidBitfieldLength` if `bits` is not the same length as `committee`.
+    pub fn fast_aggregate_verify_selected<M, I>(
+        &self,
+        msg: M,
+        committee: &[&GenericPublicKey<Pub>],
+        bits: I,
+    ) -> Result<bool, Error>
+    where
+        M: SignableMessage,
+        I: IntoIterator<Item = bool>,
+    {
+        let bits = bits.into_iter().collect::<Vec<_>>();
+        let pubkeys = select_pubkeys(committee, &bits)?;
+        Ok(self.fast_aggregate_verify(msg, &pubkeys))
+    }
+
+    /// Verify that `self` represents an aggregate signature where each member of `committee` whose
+    /// bit is set in `bits` has signed `msg`, returning the participation of `committee` if so.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify_selected`, except the reason for a failed
+    /// verification is returned (as per `Self::fast_aggregate_verify_checked`).
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidBitfieldLength` if `bits` is not the same length as `committee`.
+    /// - `Error::NoPublicKeys` if no bits are set.
+    /// - Any other error from `Self::fast_aggregate_verify_checked`.
+    pub fn verify_and_report_participation<M, I>(
+        &self,
+        committee: &[&GenericPublicKey<Pub>],
+        bits: I,
+        msg: M,
+    ) -> Result<ParticipationReport, Error>
+    where
+        M: SignableMessage,
+        I: IntoIterator<Item = bool>,
+    {
+        let bits = bits.into_iter().collect::<Vec<_>>();
+        let pubkeys = select_pubkeys(committee, &bits)?;
+        self.fast_aggregate_verify_checked(msg, &pubkeys)?;
+
+        let non_participant_indices = bits
+            .iter()
+            .enumerate()
+            .filter_map(|(i, bit)| if *bit { None } else { Some(i) })
+            .collect::<Vec<_>>();
+
+        Ok(ParticipationReport {
+            participants: pubkeys.len(),
+            non_participants: non_participant_indices.len(),
+            non_participant_indices,
+        })
+    }
+
+    /// Verify a sync committee signature, where each member of the `SYNC_COMMITTEE_SIZE`
+    /// `committee` whose bit is set in `participation` has signed `msg`.
+    ///
+    /// As per `eth_fast_aggregate_verify` in the Altair specification, if no bits are set then
+    /// `self` is only valid if it is the signature at infinity.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidParticipationLength` if `participation` is not `SYNC_COMMITTEE_SIZE` bits.
+    /// - `Error::InvalidBitfieldLength` if `committee` is not the same length as `participation`.
+    pub fn verify_sync_committee<M, I>(
+        &self,
+        msg: M,
+        committee: &[&GenericPublicKey<Pub>],
+        participation: I,
+    ) -> Result<bool, Error>
+    where
+        M: SignableMessage,
+        I: IntoIterator<Item = bool>,
+    {
+        let participation = participation.into_iter().collect::<Vec<_>>();
+        if participation.len() != SYNC_COMMITTEE_SIZE {
+            return Err(Error::InvalidParticipationLength {
+                got: participation.len(),
+                expected: SYNC_COMMITTEE_SIZE,
+            });
+        }
+
+        let pubkeys = select_pubkeys(committee, &participation)?;
+        if pubkeys.is_empty() {
+            Ok(self.is_infinity())
+        } else {
+            Ok(self.fast_aggregate_verify(msg, &pubkeys))
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where all of the public keys in
+    /// `pubkey_bytes` have signed `msg`.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify`, except the public keys are given as compressed
+    /// bytes (e.g., from a flat SSZ list) and are deserialized with
+    /// `GenericPublicKey::deserialize_checked`.
+    ///
+    /// Returns an error if any of `pubkey_bytes` is not a valid public key.
+    pub fn fast_aggregate_verify_bytes(
+        &self,
+        msg: Hash256,
+        pubkey_bytes: &[&[u8]],
+    ) -> Result<bool, Error> {
+        let pubkeys = pubkey_bytes
+            .iter()
+            .map(|bytes| GenericPublicKey::deserialize_checked(bytes))
+            .collect::<Result<Vec<_>, _>>()?;
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+
+        Ok(self.fast_aggregate_verify(msg, &pubkey_refs))
+    }
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed `msg`.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify`, except `pubkeys` may be any iterator (e.g., over
+    /// the members of a committee) and are aggregated as they are visited, rather than first being
+    /// collected into a slice.
+    ///
+    /// Returns `false` if `pubkeys` is empty.
+    pub fn fast_aggregate_verify_iter<'a, M, I>(&self, msg: M, pubkeys: I) -> bool
+    where
+        M: SignableMessage,
+        I: IntoIterator<Item = &'a GenericPublicKey<Pub>>,
+        Pub: 'a,
+    {
+        let mut agg_pubkey: Option<AggPub> = None;
+        let mut num_pubkeys = 0;
+        let mut only_infinity = true;
+        for pubkey in pubkeys {
+            num_pubkeys += 1;
+            only_infinity &= pubkey.is_infinity;
+
+            let point = AggPub::aggregate(&[pubkey]);
+            match agg_pubkey.as_mut() {
+                Some(agg_pubkey) => agg_pubkey.add_assign_aggregate(&point),
+                None => agg_pubkey = Some(point),
+            }
+        }
+
+        if self.is_infinity && num_pubkeys == 1 && only_infinity {
+            return true;
+        }
+
+        match agg_pubkey {
+            Some(point) => self.fast_aggregate_verify_pre_aggregated(
+                msg,
+                &GenericAggregatePublicKey::from_point(point),
+            ),
             None => false,
         }
     }
 
-    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed their
-    /// corresponding message in `msgs`.
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed the
+    /// message in `prepared`.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify`, except the message is given as a
+    /// `PreparedMessage`. Returns `false` unless `prepared` was hashed with `DST`.
     ///
     /// ## Notes
     ///
-    /// This function only exists for EF tests, it's presently not used in production.
-    pub fn aggregate_verify(&self, msgs: &[Hash256], pubkeys: &[&GenericPublicKey<Pub>]) -> bool {
-        if msgs.is_empty() || msgs.len() != pubkeys.len() {
+    /// Only the `blst` backend verifies against the prepared point; the others hash the message
+    /// again.
+    #[cfg(feature = "supranational")]
+    pub fn fast_aggregate_verify_prepared(
+        &self,
+        prepared: &PreparedMessage,
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool {
+        if pubkeys.is_empty() {
             return false;
         }
 
@@ -211,14 +850,241 @@ where
             && pubkeys.len() == 1
             && pubkeys.first().map_or(false, |pk| pk.is_infinity)
         {
-            return true;
+            return prepared.dst() == DST;
         }
 
         match self.point.as_ref() {
-            Some(point) => point.aggregate_verify(msgs, pubkeys),
+            Some(point) => point.fast_aggregate_verify_prepared(prepared, pubkeys),
+            None => false,
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where all of the public keys in
+    /// `agg_pubkey` have signed `msg`.
+    ///
+    /// Equivalent to `Self::fast_aggregate_verify`, except the public keys have already been
+    /// aggregated (see `GenericAggregatePublicKey::aggregate`). This avoids repeating the
+    /// aggregation when the same set of public keys signs many messages.
+    pub fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msg: M,
+        agg_pubkey: &GenericAggregatePublicKey<Pub, AggPub>,
+    ) -> bool {
+        match self.point.as_ref() {
+            Some(point) => point.fast_aggregate_verify_pre_aggregated(msg, agg_pubkey),
             None => false,
         }
     }
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed their
+    /// corresponding message in `msgs`.
+    ///
+    /// Unlike `Self::fast_aggregate_verify`, each of `pubkeys` may sign a distinct message (e.g.,
+    /// sync committee contributions).
+    pub fn aggregate_verify<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> bool {
+        traced_verify!(
+            "aggregate_verify",
+            pubkeys.len(),
+            self.aggregate_verify_checked(msgs, pubkeys).is_ok()
+        )
+    }
+
+    /// Verify that `self` represents an aggregate signature where all `pubkeys` have signed their
+    /// corresponding message in `msgs`.
+    ///
+    /// Equivalent to `Self::aggregate_verify`, except the reason for a failed verification is
+    /// returned:
+    ///
+    /// - `Error::NoPublicKeys` if `pubkeys` is empty.
+    /// - `Error::InvalidMessageCount` if `msgs` is not the same length as `pubkeys`.
+    /// - `Error::EmptyAggregate` if `self` is the "empty" value.
+    /// - `Error::InvalidSignature` if the signature is invalid.
+    pub fn aggregate_verify_checked<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        pubkeys: &[&GenericPublicKey<Pub>],
+    ) -> Result<(), Error> {
+        if pubkeys.is_empty() {
+            return Err(Error::NoPublicKeys);
+        }
+
+        if msgs.len() != pubkeys.len() {
+            return Err(Error::InvalidMessageCount {
+                got: msgs.len(),
+                expected: pubkeys.len(),
+            });
+        }
+
+        if self.is_infinity
+            && pubkeys.len() == 1
+            && pubkeys.first().map_or(false, |pk| pk.is_infinity)
+        {
+            return Ok(());
+        }
+
+        match self.point.as_ref() {
+            Some(point) => {
+                if point.aggregate_verify(msgs, pubkeys) {
+                    Ok(())
+                } else {
+                    Err(Error::InvalidSignature)
+                }
+            }
+            None => Err(Error::EmptyAggregate),
+        }
+    }
+
+    /// Verify that `self` represents an aggregate signature where, for each `(msg, pubkeys)` in
+    /// `groups`, all of `pubkeys` have signed `msg`.
+    ///
+    /// Equivalent to `Self::aggregate_verify` across the flattened `groups`, except the public
+    /// keys in each group are aggregated first, so only one pairing is computed per group rather
+    /// than per public key (e.g., when many sync committee members sign the same block root).
+    ///
+    /// Returns `false` if `groups` or any group's public keys are empty.
+    pub fn aggregate_verify_grouped(
+        &self,
+        groups: &[(Hash256, Vec<&GenericPublicKey<Pub>>)],
+    ) -> bool {
+        if groups.is_empty() {
+            return false;
+        }
+
+        let mut msgs = Vec::with_capacity(groups.len());
+        let mut agg_pubkeys = Vec::with_capacity(groups.len());
+        for (msg, pubkeys) in groups {
+            match GenericAggregatePublicKey::aggregate(pubkeys) {
+                Ok(agg_pubkey) => agg_pubkeys.push(agg_pubkey),
+                Err(_) => return false,
+            }
+            msgs.push(*msg);
+        }
+
+        let agg_pubkey_refs = agg_pubkeys.iter().collect::<Vec<_>>();
+        traced_verify!(
+            "aggregate_verify_grouped",
+            agg_pubkey_refs.len(),
+            self.point.as_ref().map_or(false, |point| {
+                point.aggregate_verify_pre_aggregated(&msgs, &agg_pubkey_refs)
+            })
+        )
+    }
+
+    /// Equivalent to `Self::fast_aggregate_verify_checked`, except a successful verification
+    /// returns a `GenericVerifiedSignature` which borrows `self` and `pubkeys`.
+    pub fn fast_aggregate_verify_typed<'a, M: SignableMessage>(
+        &'a self,
+        msg: M,
+        pubkeys: &[&'a GenericPublicKey<Pub>],
+    ) -> Result<GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig>, Error> {
+        self.fast_aggregate_verify_checked(msg, pubkeys)?;
+        Ok(GenericVerifiedSignature::new(self))
+    }
+
+    /// Equivalent to `Self::aggregate_verify`, except a successful verification returns a
+    /// `GenericVerifiedSignature` which borrows `self` and `pubkeys`.
+    ///
+    /// Returns `None` if verification fails.
+    pub fn aggregate_verify_typed<'a, M: SignableMessage>(
+        &'a self,
+        msgs: &[M],
+        pubkeys: &[&'a GenericPublicKey<Pub>],
+    ) -> Option<GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig>> {
+        if self.aggregate_verify(msgs, pubkeys) {
+            Some(GenericVerifiedSignature::new(self))
+        } else {
+            None
+        }
+    }
+}
+
+impl<Pub, AggPub, Sig, AggSig> GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignatureUncompressed<Pub, AggPub, Sig>,
+{
+    /// Serialize `self` as uncompressed bytes.
+    ///
+    /// The "empty" value is serialized as all-zeros. The compressed form (i.e., `Self::serialize`)
+    /// is used for SSZ and should be preferred in all consensus-related contexts.
+    pub fn serialize_uncompressed(&self) -> [u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN] {
+        if let Some(point) = &self.point {
+            point.serialize_uncompressed()
+        } else {
+            [0; SIGNATURE_UNCOMPRESSED_BYTES_LEN]
+        }
+    }
+
+    /// Deserialize `self` from uncompressed bytes.
+    ///
+    /// All-zeros bytes are deserialized as the "empty" value. As with `Self::deserialize`, the
+    /// point is *not* checked to be in the G2 subgroup.
+    pub fn deserialize_uncompressed(
+        bytes: &[u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN],
+    ) -> Result<Self, Error> {
+        let point = if bytes.iter().all(|byte| *byte == 0) {
+            None
+        } else {
+            Some(AggSig::deserialize_uncompressed(bytes)?)
+        };
+
+        Ok(Self {
+            is_infinity: point
+                .as_ref()
+                .map_or(false, |point| point.serialize() == INFINITY_SIGNATURE),
+            point,
+            _phantom_pub: PhantomData,
+            _phantom_agg_pub: PhantomData,
+            _phantom_sig: PhantomData,
+        })
+    }
+}
+
+/// Returns each member of `committee` whose corresponding bit in `bits` is set.
+///
+/// Returns `Error::InvalidBitfieldLength` if `bits` is not the same length as `committee`.
+fn select_pubkeys<'a, Pub>(
+    committee: &[&'a GenericPublicKey<Pub>],
+    bits: &[bool],
+) -> Result<Vec<&'a GenericPublicKey<Pub>>, Error> {
+    if bits.len() != committee.len() {
+        return Err(Error::InvalidBitfieldLength {
+            got: bits.len(),
+            expected: committee.len(),
+        });
+    }
+
+    Ok(committee
+        .iter()
+        .zip(bits)
+        .filter_map(|(pubkey, bit)| if *bit { Some(*pubkey) } else { None })
+        .collect())
+}
+
+impl<Pub, AggPub, Sig, AggSig> Clone for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    AggSig: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone(),
+            is_infinity: self.is_infinity,
+            _phantom_pub: PhantomData,
+            _phantom_agg_pub: PhantomData,
+            _phantom_sig: PhantomData,
+        }
+    }
+
+    /// Copies `source` into the existing point of `self` (if both have a point), which allows a
+    /// scratch aggregate to be reused without constructing a new point (see `AggSig::clone_from`).
+    fn clone_from(&mut self, source: &Self) {
+        self.point.clone_from(&source.point);
+        self.is_infinity = source.is_infinity;
+    }
 }
 
 impl<Pub, AggPub, Sig, AggSig> Encode for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
@@ -270,6 +1136,160 @@ where
     impl_debug!();
 }
 
+impl<Pub, AggPub, Sig, AggSig> fmt::Display for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    impl_display!();
+}
+
+impl<Pub, AggPub, Sig, AggSig> FromStr for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    impl_from_str!();
+}
+
+impl<Pub, AggPub, Sig, AggSig> TryFrom<&str> for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    impl_try_from_str!(SIGNATURE_BYTES_LEN);
+}
+
+impl<Pub, AggPub, Sig, AggSig> TryFrom<&[u8]>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    impl_try_from_slice!(SIGNATURE_BYTES_LEN);
+}
+
+impl<Pub, AggPub, Sig, AggSig> TryFrom<Vec<u8>>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    impl_try_from_vec!();
+}
+
+/// Aggregates the signatures, starting from `Self::empty()`.
+impl<Pub, AggPub, Sig, AggSig> Sum<GenericSignature<Pub, Sig>>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn sum<I: Iterator<Item = GenericSignature<Pub, Sig>>>(iter: I) -> Self {
+        let mut aggregate = Self::empty();
+        for signature in iter {
+            signature.aggregate_into(&mut aggregate);
+        }
+        aggregate
+    }
+}
+
+/// Aggregates the signatures, starting from `Self::empty()`.
+impl<'a, Pub, AggPub, Sig, AggSig> Sum<&'a GenericSignature<Pub, Sig>>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn sum<I: Iterator<Item = &'a GenericSignature<Pub, Sig>>>(iter: I) -> Self {
+        Self::aggregate(iter)
+    }
+}
+
+/// Aggregates the aggregate signatures, starting from `Self::empty()`.
+impl<Pub, AggPub, Sig, AggSig> Sum for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::empty(), |mut aggregate, other| {
+            aggregate.add_assign_aggregate(&other);
+            aggregate
+        })
+    }
+}
+
+/// Aggregates the aggregate signatures, starting from `Self::empty()`.
+impl<'a, Pub, AggPub, Sig, AggSig> Sum<&'a Self>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::empty(), |mut aggregate, other| {
+            aggregate.add_assign_aggregate(other);
+            aggregate
+        })
+    }
+}
+
+/// Aggregates the signatures onto `self`.
+impl<Pub, AggPub, Sig, AggSig> Extend<GenericSignature<Pub, Sig>>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn extend<I: IntoIterator<Item = GenericSignature<Pub, Sig>>>(&mut self, iter: I) {
+        for signature in iter {
+            signature.aggregate_into(self);
+        }
+    }
+}
+
+/// Aggregates the signatures onto `self`.
+impl<'a, Pub, AggPub, Sig, AggSig> Extend<&'a GenericSignature<Pub, Sig>>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn extend<I: IntoIterator<Item = &'a GenericSignature<Pub, Sig>>>(&mut self, iter: I) {
+        for signature in iter {
+            self.add_assign(signature);
+        }
+    }
+}
+
+/// Aggregates the aggregate signatures onto `self`.
+impl<Pub, AggPub, Sig, AggSig> Extend<Self> for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn extend<I: IntoIterator<Item = Self>>(&mut self, iter: I) {
+        for other in iter {
+            self.add_assign_aggregate(&other);
+        }
+    }
+}
+
+/// Aggregates the aggregate signatures onto `self`.
+impl<'a, Pub, AggPub, Sig, AggSig> Extend<&'a Self>
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn extend<I: IntoIterator<Item = &'a Self>>(&mut self, iter: I) {
+        for other in iter {
+            self.add_assign_aggregate(other);
+        }
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<Pub, AggPub, Sig, AggSig> arbitrary::Arbitrary
     for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
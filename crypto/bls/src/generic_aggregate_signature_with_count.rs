@@ -0,0 +1,76 @@
+use crate::{
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
+    generic_signature::{GenericSignature, TSignature},
+};
+use std::ops::Deref;
+
+/// Wraps a `GenericAggregateSignature` and counts the signatures which have been aggregated into
+/// it, for diagnostics (e.g., logging how many attestations were packed into an aggregate).
+///
+/// The count is not part of the signature: it is not serialized and it is not checked during
+/// verification. All verification and serialization is delegated to the inner aggregate via
+/// `Deref`. There is deliberately no `DerefMut`, since mutating the inner aggregate directly would
+/// not update the count.
+#[derive(Clone)]
+pub struct GenericAggregateSignatureWithCount<Pub, AggPub, Sig, AggSig> {
+    aggregate: GenericAggregateSignature<Pub, AggPub, Sig, AggSig>,
+    count: usize,
+}
+
+impl<Pub, AggPub, Sig, AggSig> GenericAggregateSignatureWithCount<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    /// Initialize `Self` to the infinity value, with a count of zero.
+    pub fn infinity() -> Self {
+        Self {
+            aggregate: GenericAggregateSignature::infinity(),
+            count: 0,
+        }
+    }
+
+    /// Initialize `Self` to the "empty" value, with a count of zero.
+    pub fn empty() -> Self {
+        Self {
+            aggregate: GenericAggregateSignature::empty(),
+            count: 0,
+        }
+    }
+
+    /// Aggregates a signature onto `self`, incrementing the count.
+    ///
+    /// The count is not incremented if `other` is the "empty" value, since it is ignored.
+    pub fn add_assign(&mut self, other: &GenericSignature<Pub, Sig>) {
+        if !other.is_empty() {
+            self.count += 1;
+        }
+        self.aggregate.add_assign(other);
+    }
+
+    /// Aggregates another counted aggregate onto `self`, adding its count to `self`.
+    pub fn add_assign_aggregate(&mut self, other: &Self) {
+        self.count += other.count;
+        self.aggregate.add_assign_aggregate(&other.aggregate);
+    }
+
+    /// Returns the number of signatures which have been aggregated into `self`.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the inner aggregate signature, discarding the count.
+    pub fn into_inner(self) -> GenericAggregateSignature<Pub, AggPub, Sig, AggSig> {
+        self.aggregate
+    }
+}
+
+impl<Pub, AggPub, Sig, AggSig> Deref
+    for GenericAggregateSignatureWithCount<Pub, AggPub, Sig, AggSig>
+{
+    type Target = GenericAggregateSignature<Pub, AggPub, Sig, AggSig>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.aggregate
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    generic_signature::{GenericSignature, TSignature},
+    Hash256,
+};
+use std::collections::HashMap;
+use std::ptr;
+
+/// The aggregate signature and signing keys across a single message.
+struct MessageGroup<'a, Pub, AggPub, Sig, AggSig> {
+    message: Hash256,
+    signature: GenericAggregateSignature<Pub, AggPub, Sig, AggSig>,
+    signing_keys: Vec<&'a GenericPublicKey<Pub>>,
+}
+
+/// Incrementally collects `(signature, public_key, message)` tuples (e.g., as attestations arrive
+/// from gossip) and then verifies them all with a single randomized batch verification.
+///
+/// Signatures across the same message are aggregated as they are pushed, so each distinct message
+/// contributes a single signature to the batch. If several messages are signed by exactly the same
+/// public keys (e.g., the same committee), those public keys are only aggregated once.
+pub struct GenericAggregateVerifier<'a, Pub, AggPub, Sig, AggSig> {
+    groups: Vec<MessageGroup<'a, Pub, AggPub, Sig, AggSig>>,
+    group_indices: HashMap<Hash256, usize>,
+    /// Set to `true` if an input was pushed which can never be valid.
+    invalid: bool,
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> Default
+    for GenericAggregateVerifier<'a, Pub, AggPub, Sig, AggSig>
+{
+    fn default() -> Self {
+        Self {
+            groups: vec![],
+            group_indices: HashMap::new(),
+            invalid: false,
+        }
+    }
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> GenericAggregateVerifier<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    /// Instantiate an empty verifier.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `signature` across `msg` by `pubkey` to `self`.
+    ///
+    /// An "empty" `signature` or an infinity `pubkey` will cause `Self::finish` to return `false`.
+    pub fn push(
+        &mut self,
+        signature: &GenericSignature<Pub, Sig>,
+        pubkey: &'a GenericPublicKey<Pub>,
+        msg: Hash256,
+    ) {
+        if signature.is_empty() || pubkey.is_infinity {
+            self.invalid = true;
+            return;
+        }
+
+        let groups = &mut self.groups;
+        let index = *self.group_indices.entry(msg).or_insert_with(|| {
+            groups.push(MessageGroup {
+                message: msg,
+                signature: GenericAggregateSignature::infinity(),
+                signing_keys: vec![],
+            });
+            groups.len() - 1
+        });
+
+        let group = &mut self.groups[index];
+        group.signature.add_assign(signature);
+        group.signing_keys.push(pubkey);
+    }
+
+    /// Returns `true` if all of the signatures pushed to `self` are valid.
+    ///
+    /// A verifier without any signatures is invalid.
+    pub fn finish(self) -> bool {
+        if self.invalid || self.groups.is_empty() {
+            return false;
+        }
+
+        // Aggregate the signing keys for each message, re-using the aggregate for any previous
+        // message which was signed by the same public keys.
+        let mut agg_pubkeys: Vec<(&[&GenericPublicKey<Pub>], _)> = vec![];
+        let mut agg_pubkey_indices = Vec::with_capacity(self.groups.len());
+        for group in &self.groups {
+            let existing = agg_pubkeys
+                .iter()
+                .position(|(signing_keys, _)| same_keys(signing_keys, &group.signing_keys));
+
+            let index = match existing {
+                Some(index) => index,
+                None => match GenericAggregatePublicKey::aggregate(&group.signing_keys) {
+                    Ok(agg_pubkey) => {
+                        agg_pubkeys.push((&group.signing_keys[..], agg_pubkey));
+                        agg_pubkeys.len() - 1
+                    }
+                    Err(_) => return false,
+                },
+            };
+            agg_pubkey_indices.push(index);
+        }
+
+        let signatures = match self
+            .groups
+            .iter()
+            .map(|group| group.signature.point())
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(signatures) => signatures,
+            None => return false,
+        };
+        let messages = self
+            .groups
+            .iter()
+            .map(|group| group.message)
+            .collect::<Vec<_>>();
+        let agg_pubkey_refs = agg_pubkey_indices
+            .into_iter()
+            .map(|index| &agg_pubkeys[index].1)
+            .collect::<Vec<_>>();
+
+        AggSig::fast_aggregate_verify_multiple_pre_aggregated(
+            &signatures,
+            &messages,
+            &agg_pubkey_refs,
+        )
+    }
+}
+
+/// Returns `true` if `a` and `b` contain references to the same public keys, in the same order.
+///
+/// Compares references rather than values, which avoids serializing the public keys.
+fn same_keys<Pub>(a: &[&GenericPublicKey<Pub>], b: &[&GenericPublicKey<Pub>]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| ptr::eq(*a, *b))
+}
@@ -0,0 +1,182 @@
+//! Blind signatures, where the signer signs a message without learning it.
+//!
+//! The requester blinds `H(msg)` with a random scalar `r` (i.e., `r * H(msg)`), the signer
+//! multiplies the blinded point by their secret key and the requester unblinds the result with
+//! `r^-1`. The unblinded signature is `sk * H(msg)`, which is an ordinary signature across `msg`.
+//!
+//! Each scalar multiplication is performed by the backend (see `TSignature::mul`).
+
+use crate::{
+    generic_public_key::TPublicKey,
+    generic_secret_key::{GenericSecretKey, TSecretKey},
+    generic_signature::{GenericSignature, TSignature},
+    scalar::Scalar,
+    Error, Hash256, ZeroizeHash, SECRET_KEY_BYTES_LEN, SIGNATURE_BYTES_LEN,
+};
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+/// A random, non-zero scalar which is known only to the requester of a blind signature.
+///
+/// It must never be reused, since two messages blinded with the same factor may be linked.
+pub struct BlindingFactor(Scalar);
+
+impl BlindingFactor {
+    /// Instantiate `Self` from some secure source of entropy (the operating system's RNG).
+    pub fn random() -> Self {
+        Self::random_with_rng(&mut OsRng)
+    }
+
+    /// Instantiate `Self` using entropy from `rng`.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            // The curve order is less than 2^255, so clearing the top bit rejects fewer than 10%
+            // of samples without introducing a bias.
+            let mut bytes = [0; SECRET_KEY_BYTES_LEN];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] &= 0x7f;
+            let factor = Self::from_bytes(&bytes);
+            bytes.zeroize();
+
+            if let Ok(factor) = factor {
+                return factor;
+            }
+        }
+    }
+
+    /// Deserialize `self` from big-endian bytes.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidByteLength` if `bytes` is not `SECRET_KEY_BYTES_LEN` bytes.
+    /// - `Error::InvalidSecretKeyBytes` if `bytes` is zero or not less than the curve order.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != SECRET_KEY_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SECRET_KEY_BYTES_LEN,
+            });
+        }
+
+        let mut array = [0; SECRET_KEY_BYTES_LEN];
+        array.copy_from_slice(bytes);
+        let factor = Self::from_bytes(&array);
+        array.zeroize();
+        factor
+    }
+
+    /// Serialize `self` as big-endian bytes.
+    pub fn serialize(&self) -> ZeroizeHash {
+        self.0.to_bytes_be()
+    }
+
+    /// Instantiates `Self` from big-endian bytes, which must be a non-zero scalar less than the
+    /// curve order.
+    fn from_bytes(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Result<Self, Error> {
+        match Scalar::from_bytes_be(bytes) {
+            Some(scalar) if !scalar.is_zero() => Ok(Self(scalar)),
+            _ => Err(Error::InvalidSecretKeyBytes),
+        }
+    }
+}
+
+/// A message which has been blinded by `blind_message`, which may be safely given to the signer.
+pub struct GenericBlindedMessage<Pub, Sig>(GenericSignature<Pub, Sig>);
+
+/// A signature across a `GenericBlindedMessage`, which may be unblinded by `unblind` to recover a
+/// signature across the original message.
+pub struct GenericBlindedSignature<Pub, Sig>(GenericSignature<Pub, Sig>);
+
+macro_rules! impl_blinded_point {
+    ($type: ident) => {
+        impl<Pub, Sig: TSignature<Pub>> $type<Pub, Sig> {
+            /// Serialize `self` as compressed bytes.
+            pub fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+                self.0.serialize()
+            }
+
+            /// Deserialize `self` from compressed bytes, checking that the point is in the G2
+            /// subgroup.
+            ///
+            /// Returns `Error::EmptySignature` if `bytes` is the `NONE_SIGNATURE`.
+            pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+                let point = GenericSignature::deserialize_checked(bytes)?;
+                if point.is_empty() {
+                    Err(Error::EmptySignature)
+                } else {
+                    Ok(Self(point))
+                }
+            }
+        }
+    };
+}
+
+impl_blinded_point!(GenericBlindedMessage);
+impl_blinded_point!(GenericBlindedSignature);
+
+/// Blinds `msg` with `blinding`, so it may be signed without revealing `msg` to the signer.
+pub fn blind_message<Pub, Sig, Sec>(
+    msg: Hash256,
+    blinding: &BlindingFactor,
+) -> GenericBlindedMessage<Pub, Sig>
+where
+    Pub: TPublicKey,
+    Sig: TSignature<Pub>,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    // Signing with the secret key `1` returns `H(msg)`.
+    let one = Scalar::from_u64(1).to_bytes_be();
+    let hash = GenericSecretKey::<Sig, Pub, Sec>::deserialize(one.as_bytes())
+        .expect("one is a valid secret key")
+        .sign(msg);
+
+    let blinded = multiply(&hash, &blinding.0.to_bytes_be()).expect("a signature is never empty");
+    GenericBlindedMessage(blinded)
+}
+
+impl<Sig, Pub, Sec> GenericSecretKey<Sig, Pub, Sec>
+where
+    Pub: TPublicKey,
+    Sig: TSignature<Pub>,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    /// Signs the `blinded` message, without learning the message itself (see
+    /// `blind::blind_message`).
+    pub fn sign_blinded(
+        &self,
+        blinded: &GenericBlindedMessage<Pub, Sig>,
+    ) -> GenericBlindedSignature<Pub, Sig> {
+        let signature =
+            multiply(&blinded.0, &self.serialize()).expect("a blinded message is never empty");
+        GenericBlindedSignature(signature)
+    }
+}
+
+/// Removes `blinding` from `blinded_signature`, returning a signature across the message that was
+/// given to `blind_message`.
+///
+/// The result should be verified, since the signer may not have used the expected secret key.
+pub fn unblind<Pub, Sig>(
+    blinded_signature: &GenericBlindedSignature<Pub, Sig>,
+    blinding: &BlindingFactor,
+) -> GenericSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+{
+    multiply(&blinded_signature.0, &blinding.0.invert().to_bytes_be())
+        .expect("a blinded signature is never empty")
+}
+
+/// Returns `signature * scalar`, or `Error::EmptySignature` if `signature` is the "empty" value.
+///
+/// The `scalar` must be non-zero, so the result is only the point at infinity if `signature` is.
+fn multiply<Pub, Sig>(
+    signature: &GenericSignature<Pub, Sig>,
+    scalar: &ZeroizeHash,
+) -> Result<GenericSignature<Pub, Sig>, Error>
+where
+    Sig: TSignature<Pub>,
+{
+    let point = signature.point().ok_or(Error::EmptySignature)?.mul(scalar);
+    Ok(GenericSignature::from_point(point, signature.is_infinity))
+}
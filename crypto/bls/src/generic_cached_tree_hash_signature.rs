@@ -0,0 +1,98 @@
+use crate::generic_signature::{GenericSignature, TSignature};
+use once_cell::sync::OnceCell;
+use tree_hash::{Hash256, TreeHash};
+
+/// Wraps a `GenericSignature` and memoizes its tree hash root, for signatures which are hashed
+/// repeatedly (e.g., during state transitions).
+///
+/// The root is computed on the first call to `Self::tree_hash_root_cached` and is discarded
+/// whenever the signature may have been mutated (i.e., by `Self::signature_mut` or
+/// `Self::set_signature`).
+///
+/// The cache is a `OnceCell`, so this type is `Sync` and may be hashed from multiple threads (e.g.,
+/// when tree hashing a `BeaconState` in parallel).
+#[derive(Clone)]
+pub struct GenericCachedTreeHashSignature<Pub, Sig> {
+    signature: GenericSignature<Pub, Sig>,
+    root: OnceCell<Hash256>,
+}
+
+impl<Pub, Sig> GenericCachedTreeHashSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+{
+    /// Wraps `signature` with an empty cache.
+    pub fn new(signature: GenericSignature<Pub, Sig>) -> Self {
+        Self {
+            signature,
+            root: OnceCell::new(),
+        }
+    }
+
+    /// Returns the tree hash root of the signature, computing it only if it is not cached.
+    pub fn tree_hash_root_cached(&self) -> Hash256 {
+        *self.root.get_or_init(|| self.signature.tree_hash_root())
+    }
+
+    /// Returns the signature.
+    pub fn signature(&self) -> &GenericSignature<Pub, Sig> {
+        &self.signature
+    }
+
+    /// Returns the signature for mutation, discarding the cached root.
+    pub fn signature_mut(&mut self) -> &mut GenericSignature<Pub, Sig> {
+        self.root = OnceCell::new();
+        &mut self.signature
+    }
+
+    /// Replaces the signature, discarding the cached root.
+    pub fn set_signature(&mut self, signature: GenericSignature<Pub, Sig>) {
+        *self.signature_mut() = signature;
+    }
+
+    /// Returns the signature, discarding the cached root.
+    pub fn into_inner(self) -> GenericSignature<Pub, Sig> {
+        self.signature
+    }
+}
+
+impl<Pub, Sig> From<GenericSignature<Pub, Sig>> for GenericCachedTreeHashSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+{
+    fn from(signature: GenericSignature<Pub, Sig>) -> Self {
+        Self::new(signature)
+    }
+}
+
+/// Compares the signatures, ignoring the cached roots.
+impl<Pub, Sig> PartialEq for GenericCachedTreeHashSignature<Pub, Sig>
+where
+    GenericSignature<Pub, Sig>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.signature == other.signature
+    }
+}
+
+/// Equivalent to the `TreeHash` implementation of `GenericSignature`, except the root is cached.
+impl<Pub, Sig> TreeHash for GenericCachedTreeHashSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+{
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        GenericSignature::<Pub, Sig>::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> Vec<u8> {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        self.tree_hash_root_cached()
+    }
+}
@@ -2,13 +2,18 @@ use crate::{
     generic_public_key::{GenericPublicKey, TPublicKey},
     generic_secret_key::{GenericSecretKey, TSecretKey},
     generic_signature::TSignature,
+    Error,
 };
+use rand::{CryptoRng, RngCore};
 use std::fmt;
 use std::marker::PhantomData;
 
 /// A simple wrapper around `PublicKey` and `GenericSecretKey`.
 #[derive(Clone)]
-pub struct GenericKeypair<Pub, Sec, Sig> {
+pub struct GenericKeypair<Pub, Sec, Sig>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
     pub pk: GenericPublicKey<Pub>,
     pub sk: GenericSecretKey<Sig, Pub, Sec>,
     _phantom: PhantomData<Sig>,
@@ -22,19 +27,35 @@ where
 {
     /// Instantiate `Self` from a public and secret key.
     ///
-    /// This function does not check to ensure that `pk` is derived from `sk`. It would be a logic
-    /// error to supply such a `pk`.
-    pub fn from_components(pk: GenericPublicKey<Pub>, sk: GenericSecretKey<Sig, Pub, Sec>) -> Self {
-        Self {
-            pk,
-            sk,
-            _phantom: PhantomData,
+    /// Returns `Error::InconsistentKeypair` if `pk` is not derived from `sk` (e.g., a corrupt or
+    /// mismatched keystore). Use `Self::from_secret_key` if the public key is not already known.
+    pub fn from_components(
+        pk: GenericPublicKey<Pub>,
+        sk: GenericSecretKey<Sig, Pub, Sec>,
+    ) -> Result<Self, Error> {
+        if sk.public_key() == pk {
+            Ok(Self {
+                pk,
+                sk,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::InconsistentKeypair)
         }
     }
 
     /// Instantiates `Self` from a randomly generated secret key.
     pub fn random() -> Self {
-        let sk = GenericSecretKey::random();
+        Self::from_secret_key(GenericSecretKey::random())
+    }
+
+    /// Instantiates `Self` from a secret key generated using entropy from `rng`.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::from_secret_key(GenericSecretKey::random_with_rng(rng))
+    }
+
+    /// Instantiates `Self` from `sk`, deriving the public key.
+    pub fn from_secret_key(sk: GenericSecretKey<Sig, Pub, Sec>) -> Self {
         Self {
             pk: sk.public_key(),
             sk,
@@ -46,6 +67,7 @@ where
 impl<Pub, Sec, Sig> fmt::Debug for GenericKeypair<Pub, Sec, Sig>
 where
     Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
 {
     /// Defers to `self.pk` to avoid leaking the secret key.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -1,8 +1,17 @@
-use crate::Error;
+use crate::{
+    generic_aggregate_public_key::TAggregatePublicKey,
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
+    generic_secret_key::POP_DST,
+    generic_signature::{GenericSignature, TSignature, TSignatureWithDst},
+    Error, Hash256, SignableMessage, ZeroizeHash,
+};
+use eth2_hashing::hash;
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
-use serde_hex::{encode as hex_encode, PrefixedHexVisitor};
+use serde_hex::encode as hex_encode;
 use ssz::{Decode, Encode};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use tree_hash::TreeHash;
@@ -16,14 +25,53 @@ pub const INFINITY_PUBLIC_KEY: [u8; PUBLIC_KEY_BYTES_LEN] = [
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ];
 
+/// Returns `Ok(())` if the flag bits in the most-significant byte of `bytes` are consistent with
+/// a compressed G1 point, as per the serialization format used by the Eth2 specification:
+///
+/// - The compression flag must be set.
+/// - If the infinity flag is set, the sort flag and all other bits must be zero.
+///
+/// Returns `Error::InvalidFlagBits` otherwise. This does *not* check that `x` is canonical or
+/// that the point is on the curve.
+fn check_flag_bits(bytes: &[u8]) -> Result<(), Error> {
+    let compression_flag = bytes[0] & 0x80 != 0;
+    let infinity_flag = bytes[0] & 0x40 != 0;
+    let sort_flag = bytes[0] & 0x20 != 0;
+
+    let is_valid = compression_flag
+        && (!infinity_flag
+            || (!sort_flag && bytes[0] & 0x1f == 0 && bytes[1..].iter().all(|byte| *byte == 0)));
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidFlagBits)
+    }
+}
+
 /// Implemented on some struct from a BLS library so it may be used as the `point` in a
 /// `GenericPublicKey`.
 pub trait TPublicKey: Sized + Clone {
     /// Serialize `self` as compressed bytes.
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN];
 
+    /// Writes the compressed bytes of `self` into `out`, which must be exactly `PUBLIC_KEY_BYTES_LEN`
+    /// bytes.
+    ///
+    /// The default implementation copies the result of `Self::serialize`.
+    fn serialize_into(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.serialize())
+    }
+
     /// Deserialize `self` from compressed bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Deserialize `self` from compressed bytes, ensuring the point is in the G1 subgroup.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Returns `self` multiplied by the big-endian `scalar`, which must be less than the curve
+    /// order.
+    fn mul(&self, scalar: &ZeroizeHash) -> Self;
 }
 
 /// A BLS aggregate public key that is generic across some BLS point (`Pub`).
@@ -51,22 +99,188 @@ where
         &self.point
     }
 
+    /// Returns `true` if `self` is the public key at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.is_infinity
+    }
+
     /// Returns `self.serialize()` as a `0x`-prefixed hex string.
     pub fn to_hex_string(&self) -> String {
         format!("{:?}", self)
     }
 
+    /// Returns the first four bytes of `sha256(self.serialize())`.
+    ///
+    /// This is a short, stable identifier for correlating public keys in logs and metrics. It is
+    /// *not* collision-resistant and must never be used for security purposes.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let mut fingerprint = [0; 4];
+        fingerprint.copy_from_slice(&hash(&self.serialize())[..4]);
+        fingerprint
+    }
+
+    /// Returns `self.fingerprint()` as a `0x`-prefixed hex string.
+    pub fn fingerprint_hex(&self) -> String {
+        hex_encode(self.fingerprint())
+    }
+
     /// Serialize `self` as compressed bytes.
     pub fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         self.point.serialize()
     }
 
+    impl_serialize_into!(PUBLIC_KEY_BYTES_LEN);
+
+    /// Writes the compressed bytes of `self` into `out`, which is exactly `PUBLIC_KEY_BYTES_LEN`.
+    fn write_compressed(&self, out: &mut [u8]) {
+        self.point.serialize_into(out)
+    }
+
+    impl_deserialize_from!(PUBLIC_KEY_BYTES_LEN);
+
     /// Deserialize `self` from compressed bytes.
+    ///
+    /// The point is *not* checked to be in the G1 subgroup, so this function should only be used
+    /// on trusted bytes (e.g., those read from our own database). Bytes received from the network
+    /// or the Eth1 deposit contract should be deserialized with `Self::deserialize_checked`.
+    ///
+    /// Returns `Error::InvalidFlagBits` if the flag bits are malformed (e.g., the infinity flag is
+    /// set with a non-zero `x`).
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
-        Ok(Self {
-            point: Pub::deserialize(bytes)?,
-            is_infinity: bytes == &INFINITY_PUBLIC_KEY[..],
-        })
+        Self::deserialize_with(bytes, Pub::deserialize)
+    }
+
+    /// Serialize each of `pubkeys` as compressed bytes, concatenated into a single buffer of
+    /// `N * PUBLIC_KEY_BYTES_LEN` bytes (e.g., for storing a sync committee on disk).
+    ///
+    /// Decode with `Self::deserialize_many_checked`.
+    pub fn serialize_many(pubkeys: &[Self]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(pubkeys.len() * PUBLIC_KEY_BYTES_LEN);
+        for pubkey in pubkeys {
+            bytes.extend_from_slice(&pubkey.serialize());
+        }
+        bytes
+    }
+
+    /// Deserialize many public keys which have been concatenated as compressed bytes (i.e., as
+    /// produced by `Self::serialize_many`), as per `Self::deserialize_checked`.
+    ///
+    /// Returns `Error::InvalidByteLength` if `bytes` is not a multiple of `PUBLIC_KEY_BYTES_LEN`.
+    pub fn deserialize_many_checked(bytes: &[u8]) -> Result<Vec<Self>, Error> {
+        if bytes.len() % PUBLIC_KEY_BYTES_LEN != 0 {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: PUBLIC_KEY_BYTES_LEN,
+            });
+        }
+
+        bytes
+            .chunks(PUBLIC_KEY_BYTES_LEN)
+            .map(Self::deserialize_checked)
+            .collect()
+    }
+
+    /// Equivalent to `Self::deserialize_checked`, except the bytes are taken as an array.
+    pub fn from_bytes_checked(bytes: [u8; PUBLIC_KEY_BYTES_LEN]) -> Result<Self, Error> {
+        Self::deserialize_checked(&bytes)
+    }
+
+    /// Deserialize `self` from compressed bytes, returning `Error::InvalidSubgroup` if the point
+    /// is not in the G1 subgroup.
+    ///
+    /// Returns `Error::InvalidFlagBits` if the flag bits are malformed.
+    pub fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_with(bytes, Pub::deserialize_checked)
+    }
+
+    /// Returns `true` if `signature` is a valid proof of possession of the secret key for `self`.
+    ///
+    /// Always returns `false` if `self` is the public key at infinity, since there is no secret
+    /// key to possess. See `GenericSecretKey::sign_proof_of_possession`.
+    pub fn verify_proof_of_possession<Sig>(&self, signature: &GenericSignature<Pub, Sig>) -> bool
+    where
+        Sig: TSignatureWithDst<Pub>,
+    {
+        if self.is_infinity {
+            return false;
+        }
+
+        match signature.point() {
+            Some(point) => point.verify_with_dst(&self.point, &self.serialize(), POP_DST),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `signature` is a signature across `msg` by `self`.
+    ///
+    /// Equivalent to `GenericSignature::verify`. Returns `false` if `signature` is the "empty"
+    /// value (i.e., `NONE_SIGNATURE`), without attempting to use it as a point.
+    pub fn verify<Sig, M>(&self, signature: &GenericSignature<Pub, Sig>, msg: M) -> bool
+    where
+        Sig: TSignature<Pub>,
+        M: SignableMessage,
+    {
+        self.verify_checked(signature, msg).is_ok()
+    }
+
+    /// Returns `Ok(())` if `signature` is a signature across `msg` by `self`.
+    ///
+    /// Equivalent to `Self::verify`, except the reason for a failed verification is returned:
+    ///
+    /// - `Error::EmptySignature` if `signature` is the "empty" value.
+    /// - `Error::InvalidSignature` if the signature is invalid.
+    pub fn verify_checked<Sig, M>(
+        &self,
+        signature: &GenericSignature<Pub, Sig>,
+        msg: M,
+    ) -> Result<(), Error>
+    where
+        Sig: TSignature<Pub>,
+        M: SignableMessage,
+    {
+        signature.verify_checked(self, msg)
+    }
+
+    /// Returns `true` if `signature` is an aggregate of signatures by `self` across each of `msgs`.
+    ///
+    /// Equivalent to `GenericAggregateSignature::aggregate_verify` with `self` repeated once for
+    /// each of `msgs`. Returns `false` if `msgs` is empty.
+    pub fn verify_aggregate<AggPub, Sig, AggSig>(
+        &self,
+        signature: &GenericAggregateSignature<Pub, AggPub, Sig, AggSig>,
+        msgs: &[Hash256],
+    ) -> bool
+    where
+        AggPub: TAggregatePublicKey<Pub> + Clone,
+        Sig: TSignature<Pub>,
+        AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+    {
+        if msgs.is_empty() {
+            return false;
+        }
+
+        let pubkeys = vec![self; msgs.len()];
+        signature.aggregate_verify(msgs, &pubkeys)
+    }
+
+    /// Deserialize `self` from compressed bytes, using `deserialize` to decode the point.
+    fn deserialize_with<F>(bytes: &[u8], deserialize: F) -> Result<Self, Error>
+    where
+        F: Fn(&[u8]) -> Result<Pub, Error>,
+    {
+        if bytes.len() != PUBLIC_KEY_BYTES_LEN {
+            Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: PUBLIC_KEY_BYTES_LEN,
+            })
+        } else {
+            check_flag_bits(bytes)?;
+
+            Ok(Self {
+                point: deserialize(bytes)?,
+                is_infinity: bytes == &INFINITY_PUBLIC_KEY[..],
+            })
+        }
     }
 }
 
@@ -85,6 +299,23 @@ impl<Pub: TPublicKey> Hash for GenericPublicKey<Pub> {
     }
 }
 
+/// Orders lexicographically by the `self.serialize()` bytes.
+///
+/// This ordering is only useful for sorting and deduplication (e.g., in a `BTreeMap`); it has no
+/// relationship to the structure of the curve.
+impl<Pub: TPublicKey> PartialOrd for GenericPublicKey<Pub> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// See the `PartialOrd` implementation.
+impl<Pub: TPublicKey> Ord for GenericPublicKey<Pub> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.serialize()[..].cmp(&other.serialize()[..])
+    }
+}
+
 impl<Pub: TPublicKey> Encode for GenericPublicKey<Pub> {
     impl_ssz_encode!(PUBLIC_KEY_BYTES_LEN);
 }
@@ -109,6 +340,18 @@ impl<Pub: TPublicKey> fmt::Debug for GenericPublicKey<Pub> {
     impl_debug!();
 }
 
+impl<Pub: TPublicKey> TryFrom<&[u8]> for GenericPublicKey<Pub> {
+    impl_try_from_slice!(PUBLIC_KEY_BYTES_LEN);
+}
+
+impl<Pub: TPublicKey> TryFrom<Vec<u8>> for GenericPublicKey<Pub> {
+    impl_try_from_vec!();
+}
+
+impl<Pub: TPublicKey> TryFrom<&str> for GenericPublicKey<Pub> {
+    impl_try_from_str!(PUBLIC_KEY_BYTES_LEN);
+}
+
 #[cfg(feature = "arbitrary")]
 impl<Pub: TPublicKey + 'static> arbitrary::Arbitrary for GenericPublicKey<Pub> {
     impl_arbitrary!(PUBLIC_KEY_BYTES_LEN);
@@ -1,22 +1,48 @@
 use crate::{
     generic_public_key::{GenericPublicKey, TPublicKey},
     generic_signature::{GenericSignature, TSignature},
+    scalar::Scalar,
     Error, Hash256, ZeroizeHash,
 };
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::marker::PhantomData;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 /// The byte-length of a BLS secret key.
 pub const SECRET_KEY_BYTES_LEN: usize = 32;
 
+/// The domain separation tag used for signing messages, as per the Eth2 specification.
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The domain separation tag used for proofs of possession.
+///
+/// This is distinct from the DST used for signing messages, so a signature across a message can
+/// never be used as a proof of possession.
+pub const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The order of the BLS12-381 scalar field, as big-endian bytes.
+///
+/// A valid secret key must be non-zero and less than this value.
+pub(crate) const CURVE_ORDER: [u8; SECRET_KEY_BYTES_LEN] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
 /// Implemented on some struct from a BLS library so it may be used as the `point` in a
 /// `GenericSecretKey`.
 pub trait TSecretKey<SignaturePoint, PublicKeyPoint>: Sized {
-    /// Instantiate `Self` from some secure source of entropy.
-    fn random() -> Self;
+    /// Instantiate `Self` using entropy from `rng`.
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self;
 
     /// Signs `msg`.
     fn sign(&self, msg: Hash256) -> SignaturePoint;
 
+    /// Signs the arbitrary-length `msg`, using the Eth2 `DST`.
+    fn sign_bytes(&self, msg: &[u8]) -> SignaturePoint;
+
     /// Returns the public key that corresponds to self.
     fn public_key(&self) -> PublicKeyPoint;
 
@@ -25,10 +51,32 @@ pub trait TSecretKey<SignaturePoint, PublicKeyPoint>: Sized {
 
     /// Deserialize `self` from compressed bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Returns a `Choice` which is true if the big-endian `bytes` represent a non-zero scalar
+    /// which is less than the `CURVE_ORDER`, as determined by the backend.
+    fn is_valid_scalar(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice;
+
+    /// Overwrite the secret material in `self` with zeros.
+    fn zeroize(&mut self);
+}
+
+/// Implemented on some struct from a BLS library which supports signing with an arbitrary domain
+/// separation tag (DST).
+pub trait TSecretKeyWithDst<SignaturePoint, PublicKeyPoint>:
+    TSecretKey<SignaturePoint, PublicKeyPoint>
+{
+    /// Signs `msg` using the `dst` domain separation tag.
+    fn sign_with_dst(&self, msg: &[u8], dst: &[u8]) -> SignaturePoint;
 }
 
+/// A BLS secret key that is generic across some BLS point (`Sec`).
+///
+/// The secret material is zeroized when `self` is dropped.
 #[derive(Clone)]
-pub struct GenericSecretKey<Sig, Pub, Sec> {
+pub struct GenericSecretKey<Sig, Pub, Sec>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
     /// The underlying point which performs *actual* cryptographic operations.
     point: Sec,
     _phantom_signature: PhantomData<Sig>,
@@ -41,10 +89,17 @@ where
     Pub: TPublicKey,
     Sec: TSecretKey<Sig, Pub>,
 {
-    /// Instantiate `Self` from some secure source of entropy.
+    /// Instantiate `Self` from some secure source of entropy (the operating system's RNG).
     pub fn random() -> Self {
+        Self::random_with_rng(&mut OsRng)
+    }
+
+    /// Instantiate `Self` using entropy from `rng`.
+    ///
+    /// Useful in environments where the operating system's RNG is unavailable.
+    pub fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
         Self {
-            point: Sec::random(),
+            point: Sec::random_with_rng(rng),
             _phantom_signature: PhantomData,
             _phantom_public_key: PhantomData,
         }
@@ -56,6 +111,17 @@ where
         GenericSignature::from_point(self.point.sign(msg), is_infinity)
     }
 
+    /// Signs the arbitrary-length `msg` (e.g., a payload for a protocol which does not sign
+    /// 32-byte roots).
+    ///
+    /// `msg` is hashed directly to a curve point, so it is not pre-hashed to 32 bytes. Signing
+    /// the bytes of a `Hash256` is equivalent to `Self::sign`. Verify with
+    /// `GenericSignature::verify_bytes`.
+    pub fn sign_bytes(&self, msg: &[u8]) -> GenericSignature<Pub, Sig> {
+        let is_infinity = false;
+        GenericSignature::from_point(self.point.sign_bytes(msg), is_infinity)
+    }
+
     /// Returns the public key that corresponds to self.
     pub fn public_key(&self) -> GenericPublicKey<Pub> {
         let is_infinity = false;
@@ -67,18 +133,25 @@ where
     /// ## Note
     ///
     /// The bytes that are returned are the unencrypted secret key. This is sensitive cryptographic
-    /// material.
+    /// material. The returned `ZeroizeHash` is zeroized when it is dropped.
     pub fn serialize(&self) -> ZeroizeHash {
         self.point.serialize()
     }
 
     /// Deserialize `self` from compressed bytes.
+    ///
+    /// Returns `Error::InvalidSecretKeyBytes` if `bytes` is not a valid scalar, as per
+    /// `TSecretKey::is_valid_scalar`.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() != SECRET_KEY_BYTES_LEN {
             Err(Error::InvalidSecretKeyLength {
                 got: bytes.len(),
                 expected: SECRET_KEY_BYTES_LEN,
             })
+        } else if !bool::from(Sec::is_valid_scalar(
+            bytes.try_into().expect("length is checked above"),
+        )) {
+            Err(Error::InvalidSecretKeyBytes)
         } else {
             Ok(Self {
                 point: Sec::deserialize(bytes)?,
@@ -87,4 +160,185 @@ where
             })
         }
     }
+
+    /// Deserialize `self` from compressed bytes, returning a `CtOption` which is "none" if `bytes`
+    /// is not a valid scalar.
+    ///
+    /// ## Threat model
+    ///
+    /// Intended for secret key shares (e.g., for threshold signing), where whether a share is
+    /// present or valid may correlate with which participants took part. An attacker who can
+    /// time the signer should not learn this, so this function does not branch on `bytes`: the
+    /// range check is computed with `subtle` (rather than `TSecretKey::is_valid_scalar`, which
+    /// may branch), an invalid scalar is replaced by a fixed valid scalar using a constant-time
+    /// select and the backend always deserializes a valid key. Callers must likewise avoid
+    /// branching on the result (e.g., use `CtOption::unwrap_or` or `ConditionallySelectable`),
+    /// rather than `is_some`.
+    ///
+    /// This does not protect against timing leaks *within* the backend (e.g., when decoding the
+    /// selected scalar or during signing), nor does it hide the secret key from an attacker who
+    /// can read our memory.
+    pub fn deserialize_ct(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> CtOption<Self> {
+        let is_valid = Scalar::is_valid_secret_key(bytes);
+
+        let mut fallback = [0; SECRET_KEY_BYTES_LEN];
+        fallback[SECRET_KEY_BYTES_LEN - 1] = 1;
+
+        let mut candidate = [0; SECRET_KEY_BYTES_LEN];
+        for (candidate, (byte, fallback)) in
+            candidate.iter_mut().zip(bytes.iter().zip(fallback.iter()))
+        {
+            *candidate = u8::conditional_select(fallback, byte, is_valid);
+        }
+
+        let point = Sec::deserialize(&candidate).expect("candidate is always a valid scalar");
+        candidate.zeroize();
+
+        CtOption::new(
+            Self {
+                point,
+                _phantom_signature: PhantomData,
+                _phantom_public_key: PhantomData,
+            },
+            is_valid,
+        )
+    }
+}
+
+impl<Sig, Pub, Sec> GenericSecretKey<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKeyWithDst<Sig, Pub>,
+{
+    /// Signs `msg` using the `dst` domain separation tag.
+    ///
+    /// Signatures produced with a DST other than `DST` are not valid Eth2 signatures; this is
+    /// intended for other protocols (e.g., with a different ciphersuite). Verify with
+    /// `GenericSignature::verify_with_dst`.
+    pub fn sign_with_dst(&self, msg: &[u8], dst: &[u8]) -> GenericSignature<Pub, Sig> {
+        let is_infinity = false;
+        GenericSignature::from_point(self.point.sign_with_dst(msg, dst), is_infinity)
+    }
+
+    /// Returns a proof that the holder of `self` possesses the secret key, by signing the
+    /// compressed bytes of `self.public_key()` under the `POP_DST`.
+    ///
+    /// Verify with `GenericPublicKey::verify_proof_of_possession`.
+    pub fn sign_proof_of_possession(&self) -> GenericSignature<Pub, Sig> {
+        let pubkey_bytes = self.point.public_key().serialize();
+        let is_infinity = false;
+        GenericSignature::from_point(
+            self.point.sign_with_dst(&pubkey_bytes, POP_DST),
+            is_infinity,
+        )
+    }
+}
+
+/// Derives the public key of a secret key, without consuming it (see
+/// `GenericSecretKey::public_key`).
+impl<Sig, Pub, Sec> From<&GenericSecretKey<Sig, Pub, Sec>> for GenericPublicKey<Pub>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn from(secret_key: &GenericSecretKey<Sig, Pub, Sec>) -> Self {
+        secret_key.public_key()
+    }
+}
+
+/// Parses `0x`-prefixed, big-endian hex (e.g., from a CLI flag).
+///
+/// The intermediate bytes are zeroized, however the caller is responsible for `s`.
+impl<Sig, Pub, Sec> TryFrom<&str> for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if !s.starts_with("0x") {
+            return Err(Error::InvalidHex("missing 0x prefix".to_string()));
+        }
+
+        // The `hex` error is discarded, since it may include a character of the secret key.
+        let mut bytes =
+            hex::decode(&s[2..]).map_err(|_| Error::InvalidHex("invalid hex".into()))?;
+        let secret_key = if bytes.len() != SECRET_KEY_BYTES_LEN {
+            Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SECRET_KEY_BYTES_LEN,
+            })
+        } else {
+            Self::deserialize(&bytes)
+        };
+        bytes.zeroize();
+        secret_key
+    }
+}
+
+/// Compares the serialized bytes of each secret key in constant time.
+impl<Sig, Pub, Sec> ConstantTimeEq for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.serialize()
+            .as_bytes()
+            .ct_eq(other.serialize().as_bytes())
+    }
+}
+
+/// Defers to `ConstantTimeEq` to avoid leaking timing information about the secret keys.
+impl<Sig, Pub, Sec> PartialEq for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<Sig, Pub, Sec> Eq for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+}
+
+/// Never prints the secret key (not even a fingerprint), so a `SecretKey` may be safely included
+/// in logs and error messages. Use `Self::serialize` to export the key material.
+impl<Sig, Pub, Sec> fmt::Debug for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretKey(<redacted>)")
+    }
+}
+
+impl<Sig, Pub, Sec> Zeroize for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn zeroize(&mut self) {
+        self.point.zeroize()
+    }
+}
+
+impl<Sig, Pub, Sec> Drop for GenericSecretKey<Sig, Pub, Sec>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
+    fn drop(&mut self) {
+        self.zeroize()
+    }
 }
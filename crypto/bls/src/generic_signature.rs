@@ -1,11 +1,16 @@
 use crate::{
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
     generic_public_key::{GenericPublicKey, TPublicKey},
-    Error, Hash256,
+    Error, SignableMessage, SignatureEnvelope, ZeroizeHash, SIGNATURE_ENVELOPE_BYTES_LEN,
+    SIGNATURE_ENVELOPE_VERSION,
 };
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
-use serde_hex::{encode as hex_encode, PrefixedHexVisitor};
+use serde_hex::encode as hex_encode;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 use ssz::{Decode, Encode};
+use std::convert::TryFrom;
 use std::fmt;
 use std::marker::PhantomData;
 use tree_hash::TreeHash;
@@ -13,7 +18,14 @@ use tree_hash::TreeHash;
 /// The byte-length of a BLS signature when serialized in compressed form.
 pub const SIGNATURE_BYTES_LEN: usize = 96;
 
+/// The byte-length of a BLS signature when serialized in uncompressed form.
+pub const SIGNATURE_UNCOMPRESSED_BYTES_LEN: usize = 192;
+
 /// Represents the signature at infinity.
+///
+/// This is the compressed encoding of the G2 point at infinity (i.e., the compression and
+/// infinity flags are set in the most-significant byte). It is a valid point and is distinct from
+/// `NONE_SIGNATURE`.
 pub const INFINITY_SIGNATURE: [u8; SIGNATURE_BYTES_LEN] = [
     0xc0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -22,19 +34,101 @@ pub const INFINITY_SIGNATURE: [u8; SIGNATURE_BYTES_LEN] = [
 ];
 
 /// The compressed bytes used to represent `GenericSignature::empty()`.
+///
+/// The all-zeros encoding is not a valid point; it is only used to satisfy the Eth2
+/// specification, which expects the all-zeros signature to be meaningful. It is distinct from
+/// `INFINITY_SIGNATURE`.
 pub const NONE_SIGNATURE: [u8; SIGNATURE_BYTES_LEN] = [0; SIGNATURE_BYTES_LEN];
 
+/// The modulus of the BLS12-381 base field, as big-endian bytes.
+const FIELD_MODULUS: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6, 0x43, 0x4b, 0xac, 0xd7,
+    0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf, 0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24,
+    0x1e, 0xab, 0xff, 0xfe, 0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+];
+
+/// Returns `Ok(())` if `bytes` is the canonical compressed encoding of a G2 point, as per the
+/// serialization format used by the Eth2 specification:
+///
+/// - The compression flag must be set.
+/// - If the infinity flag is set, the sort flag and all other bits must be zero.
+/// - Otherwise, both coordinates of `x` must be less than the field modulus.
+///
+/// This does *not* check that the point is on the curve or in the G2 subgroup. The
+/// `NONE_SIGNATURE` is not canonical; callers that accept it must do so explicitly.
+fn check_canonical_encoding(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.len() != SIGNATURE_BYTES_LEN {
+        return Err(Error::InvalidByteLength {
+            got: bytes.len(),
+            expected: SIGNATURE_BYTES_LEN,
+        });
+    }
+
+    let compression_flag = bytes[0] & 0x80 != 0;
+    let infinity_flag = bytes[0] & 0x40 != 0;
+    let sort_flag = bytes[0] & 0x20 != 0;
+
+    let is_canonical = if !compression_flag {
+        false
+    } else if infinity_flag {
+        !sort_flag && bytes[0] & 0x1f == 0 && bytes[1..].iter().all(|byte| *byte == 0)
+    } else {
+        // `x` is encoded as `x.c1 || x.c0`, with the flags in the three most-significant bits.
+        let mut x_c1 = [0; 48];
+        x_c1.copy_from_slice(&bytes[..48]);
+        x_c1[0] &= 0x1f;
+        x_c1[..] < FIELD_MODULUS[..] && bytes[48..] < FIELD_MODULUS[..]
+    };
+
+    if is_canonical {
+        Ok(())
+    } else {
+        Err(Error::NonCanonicalEncoding)
+    }
+}
+
 /// Implemented on some struct from a BLS library so it may be used as the `point` in an
 /// `GenericSignature`.
 pub trait TSignature<GenericPublicKey>: Sized + Clone {
     /// Serialize `self` as compressed bytes.
     fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN];
 
+    /// Writes the compressed bytes of `self` into `out`, which must be exactly `SIGNATURE_BYTES_LEN`
+    /// bytes.
+    ///
+    /// The default implementation copies the result of `Self::serialize`.
+    fn serialize_into(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.serialize())
+    }
+
     /// Deserialize `self` from compressed bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error>;
 
+    /// Deserialize `self` from compressed bytes, ensuring the point is in the G2 subgroup.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Returns `Error::NonCanonicalEncoding` if `bytes` is not the canonical encoding of a point.
+    ///
+    /// The default implementation enforces the Eth2 serialization format. Backends which do not
+    /// use real curve points override it to accept any bytes.
+    fn check_encoding(bytes: &[u8]) -> Result<(), Error> {
+        check_canonical_encoding(bytes)
+    }
+
     /// Returns `true` if `self` is a signature across `msg` by `pubkey`.
-    fn verify(&self, pubkey: &GenericPublicKey, msg: Hash256) -> bool;
+    fn verify<M: SignableMessage>(&self, pubkey: &GenericPublicKey, msg: M) -> bool;
+
+    /// Returns `self` multiplied by the big-endian `scalar`, which must be less than the curve
+    /// order.
+    fn mul(&self, scalar: &ZeroizeHash) -> Self;
+}
+
+/// Implemented on some struct from a BLS library which supports verifying against an arbitrary
+/// domain separation tag (DST).
+pub trait TSignatureWithDst<GenericPublicKey>: TSignature<GenericPublicKey> {
+    /// Returns `true` if `self` is a signature across `msg` by `pubkey`, using the `dst` domain
+    /// separation tag.
+    fn verify_with_dst(&self, pubkey: &GenericPublicKey, msg: &[u8], dst: &[u8]) -> bool;
 }
 
 /// A BLS signature that is generic across:
@@ -84,6 +178,11 @@ where
         self.point.as_ref()
     }
 
+    /// Consumes `self`, returning the underlying BLS point.
+    pub(crate) fn into_point(self) -> Option<Sig> {
+        self.point
+    }
+
     /// Instantiates `Self` from a `point`.
     pub(crate) fn from_point(point: Sig, is_infinity: bool) -> Self {
         Self {
@@ -102,12 +201,97 @@ where
         }
     }
 
+    /// Serialize `self` as compressed bytes, stored inline in a `SmallVec`.
+    ///
+    /// Equivalent to `Self::serialize`, except the result can be stored in collections which
+    /// expect a vector without a heap allocation per signature. Only available with the
+    /// `smallvec` feature.
+    #[cfg(feature = "smallvec")]
+    pub fn serialize_smallvec(&self) -> SmallVec<[u8; SIGNATURE_BYTES_LEN]> {
+        SmallVec::from_buf(self.serialize())
+    }
+
+    impl_serialize_into!(SIGNATURE_BYTES_LEN);
+
+    /// Writes the compressed bytes of `self` into `out`, which is exactly `SIGNATURE_BYTES_LEN`.
+    fn write_compressed(&self, out: &mut [u8]) {
+        if let Some(point) = &self.point {
+            point.serialize_into(out)
+        } else {
+            out.copy_from_slice(&NONE_SIGNATURE)
+        }
+    }
+
+    impl_deserialize_from!(SIGNATURE_BYTES_LEN);
+
     /// Deserialize `self` from compressed bytes.
+    ///
+    /// The point is *not* checked to be in the G2 subgroup. This is the path taken by the SSZ
+    /// `Decode` impl and `GenericSignatureBytes::decompress`, so it decodes the signatures which
+    /// consensus receives from the network: those in gossip and RPC objects (e.g.,
+    /// `SignedBeaconBlock`, `SignedVoluntaryExit` and `SignedAggregateAndProof`) and in deposits.
+    /// Those callers rely on verification to reject a point outside of G2 (`blst` checks the
+    /// subgroup during the pairing), so the result must not be used (e.g., aggregated and
+    /// republished) until it has been verified.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_with(bytes, Sig::deserialize)
+    }
+
+    /// Deserialize `self` from compressed bytes, returning `Error::InvalidSubgroup` if the point
+    /// is not in the G2 subgroup and `Error::NonCanonicalEncoding` if the bytes are not its
+    /// canonical encoding (see `TSignature::check_encoding`).
+    ///
+    /// For untrusted bytes which may be used before (or without) being verified. Within this
+    /// crate, `Self::decode_versioned` uses it; no consensus code currently does, since SSZ
+    /// decoding goes through `Self::deserialize`.
+    pub fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize_with(bytes, |bytes| {
+            Sig::check_encoding(bytes)?;
+            Sig::deserialize_checked(bytes)
+        })
+    }
+
+    /// Serialize `self` as a `SignatureEnvelope` with the current version (i.e., a version tag
+    /// followed by the compressed bytes).
+    pub fn encode_versioned(&self) -> [u8; SIGNATURE_ENVELOPE_BYTES_LEN] {
+        SignatureEnvelope::new(SIGNATURE_ENVELOPE_VERSION, self.serialize())
+            .expect("current version is supported")
+            .serialize()
+    }
+
+    /// Deserialize `self` from a `SignatureEnvelope`, as produced by `Self::encode_versioned`.
+    ///
+    /// The payload is deserialized with `Self::deserialize_checked`, since versioned bytes are
+    /// expected to come from the network.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::UnsupportedVersion` if the version tag is unknown.
+    /// - Any error from `Self::deserialize_checked`.
+    pub fn decode_versioned(bytes: &[u8]) -> Result<Self, Error> {
+        let envelope = SignatureEnvelope::deserialize(bytes)?;
+        Self::deserialize_checked(envelope.payload())
+    }
+
+    /// Deserialize `self` from compressed bytes, using `deserialize` to decode any non-empty point.
+    ///
+    /// The `NONE_SIGNATURE` is deliberately accepted and decoded as `Self::empty()`, which never
+    /// verifies.
+    fn deserialize_with<F>(bytes: &[u8], deserialize: F) -> Result<Self, Error>
+    where
+        F: Fn(&[u8]) -> Result<Sig, Error>,
+    {
+        if bytes.len() != SIGNATURE_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_BYTES_LEN,
+            });
+        }
+
         let point = if bytes == &NONE_SIGNATURE[..] {
             None
         } else {
-            Some(Sig::deserialize(bytes)?)
+            Some(deserialize(bytes)?)
         };
 
         Ok(Self {
@@ -116,6 +300,20 @@ where
             _phantom: PhantomData,
         })
     }
+
+    /// Aggregates `self` onto `aggregate`, consuming `self`.
+    ///
+    /// Equivalent to `aggregate.add_assign(&self)`, except that if `aggregate` is "empty" then the
+    /// point in `self` is moved into it, rather than being added onto a newly-created infinity
+    /// point. Does nothing if `self` is "empty".
+    pub fn aggregate_into<AggPub, AggSig>(
+        self,
+        aggregate: &mut GenericAggregateSignature<Pub, AggPub, Sig, AggSig>,
+    ) where
+        AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+    {
+        aggregate.add_assign_owned(self)
+    }
 }
 
 impl<Pub, Sig> GenericSignature<Pub, Sig>
@@ -124,15 +322,69 @@ where
     Pub: TPublicKey + Clone,
 {
     /// Returns `true` if `self` is a signature across `msg` by `pubkey`.
-    pub fn verify(&self, pubkey: &GenericPublicKey<Pub>, msg: Hash256) -> bool {
-        if self.is_infinity && pubkey.is_infinity {
-            return true;
+    pub fn verify<M: SignableMessage>(&self, pubkey: &GenericPublicKey<Pub>, msg: M) -> bool {
+        self.verify_checked(pubkey, msg).is_ok()
+    }
+
+    /// Returns `true` if `self` is a signature across the arbitrary-length `msg` by `pubkey`.
+    ///
+    /// Equivalent to `Self::verify`. See `GenericSecretKey::sign_bytes`.
+    pub fn verify_bytes(&self, pubkey: &GenericPublicKey<Pub>, msg: &[u8]) -> bool {
+        self.verify(pubkey, msg)
+    }
+
+    /// Returns `Ok(())` if `self` is a signature across `msg` by `pubkey`.
+    ///
+    /// Equivalent to `Self::verify`, except the reason for a failed verification is returned:
+    ///
+    /// - `Error::InvalidInfinityPublicKey` if `pubkey` is the public key at infinity and `self` is
+    ///     not the infinity signature.
+    /// - `Error::EmptySignature` if `self` is the "empty" value.
+    /// - `Error::InvalidSignature` if the signature is invalid.
+    pub fn verify_checked<M: SignableMessage>(
+        &self,
+        pubkey: &GenericPublicKey<Pub>,
+        msg: M,
+    ) -> Result<(), Error> {
+        if pubkey.is_infinity {
+            return if self.is_infinity {
+                Ok(())
+            } else {
+                Err(Error::InvalidInfinityPublicKey)
+            };
         }
 
-        if let Some(point) = &self.point {
-            point.verify(pubkey.point(), msg)
-        } else {
-            false
+        match &self.point {
+            Some(point) => {
+                if point.verify(pubkey.point(), msg) {
+                    Ok(())
+                } else {
+                    Err(Error::InvalidSignature)
+                }
+            }
+            None => Err(Error::EmptySignature),
+        }
+    }
+}
+
+impl<Pub, Sig> GenericSignature<Pub, Sig>
+where
+    Sig: TSignatureWithDst<Pub>,
+    Pub: TPublicKey + Clone,
+{
+    /// Returns `true` if `self` is a signature across `msg` by `pubkey`, using the `dst` domain
+    /// separation tag.
+    ///
+    /// Always returns `false` if `pubkey` is the public key at infinity or `self` is the "empty"
+    /// value. See `GenericSecretKey::sign_with_dst`.
+    pub fn verify_with_dst(&self, pubkey: &GenericPublicKey<Pub>, msg: &[u8], dst: &[u8]) -> bool {
+        if pubkey.is_infinity {
+            return false;
+        }
+
+        match &self.point {
+            Some(point) => point.verify_with_dst(pubkey.point(), msg, dst),
+            None => false,
         }
     }
 }
@@ -161,6 +413,18 @@ impl<PublicKey, T: TSignature<PublicKey>> fmt::Debug for GenericSignature<Public
     impl_debug!();
 }
 
+impl<PublicKey, T: TSignature<PublicKey>> TryFrom<&[u8]> for GenericSignature<PublicKey, T> {
+    impl_try_from_slice!(SIGNATURE_BYTES_LEN);
+}
+
+impl<PublicKey, T: TSignature<PublicKey>> TryFrom<Vec<u8>> for GenericSignature<PublicKey, T> {
+    impl_try_from_vec!();
+}
+
+impl<PublicKey, T: TSignature<PublicKey>> TryFrom<&str> for GenericSignature<PublicKey, T> {
+    impl_try_from_str!(SIGNATURE_BYTES_LEN);
+}
+
 #[cfg(feature = "arbitrary")]
 impl<PublicKey: 'static, T: TSignature<PublicKey> + 'static> arbitrary::Arbitrary
     for GenericSignature<PublicKey, T>
@@ -0,0 +1,352 @@
+use crate::{
+    generic_aggregate_public_key::TAggregatePublicKey,
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    generic_signature::{GenericSignature, TSignature},
+    generic_signature_set::WrappedSignature,
+    Error, Hash256,
+};
+use rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::borrow::Cow;
+
+/// A signature across one or more messages by multiple public keys.
+///
+/// The `messages` are interpreted as follows:
+///
+/// - A single message: all `signing_keys` have signed that message.
+/// - One message per signing key: each `signing_keys[i]` has signed `messages[i]`.
+#[derive(Clone)]
+pub struct GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey + Clone,
+    AggPub: Clone,
+    Sig: Clone,
+    AggSig: Clone,
+{
+    pub signature: Cow<'a, GenericAggregateSignature<Pub, AggPub, Sig, AggSig>>,
+    pub messages: Vec<Hash256>,
+    pub signing_keys: Vec<Cow<'a, GenericPublicKey<Pub>>>,
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey + Clone,
+    AggPub: TAggregatePublicKey<Pub> + Clone,
+    Sig: TSignature<Pub> + Clone,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig> + Clone,
+{
+    /// Returns `Some(message)` if all of `self.signing_keys` have signed the same message.
+    fn common_message(&self) -> Option<Hash256> {
+        let first = *self.messages.first()?;
+
+        if self.messages.len() == 1
+            || (self.messages.len() == self.signing_keys.len()
+                && self.messages.iter().all(|message| *message == first))
+        {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self.signature` is a signature across `self.messages` by
+    /// `self.signing_keys`.
+    ///
+    /// Verifies `self` in isolation, without any batching.
+    pub fn verify(&self) -> bool {
+        let pubkeys = self
+            .signing_keys
+            .iter()
+            .map(|pk| pk.as_ref())
+            .collect::<Vec<_>>();
+
+        if let Some(message) = self.common_message() {
+            self.signature.fast_aggregate_verify(message, &pubkeys)
+        } else {
+            self.signature.aggregate_verify(&self.messages, &pubkeys)
+        }
+    }
+}
+
+/// A collection of signatures which can be verified with a single randomized batch verification,
+/// rather than verifying each signature individually.
+///
+/// If the batch verification fails, the batch is repeatedly bisected so that the invalid entries
+/// can be identified without verifying every entry individually.
+///
+/// A batch in which many entries are invalid (e.g., one sent by a malicious peer) requires many
+/// verifications to bisect. `Self::with_max_fallback_items` bounds this work.
+#[derive(Clone)]
+pub struct GenericSignatureBatch<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey + Clone,
+    AggPub: Clone,
+    Sig: Clone,
+    AggSig: Clone,
+{
+    entries: Vec<GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>>,
+    /// The maximum number of invalid entries to find before giving up, or `None` for no limit.
+    max_fallback_items: Option<usize>,
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> Default for GenericSignatureBatch<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey + Clone,
+    AggPub: Clone,
+    Sig: Clone,
+    AggSig: Clone,
+{
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            max_fallback_items: None,
+        }
+    }
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> GenericSignatureBatch<'a, Pub, AggPub, Sig, AggSig>
+where
+    Pub: TPublicKey + Clone,
+    AggPub: TAggregatePublicKey<Pub> + Clone,
+    Sig: TSignature<Pub> + Clone,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig> + Clone,
+{
+    /// Instantiate an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of invalid entries which `Self::verify` and
+    /// `Self::verify_attributed` will find before giving up. There is no limit by default.
+    pub fn with_max_fallback_items(mut self, max_fallback_items: usize) -> Self {
+        self.max_fallback_items = Some(max_fallback_items);
+        self
+    }
+
+    /// Adds a `signature` across `messages` by `signing_keys` to `self`.
+    ///
+    /// See `GenericSignatureBatchEntry` for the interpretation of `messages`.
+    pub fn push(
+        &mut self,
+        signature: impl Into<WrappedSignature<'a, Pub, AggPub, Sig, AggSig>>,
+        messages: Vec<Hash256>,
+        signing_keys: Vec<Cow<'a, GenericPublicKey<Pub>>>,
+    ) {
+        self.entries.push(GenericSignatureBatchEntry {
+            signature: signature.into().aggregate,
+            messages,
+            signing_keys,
+        })
+    }
+
+    /// Verifies each of the independent `(signature, public_key, message)` tuples in `items`.
+    ///
+    /// Returns a vector where index `i` is `true` if `items[i]` is valid. Only a single batch
+    /// verification is required if all `items` are valid, otherwise the invalid items are found
+    /// by bisection.
+    pub fn verify_each(
+        items: &'a [(GenericSignature<Pub, Sig>, GenericPublicKey<Pub>, Hash256)],
+    ) -> Vec<bool> {
+        let mut batch = Self::new();
+        for (signature, pubkey, message) in items {
+            batch.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+        }
+
+        // The batch has no budget, so every invalid item is found.
+        let mut valid = vec![true; items.len()];
+        if let Err(Error::InvalidSignatures(invalid)) = batch.verify_attributed() {
+            for i in invalid {
+                valid[i] = false;
+            }
+        }
+        valid
+    }
+
+    /// Verifies that each `items[i].1` is a signature by `pubkey` across `items[i].0`.
+    ///
+    /// Returns `Ok(())` if all `items` are valid, otherwise `Error::InvalidSignatures` with the
+    /// indices of the invalid items (see `Self::verify_attributed`). Only a single batch
+    /// verification is required if all `items` are valid.
+    pub fn verify_single_key(
+        pubkey: &'a GenericPublicKey<Pub>,
+        items: &[(Hash256, &'a GenericSignature<Pub, Sig>)],
+    ) -> Result<(), Error> {
+        let mut batch = Self::new();
+        for (message, signature) in items {
+            batch.push(*signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+        }
+        batch.verify_attributed()
+    }
+
+    /// Equivalent to `Self::verify_each`, except the random scalars for the batch verification
+    /// are derived deterministically from `rng_seed` (see `Self::seeded_rng`) and the result is
+    /// `true` only if all `items` are valid.
+    ///
+    /// Useful for reproducing a failed batch verification exactly. Production code should use a
+    /// non-seeded verification, which draws its scalars from `rand::thread_rng` (seeded from the
+    /// operating system's RNG).
+    pub fn verify_batch_seeded(
+        items: &'a [(GenericSignature<Pub, Sig>, GenericPublicKey<Pub>, Hash256)],
+        rng_seed: [u8; 32],
+    ) -> bool {
+        let mut batch = Self::new();
+        for (signature, pubkey, message) in items {
+            batch.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+        }
+        batch
+            .verify_attributed_with_rng(&mut Self::seeded_rng(rng_seed))
+            .is_ok()
+    }
+
+    /// Returns the RNG used by `Self::verify_batch_seeded`, from which the random scalars are
+    /// drawn.
+    ///
+    /// This is always ChaCha20 (unlike `rand::rngs::StdRng`, whose algorithm may change between
+    /// `rand` releases), so the same `rng_seed` produces the same scalars on every platform and
+    /// version.
+    pub fn seeded_rng(rng_seed: [u8; 32]) -> ChaCha20Rng {
+        ChaCha20Rng::from_seed(rng_seed)
+    }
+
+    /// Returns the entries in `self`.
+    pub fn entries(&self) -> &[GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>] {
+        &self.entries
+    }
+
+    /// Returns the number of entries in `self`.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if `self` has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if all entries in `self` are valid.
+    ///
+    /// An empty batch is invalid.
+    pub fn verify(&self) -> bool {
+        self.verify_attributed().is_ok()
+    }
+
+    /// Returns `Ok(())` if all entries in `self` are valid.
+    ///
+    /// ## Errors
+    ///
+    /// - `Error::InvalidSignatures` with the indices of the invalid entries. An empty batch
+    ///   returns an empty list of indices.
+    /// - `Error::TooManyInvalid` if more than the `max_fallback_items` (see
+    ///   `Self::with_max_fallback_items`) invalid entries were found, in which case bisection
+    ///   stops early. Useful for bounding the work spent on a batch from a peer which will be
+    ///   disconnected anyway.
+    pub fn verify_attributed(&self) -> Result<(), Error> {
+        self.verify_attributed_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Equivalent to `Self::verify_attributed`, except the random scalars for the batch
+    /// verification are drawn from `rng`.
+    pub fn verify_attributed_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(), Error> {
+        let max_invalid = self.max_fallback_items.unwrap_or_else(usize::max_value);
+
+        if self.entries.is_empty() {
+            return Err(Error::InvalidSignatures(vec![]));
+        }
+
+        let mut invalid = vec![];
+        Self::find_invalid(&self.entries, 0, &mut invalid, max_invalid, rng);
+
+        if invalid.len() > max_invalid {
+            Err(Error::TooManyInvalid)
+        } else if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignatures(invalid))
+        }
+    }
+
+    /// Pushes the indices of the invalid `entries` onto `invalid`, where `offset` is the index of
+    /// `entries[0]` in `self.entries`.
+    ///
+    /// Verifies `entries` as a single batch and, if that fails, bisects `entries` and recurses on
+    /// each half. Returns early once more than `max_invalid` indices are in `invalid`.
+    fn find_invalid<R: RngCore + CryptoRng>(
+        entries: &[GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>],
+        offset: usize,
+        invalid: &mut Vec<usize>,
+        max_invalid: usize,
+        rng: &mut R,
+    ) {
+        if invalid.len() > max_invalid {
+            return;
+        }
+
+        match entries {
+            [] => {}
+            [entry] => {
+                if !entry.verify() {
+                    invalid.push(offset)
+                }
+            }
+            _ => {
+                if !Self::verify_batch(entries, rng) {
+                    let mid = entries.len() / 2;
+                    Self::find_invalid(&entries[..mid], offset, invalid, max_invalid, rng);
+                    Self::find_invalid(&entries[mid..], offset + mid, invalid, max_invalid, rng);
+                }
+            }
+        }
+    }
+
+    /// Verifies all `entries` with a single randomized batch verification.
+    ///
+    /// Entries that cannot be expressed as a single message signed by some public keys (i.e.,
+    /// distinct messages or an infinity signature) are verified individually.
+    fn verify_batch<R: RngCore + CryptoRng>(
+        entries: &[GenericSignatureBatchEntry<'a, Pub, AggPub, Sig, AggSig>],
+        rng: &mut R,
+    ) -> bool {
+        let mut signatures = Vec::with_capacity(entries.len());
+        let mut messages = Vec::with_capacity(entries.len());
+        let mut pubkeys = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            match (entry.common_message(), entry.signature.point()) {
+                (Some(message), Some(point))
+                    if !entry.signature.is_infinity && !entry.signing_keys.is_empty() =>
+                {
+                    signatures.push(point);
+                    messages.push(message);
+                    pubkeys.push(
+                        entry
+                            .signing_keys
+                            .iter()
+                            .map(|pk| pk.as_ref())
+                            .collect::<Vec<_>>(),
+                    );
+                }
+                _ => {
+                    if !entry.verify() {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        // All entries have been verified individually.
+        if signatures.is_empty() {
+            return true;
+        }
+
+        let pubkey_refs = pubkeys
+            .iter()
+            .map(|signing_keys| signing_keys.as_slice())
+            .collect::<Vec<_>>();
+
+        AggSig::fast_aggregate_verify_multiple_with_rng(&signatures, &messages, &pubkey_refs, rng)
+    }
+}
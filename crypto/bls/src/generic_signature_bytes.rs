@@ -1,11 +1,11 @@
 use crate::{
     generic_public_key::TPublicKey,
     generic_signature::{GenericSignature, TSignature},
-    Error, INFINITY_SIGNATURE, SIGNATURE_BYTES_LEN,
+    Error, SIGNATURE_BYTES_LEN,
 };
 use serde::de::{Deserialize, Deserializer};
 use serde::ser::{Serialize, Serializer};
-use serde_hex::{encode as hex_encode, PrefixedHexVisitor};
+use serde_hex::encode as hex_encode;
 use ssz::{Decode, Encode};
 use std::convert::TryInto;
 use std::fmt;
@@ -32,10 +32,10 @@ where
 {
     /// Decompress and deserialize the bytes in `self` into an actual signature.
     ///
-    /// May fail if the bytes are invalid.
+    /// May fail if the bytes are invalid. The all-zeros bytes (as produced by `Self::empty`)
+    /// decompress to `GenericSignature::empty()`, which never verifies.
     pub fn decompress(&self) -> Result<GenericSignature<Pub, Sig>, Error> {
-        let is_infinity = self.bytes[..] == INFINITY_SIGNATURE[..];
-        Sig::deserialize(&self.bytes).map(|point| GenericSignature::from_point(point, is_infinity))
+        GenericSignature::deserialize(&self.bytes)
     }
 }
 
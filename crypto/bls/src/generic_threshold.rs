@@ -0,0 +1,184 @@
+//! Recovery of threshold signatures and public keys via Lagrange interpolation.
+//!
+//! A secret key `sk` may be split into `n` shares, `sk_i = f(i)`, where `f` is a random
+//! polynomial of degree `threshold - 1` with `f(0) = sk`. Any `threshold` of the signatures (or
+//! public keys) made with those shares may then be combined to recover the signature (or public
+//! key) that `sk` would have made.
+//!
+//! The Lagrange coefficients are computed with `Scalar` and applied with the backend's scalar
+//! multiplication.
+
+use crate::{
+    generic_aggregate_public_key::TAggregatePublicKey,
+    generic_aggregate_signature::TAggregateSignature,
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    generic_secret_key::{GenericSecretKey, TSecretKey},
+    generic_signature::{GenericSignature, TSignature},
+    scalar::Scalar,
+    Error, Hash256,
+};
+
+/// A secret key share, along with the (non-zero) index at which the secret key polynomial was
+/// evaluated to produce it.
+///
+/// Signatures made with `Self::sign` are tagged with the index, so they may be passed directly to
+/// `recover_signature`, which rejects any index that is repeated (e.g., a stale share).
+pub struct GenericSignerShare<Sig, Pub, Sec>
+where
+    Sec: TSecretKey<Sig, Pub>,
+{
+    index: u64,
+    secret_key: GenericSecretKey<Sig, Pub, Sec>,
+}
+
+impl<Sig, Pub, Sec> GenericSignerShare<Sig, Pub, Sec>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKey<Sig, Pub>,
+{
+    /// Instantiates `Self` from the `secret_key` share at `index`.
+    ///
+    /// Returns `Error::InvalidShareIndex` if `index` is zero, since that is the index of the
+    /// secret key which was split.
+    pub fn new(index: u64, secret_key: GenericSecretKey<Sig, Pub, Sec>) -> Result<Self, Error> {
+        if index == 0 {
+            Err(Error::InvalidShareIndex(index))
+        } else {
+            Ok(Self { index, secret_key })
+        }
+    }
+
+    /// Returns the index of this share.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Signs `msg`, returning the signature share tagged with `self.index()`.
+    pub fn sign(&self, msg: Hash256) -> (u64, GenericSignature<Pub, Sig>) {
+        (self.index, self.secret_key.sign(msg))
+    }
+
+    /// Returns the public key share, tagged with `self.index()`.
+    pub fn public_key(&self) -> (u64, GenericPublicKey<Pub>) {
+        (self.index, self.secret_key.public_key())
+    }
+}
+
+/// Recovers the signature made by the secret key that was split into the shares which made each
+/// of `shares`.
+///
+/// Each share is a `(index, signature)` pair, where `index` is the (non-zero) point at which the
+/// secret key polynomial was evaluated. Only the first `threshold` shares are used, however the
+/// indices of all shares are checked.
+///
+/// ## Errors
+///
+/// - `Error::InsufficientShares` if there are fewer than `threshold` shares (or `threshold` is
+///   zero).
+/// - `Error::InvalidShareIndex` if an index is zero.
+/// - `Error::DuplicateShareIndex` if an index is repeated anywhere in `shares`.
+/// - `Error::EmptySignature` if a signature is the "empty" value.
+pub fn recover_signature<Pub, AggPub, Sig, AggSig>(
+    shares: &[(u64, GenericSignature<Pub, Sig>)],
+    threshold: usize,
+) -> Result<GenericSignature<Pub, Sig>, Error>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    let shares = check_shares(shares, threshold)?;
+    let indices = shares.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+
+    let mut recovered = AggSig::infinity();
+    for (i, (_, signature)) in shares.iter().enumerate() {
+        let coefficient = lagrange_coefficient(&indices, i).to_bytes_be();
+        let point = signature.point().ok_or(Error::EmptySignature)?;
+        recovered.add_assign(&point.mul(&coefficient));
+    }
+
+    GenericSignature::deserialize(&recovered.serialize())
+}
+
+/// Recovers the public key of the secret key that was split into the shares with each of
+/// `shares` as their public key.
+///
+/// See `recover_signature` for the interpretation of `shares` and `threshold`.
+///
+/// ## Errors
+///
+/// - `Error::InsufficientShares` if there are fewer than `threshold` shares (or `threshold` is
+///   zero).
+/// - `Error::InvalidShareIndex` if an index is zero.
+/// - `Error::DuplicateShareIndex` if an index is repeated anywhere in `shares`.
+/// - `Error::InvalidInfinityPublicKey` if a public key, or the recovered public key, is the
+///   public key at infinity.
+pub fn recover_public_key<Pub, AggPub>(
+    shares: &[(u64, GenericPublicKey<Pub>)],
+    threshold: usize,
+) -> Result<GenericPublicKey<Pub>, Error>
+where
+    Pub: TPublicKey,
+    AggPub: TAggregatePublicKey<Pub>,
+{
+    let shares = check_shares(shares, threshold)?;
+    let indices = shares.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+
+    let mut recovered = AggPub::infinity();
+    for (i, (_, pubkey)) in shares.iter().enumerate() {
+        if pubkey.is_infinity() {
+            return Err(Error::InvalidInfinityPublicKey);
+        }
+
+        let coefficient = lagrange_coefficient(&indices, i).to_bytes_be();
+        let term = GenericPublicKey::from_point(pubkey.point().mul(&coefficient), false);
+        recovered.add_assign_aggregate(&AggPub::aggregate(&[&term]));
+    }
+
+    GenericPublicKey::deserialize(&recovered.serialize())
+}
+
+/// Returns the first `threshold` of `shares`, checking that they are sufficient and that the
+/// indices of *all* `shares` (including those which are not used) are non-zero and distinct.
+///
+/// A repeated index anywhere in `shares` indicates a stale or misattributed share, so it is
+/// rejected rather than silently ignored.
+fn check_shares<T>(shares: &[(u64, T)], threshold: usize) -> Result<&[(u64, T)], Error> {
+    if threshold == 0 || shares.len() < threshold {
+        return Err(Error::InsufficientShares {
+            got: shares.len(),
+            threshold,
+        });
+    }
+
+    for (i, (index, _)) in shares.iter().enumerate() {
+        if *index == 0 {
+            return Err(Error::InvalidShareIndex(*index));
+        }
+        if shares[..i].iter().any(|(other, _)| other == index) {
+            return Err(Error::DuplicateShareIndex(*index));
+        }
+    }
+
+    Ok(&shares[..threshold])
+}
+
+/// Returns the Lagrange coefficient for `indices[i]`, evaluated at zero and modulo the curve
+/// order.
+///
+/// The `indices` must be non-zero and distinct.
+fn lagrange_coefficient(indices: &[u64], i: usize) -> Scalar {
+    let x_i = Scalar::from_u64(indices[i]);
+
+    let mut numerator = Scalar::from_u64(1);
+    let mut denominator = Scalar::from_u64(1);
+    for (j, index) in indices.iter().enumerate() {
+        if j != i {
+            let x_j = Scalar::from_u64(*index);
+            numerator = numerator.mul(&x_j);
+            denominator = denominator.mul(&x_j.sub(&x_i));
+        }
+    }
+
+    numerator.mul(&denominator.invert())
+}
@@ -0,0 +1,59 @@
+use crate::{
+    generic_aggregate_signature::GenericAggregateSignature, generic_public_key::GenericPublicKey,
+};
+use std::marker::PhantomData;
+
+/// A zero-sized token which proves that a signature has been verified.
+///
+/// It has a private field, so it can only be created within this crate.
+#[derive(Clone, Copy)]
+struct VerificationProof(());
+
+/// An aggregate signature which has been successfully verified, created by
+/// `GenericAggregateSignature::fast_aggregate_verify_typed` or
+/// `GenericAggregateSignature::aggregate_verify_typed`.
+///
+/// Functions which must only ever receive a verified signature (e.g., in consensus) can take this
+/// type instead of an aggregate signature, making it impossible to pass an unverified one. The
+/// lifetime `'a` ties it to both the signature and the public keys it was verified against.
+///
+/// Passing an unverified signature is a compile error:
+///
+/// ```compile_fail
+/// use bls::{AggregateSignature, VerifiedSignature};
+///
+/// fn import(_signature: VerifiedSignature<'_>) {}
+///
+/// import(&AggregateSignature::infinity());
+/// ```
+pub struct GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig> {
+    signature: &'a GenericAggregateSignature<Pub, AggPub, Sig, AggSig>,
+    _pubkeys: PhantomData<&'a GenericPublicKey<Pub>>,
+    _proof: VerificationProof,
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig> {
+    /// Wraps `signature`, which must have just been successfully verified.
+    pub(crate) fn new(signature: &'a GenericAggregateSignature<Pub, AggPub, Sig, AggSig>) -> Self {
+        Self {
+            signature,
+            _pubkeys: PhantomData,
+            _proof: VerificationProof(()),
+        }
+    }
+
+    /// Returns the verified signature.
+    pub fn signature(&self) -> &'a GenericAggregateSignature<Pub, AggPub, Sig, AggSig> {
+        self.signature
+    }
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> Clone
+    for GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, Pub, AggPub, Sig, AggSig> Copy for GenericVerifiedSignature<'a, Pub, AggPub, Sig, AggSig> {}
@@ -0,0 +1,130 @@
+//! Exposes the hash-to-curve used by this crate, for protocols which are built upon the same
+//! hashed messages as BLS signatures.
+
+use crate::{Error, Hash256, SIGNATURE_BYTES_LEN};
+use blst::BLST_ERROR;
+use serde_hex::encode as hex_encode;
+use std::fmt;
+
+/// A point in G2, as returned by `hash_to_g2`.
+///
+/// The point is opaque; it may only be compared and converted to or from its compressed encoding.
+#[derive(Clone, Copy)]
+pub struct G2Point(blst::blst_p2_affine);
+
+impl G2Point {
+    /// Returns the underlying `blst` point.
+    pub(crate) fn as_affine(&self) -> &blst::blst_p2_affine {
+        &self.0
+    }
+
+    /// Serialize `self` as compressed bytes.
+    pub fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        let mut bytes = [0; SIGNATURE_BYTES_LEN];
+        // Safe since `bytes` is `SIGNATURE_BYTES_LEN` long.
+        unsafe {
+            blst::blst_p2_affine_compress(bytes.as_mut_ptr(), &self.0);
+        }
+        bytes
+    }
+
+    /// Deserialize `self` from compressed bytes, checking that the point is in the G2 subgroup.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != SIGNATURE_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_BYTES_LEN,
+            });
+        }
+
+        let mut point = blst::blst_p2_affine::default();
+
+        // Safe since `bytes` has been checked to be `SIGNATURE_BYTES_LEN` long.
+        let (err, in_group) = unsafe {
+            let err = blst::blst_p2_uncompress(&mut point, bytes.as_ptr());
+            (err, blst::blst_p2_affine_in_g2(&point))
+        };
+
+        if err != BLST_ERROR::BLST_SUCCESS {
+            Err(err.into())
+        } else if !in_group {
+            Err(Error::InvalidSubgroup)
+        } else {
+            Ok(Self(point))
+        }
+    }
+}
+
+impl PartialEq for G2Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.serialize()[..] == other.serialize()[..]
+    }
+}
+
+impl Eq for G2Point {}
+
+impl fmt::Debug for G2Point {
+    impl_debug!();
+}
+
+/// Hashes `msg` to a point in G2 using the `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite from RFC 9380,
+/// with the domain separation tag `dst`.
+///
+/// With `dst == DST`, this is the point which is multiplied by the secret key when signing `msg`.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2Point {
+    let mut point = blst::blst_p2::default();
+    let mut affine = blst::blst_p2_affine::default();
+
+    // Safe since each pointer is paired with the length of the slice it was taken from.
+    unsafe {
+        blst::blst_hash_to_g2(
+            &mut point,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            std::ptr::null(),
+            0,
+        );
+        blst::blst_p2_to_affine(&mut affine, &point);
+    }
+
+    G2Point(affine)
+}
+
+/// A message which has been hashed to G2 ahead of time, so it may be verified against many
+/// signatures (e.g., a sync committee block root) without repeating the hash-to-curve.
+///
+/// See `AggregateSignature::fast_aggregate_verify_prepared`.
+#[derive(Clone)]
+pub struct PreparedMessage {
+    msg: Hash256,
+    dst: Vec<u8>,
+    point: G2Point,
+}
+
+impl PreparedMessage {
+    /// Hashes `msg` to G2 with the domain separation tag `dst` (usually `DST`).
+    pub fn new(msg: Hash256, dst: &[u8]) -> Self {
+        Self {
+            msg,
+            dst: dst.to_vec(),
+            point: hash_to_g2(msg.as_bytes(), dst),
+        }
+    }
+
+    /// Returns the message which was hashed.
+    pub fn message(&self) -> Hash256 {
+        self.msg
+    }
+
+    /// Returns the domain separation tag which was used to hash the message.
+    pub fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    /// Returns the message hashed to G2.
+    pub fn point(&self) -> &G2Point {
+        &self.point
+    }
+}
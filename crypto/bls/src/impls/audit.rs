@@ -0,0 +1,67 @@
+//! Cross-checks of each real backend against the other, to catch a backend returning the wrong
+//! verification result.
+//!
+//! Only compiled with the `audit-pairings` feature in builds with debug assertions. Each check
+//! repeats the entire verification with the other backend, so it is very slow.
+
+use super::{blst::blst_core, milagro::milagro};
+use crate::{
+    generic_aggregate_signature::TAggregateSignature,
+    generic_public_key::{TPublicKey, PUBLIC_KEY_BYTES_LEN},
+    generic_signature::SIGNATURE_BYTES_LEN,
+    DST,
+};
+use blst::BLST_ERROR;
+
+/// Returns the result of `aggregate_verify` as computed by `milagro`, or `false` if any of the
+/// points cannot be decoded.
+pub(crate) fn milagro_aggregate_verify(
+    signature: &[u8; SIGNATURE_BYTES_LEN],
+    msgs: &[&[u8]],
+    pubkeys: &[[u8; PUBLIC_KEY_BYTES_LEN]],
+) -> bool {
+    let signature = match <milagro::AggregateSignature as TAggregateSignature<
+        milagro::PublicKey,
+        milagro::AggregatePublicKey,
+        milagro::Signature,
+    >>::deserialize(signature)
+    {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let pubkeys = match pubkeys
+        .iter()
+        .map(|bytes| <milagro::PublicKey as TPublicKey>::deserialize(bytes))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(pubkeys) => pubkeys,
+        Err(_) => return false,
+    };
+    let pubkeys = pubkeys.iter().collect::<Vec<_>>();
+
+    signature.aggregate_verify(msgs, &pubkeys)
+}
+
+/// Returns the result of `aggregate_verify` as computed by `blst`, or `false` if any of the points
+/// cannot be decoded.
+pub(crate) fn blst_aggregate_verify(
+    signature: &[u8; SIGNATURE_BYTES_LEN],
+    msgs: &[&[u8]],
+    pubkeys: &[[u8; PUBLIC_KEY_BYTES_LEN]],
+) -> bool {
+    let signature = match blst_core::Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let pubkeys = match pubkeys
+        .iter()
+        .map(|bytes| <blst_core::PublicKey as TPublicKey>::deserialize(bytes))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(pubkeys) => pubkeys,
+        Err(_) => return false,
+    };
+    let pubkeys = pubkeys.iter().collect::<Vec<_>>();
+
+    signature.aggregate_verify(msgs, DST, &pubkeys) == BLST_ERROR::BLST_SUCCESS
+}
@@ -1,19 +1,27 @@
 use crate::{
-    generic_aggregate_public_key::TAggregatePublicKey,
-    generic_aggregate_signature::TAggregateSignature,
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
+    generic_aggregate_signature::{TAggregateSignature, TAggregateSignatureUncompressed},
     generic_public_key::{GenericPublicKey, TPublicKey, PUBLIC_KEY_BYTES_LEN},
-    generic_secret_key::TSecretKey,
-    generic_signature::{TSignature, SIGNATURE_BYTES_LEN},
-    Error, Hash256, ZeroizeHash, INFINITY_PUBLIC_KEY, INFINITY_SIGNATURE,
+    generic_secret_key::{TSecretKey, TSecretKeyWithDst, SECRET_KEY_BYTES_LEN},
+    generic_signature::{
+        TSignature, TSignatureWithDst, SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN,
+    },
+    Error, Hash256, PreparedMessage, SignableMessage, ZeroizeHash, INFINITY_PUBLIC_KEY,
+    INFINITY_SIGNATURE,
 };
 pub use blst::min_pk as blst_core;
 use blst::{blst_scalar, BLST_ERROR};
-use rand::Rng;
+use rand::{CryptoRng, Rng, RngCore};
 use std::iter::ExactSizeIterator;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
 
-pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+pub use crate::DST;
 pub const RAND_BITS: usize = 64;
 
+/// The number of bits in a scalar which is less than the curve order.
+const SCALAR_BITS: usize = 255;
+
 /// Provides the externally-facing, core BLS types.
 pub mod types {
     pub use super::blst_core::PublicKey;
@@ -60,17 +68,7 @@ pub fn verify_signature_sets<'a>(
         }
 
         // Generate random scalars.
-        let mut vals = [0u64; 4];
-        vals[0] = rng.gen();
-        let mut rand_i = std::mem::MaybeUninit::<blst_scalar>::uninit();
-
-        // TODO: remove this `unsafe` code-block once we get a safe option from `blst`.
-        //
-        // See https://github.com/supranational/blst/issues/13
-        unsafe {
-            blst::blst_scalar_from_uint64(rand_i.as_mut_ptr(), vals.as_ptr());
-            rands.push(rand_i.assume_init());
-        }
+        rands.push(random_scalar(rng));
 
         // Grab a slice of the message, to satisfy the blst API.
         msgs_refs.push(set.message.as_bytes());
@@ -118,14 +116,150 @@ pub fn verify_signature_sets<'a>(
     err == blst::BLST_ERROR::BLST_SUCCESS
 }
 
+/// Returns a random scalar of `RAND_BITS` bits, for use in randomized batch verification.
+fn random_scalar<R: Rng>(rng: &mut R) -> blst_scalar {
+    let mut vals = [0u64; 4];
+    vals[0] = rng.gen();
+    let mut rand_i = std::mem::MaybeUninit::<blst_scalar>::uninit();
+
+    // TODO: remove this `unsafe` code-block once we get a safe option from `blst`.
+    //
+    // See https://github.com/supranational/blst/issues/13
+    unsafe {
+        blst::blst_scalar_from_uint64(rand_i.as_mut_ptr(), vals.as_ptr());
+        rand_i.assume_init()
+    }
+}
+
+/// Converts the big-endian `scalar` to a `blst_scalar`.
+fn scalar_from_bytes_be(scalar: &ZeroizeHash) -> blst_scalar {
+    let mut out = blst_scalar::default();
+
+    // Safe since `scalar` is always `SECRET_KEY_BYTES_LEN` long.
+    unsafe {
+        blst::blst_scalar_from_bendian(&mut out, scalar.as_bytes().as_ptr());
+    }
+    out
+}
+
+/// Overwrites `scalar` with zeros, since it may be derived from a secret (e.g., an unblinding
+/// factor).
+fn zeroize_scalar(scalar: &mut blst_scalar) {
+    // Safe since `scalar` is a valid, aligned reference and `blst_scalar` is a plain array.
+    unsafe {
+        std::ptr::write_volatile(scalar, blst_scalar::default());
+    }
+}
+
+/// Returns `Ok(())` if `bytes` is a compressed G1 point which is in the G1 subgroup.
+fn g1_subgroup_check(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.len() != PUBLIC_KEY_BYTES_LEN {
+        return Err(Error::InvalidByteLength {
+            got: bytes.len(),
+            expected: PUBLIC_KEY_BYTES_LEN,
+        });
+    }
+
+    let mut point = blst::blst_p1_affine::default();
+
+    // Safe since `bytes` has been checked to be `PUBLIC_KEY_BYTES_LEN` long.
+    let (err, in_group) = unsafe {
+        let err = blst::blst_p1_uncompress(&mut point, bytes.as_ptr());
+        // The point at infinity is in every subgroup, but `blst_p1_affine_in_g1` rejects it.
+        (
+            err,
+            blst::blst_p1_affine_is_inf(&point) || blst::blst_p1_affine_in_g1(&point),
+        )
+    };
+
+    if err != BLST_ERROR::BLST_SUCCESS {
+        Err(err.into())
+    } else if !in_group {
+        Err(Error::InvalidSubgroup)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `Ok(())` if `bytes` is a compressed G2 point which is in the G2 subgroup.
+fn g2_subgroup_check(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.len() != SIGNATURE_BYTES_LEN {
+        return Err(Error::InvalidByteLength {
+            got: bytes.len(),
+            expected: SIGNATURE_BYTES_LEN,
+        });
+    }
+
+    let mut point = blst::blst_p2_affine::default();
+
+    // Safe since `bytes` has been checked to be `SIGNATURE_BYTES_LEN` long.
+    let (err, in_group) = unsafe {
+        let err = blst::blst_p2_uncompress(&mut point, bytes.as_ptr());
+        // The point at infinity is in every subgroup, but `blst_p2_affine_in_g2` rejects it.
+        (
+            err,
+            blst::blst_p2_affine_is_inf(&point) || blst::blst_p2_affine_in_g2(&point),
+        )
+    };
+
+    if err != BLST_ERROR::BLST_SUCCESS {
+        Err(err.into())
+    } else if !in_group {
+        Err(Error::InvalidSubgroup)
+    } else {
+        Ok(())
+    }
+}
+
 impl TPublicKey for blst_core::PublicKey {
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         self.compress()
     }
 
+    fn serialize_into(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), PUBLIC_KEY_BYTES_LEN);
+        // Safe since `out` is `PUBLIC_KEY_BYTES_LEN` long and `blst_core::PublicKey` only contains
+        // a `blst_p1_affine`.
+        unsafe {
+            blst::blst_p1_affine_compress(
+                out.as_mut_ptr(),
+                self as *const blst_core::PublicKey as *const blst::blst_p1_affine,
+            );
+        }
+    }
+
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::uncompress(&bytes).map_err(Into::into)
     }
+
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        g1_subgroup_check(bytes)?;
+        <Self as TPublicKey>::deserialize(bytes)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        let mut point = blst::blst_p1::default();
+        let mut product = blst::blst_p1::default();
+        let mut scalar = scalar_from_bytes_be(scalar);
+        let mut out = *self;
+
+        // Safe since `blst_core::PublicKey` only contains a `blst_p1_affine` and `scalar` holds
+        // `SCALAR_BITS` bits.
+        unsafe {
+            blst::blst_p1_from_affine(
+                &mut point,
+                self as *const blst_core::PublicKey as *const blst::blst_p1_affine,
+            );
+            blst::blst_p1_mult(&mut product, &point, &scalar, SCALAR_BITS);
+            blst::blst_p1_to_affine(
+                &mut out as *mut blst_core::PublicKey as *mut blst::blst_p1_affine,
+                &product,
+            );
+        }
+        zeroize_scalar(&mut scalar);
+
+        out
+    }
 }
 
 /// A wrapper that allows for `PartialEq` and `Clone` impls.
@@ -145,7 +279,7 @@ impl PartialEq for BlstAggregatePublicKey {
     }
 }
 
-impl TAggregatePublicKey for BlstAggregatePublicKey {
+impl TAggregatePublicKey<blst_core::PublicKey> for BlstAggregatePublicKey {
     fn infinity() -> Self {
         blst_core::PublicKey::from_bytes(&INFINITY_PUBLIC_KEY)
             .map(|pk| blst_core::AggregatePublicKey::from_public_key(&pk))
@@ -153,6 +287,17 @@ impl TAggregatePublicKey for BlstAggregatePublicKey {
             .expect("should decode infinity public key")
     }
 
+    /// Adds the points sequentially. The pinned `blst` revision predates the `blst_p1s_add`
+    /// batch-addition primitive, so there is no faster path for large committees.
+    fn aggregate(pubkeys: &[&GenericPublicKey<blst_core::PublicKey>]) -> Self {
+        let points = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
+        Self(blst_core::AggregatePublicKey::aggregate(&points))
+    }
+
+    fn add_assign_aggregate(&mut self, other: &Self) {
+        self.0.add_aggregate(&other.0)
+    }
+
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         self.0.to_public_key().compress()
     }
@@ -170,12 +315,59 @@ impl TSignature<blst_core::PublicKey> for blst_core::Signature {
         self.to_bytes()
     }
 
+    fn serialize_into(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), SIGNATURE_BYTES_LEN);
+        // Safe since `out` is `SIGNATURE_BYTES_LEN` long and `blst_core::Signature` only contains
+        // a `blst_p2_affine`.
+        unsafe {
+            blst::blst_p2_affine_compress(
+                out.as_mut_ptr(),
+                self as *const blst_core::Signature as *const blst::blst_p2_affine,
+            );
+        }
+    }
+
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(bytes).map_err(Into::into)
     }
 
-    fn verify(&self, pubkey: &blst_core::PublicKey, msg: Hash256) -> bool {
-        self.verify(msg.as_bytes(), DST, &[], pubkey) == BLST_ERROR::BLST_SUCCESS
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        g2_subgroup_check(bytes)?;
+        <Self as TSignature<blst_core::PublicKey>>::deserialize(bytes)
+    }
+
+    fn verify<M: SignableMessage>(&self, pubkey: &blst_core::PublicKey, msg: M) -> bool {
+        self.verify_with_dst(pubkey, msg.signing_bytes(), DST)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        let mut point = blst::blst_p2::default();
+        let mut product = blst::blst_p2::default();
+        let mut scalar = scalar_from_bytes_be(scalar);
+        let mut out = *self;
+
+        // Safe since `blst_core::Signature` only contains a `blst_p2_affine` and `scalar` holds
+        // `SCALAR_BITS` bits.
+        unsafe {
+            blst::blst_p2_from_affine(
+                &mut point,
+                self as *const blst_core::Signature as *const blst::blst_p2_affine,
+            );
+            blst::blst_p2_mult(&mut product, &point, &scalar, SCALAR_BITS);
+            blst::blst_p2_to_affine(
+                &mut out as *mut blst_core::Signature as *mut blst::blst_p2_affine,
+                &product,
+            );
+        }
+        zeroize_scalar(&mut scalar);
+
+        out
+    }
+}
+
+impl TSignatureWithDst<blst_core::PublicKey> for blst_core::Signature {
+    fn verify_with_dst(&self, pubkey: &blst_core::PublicKey, msg: &[u8], dst: &[u8]) -> bool {
+        self.verify(msg, dst, &[], pubkey) == BLST_ERROR::BLST_SUCCESS
     }
 }
 
@@ -210,14 +402,56 @@ impl TAggregateSignature<blst_core::PublicKey, BlstAggregatePublicKey, blst_core
         self.0.add_signature(other)
     }
 
+    fn from_signature(signature: blst_core::Signature) -> Self {
+        Self(blst_core::AggregateSignature::from_signature(&signature))
+    }
+
+    fn into_signature(self) -> blst_core::Signature {
+        self.0.to_signature()
+    }
+
     fn add_assign_aggregate(&mut self, other: &Self) {
         self.0.add_aggregate(&other.0)
     }
 
+    fn sub_assign(&mut self, other: &blst_core::Signature) {
+        let mut negated = blst_core::AggregateSignature::from_signature(other);
+        // Safe since `blst_core::AggregateSignature` only contains a `blst_p2`.
+        unsafe {
+            blst::blst_p2_cneg(
+                &mut negated as *mut blst_core::AggregateSignature as *mut blst::blst_p2,
+                1,
+            );
+        }
+        self.0.add_aggregate(&negated)
+    }
+
+    fn is_infinity(&self) -> bool {
+        // Safe since `blst_core::AggregateSignature` only contains a `blst_p2`.
+        unsafe {
+            blst::blst_p2_is_inf(
+                &self.0 as *const blst_core::AggregateSignature as *const blst::blst_p2,
+            )
+        }
+    }
+
     fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
         self.0.to_signature().to_bytes()
     }
 
+    /// Compresses the point directly, rather than converting it to a `blst_core::Signature` first.
+    fn serialize_into(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), SIGNATURE_BYTES_LEN);
+        // Safe since `out` is `SIGNATURE_BYTES_LEN` long and `blst_core::AggregateSignature` only
+        // contains a `blst_p2`.
+        unsafe {
+            blst::blst_p2_compress(
+                out.as_mut_ptr(),
+                &self.0 as *const blst_core::AggregateSignature as *const blst::blst_p2,
+            );
+        }
+    }
+
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         blst_core::Signature::from_bytes(bytes)
             .map_err(Into::into)
@@ -225,34 +459,222 @@ impl TAggregateSignature<blst_core::PublicKey, BlstAggregatePublicKey, blst_core
             .map(Self)
     }
 
-    fn fast_aggregate_verify(
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        g2_subgroup_check(bytes)?;
+        Self::deserialize(bytes)
+    }
+
+    fn fast_aggregate_verify<M: SignableMessage>(
         &self,
-        msg: Hash256,
+        msg: M,
         pubkeys: &[&GenericPublicKey<blst_core::PublicKey>],
     ) -> bool {
         let pubkeys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
         let signature = self.0.clone().to_signature();
-        signature.fast_aggregate_verify(msg.as_bytes(), DST, &pubkeys) == BLST_ERROR::BLST_SUCCESS
+        signature.fast_aggregate_verify(msg.signing_bytes(), DST, &pubkeys)
+            == BLST_ERROR::BLST_SUCCESS
     }
 
-    fn aggregate_verify(
+    /// Verifies against the point in `prepared`, rather than hashing the message again.
+    fn fast_aggregate_verify_prepared(
         &self,
-        msgs: &[Hash256],
+        prepared: &PreparedMessage,
+        pubkeys: &[&GenericPublicKey<blst_core::PublicKey>],
+    ) -> bool {
+        if prepared.dst() != DST || pubkeys.is_empty() {
+            return false;
+        }
+
+        let signing_keys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
+        let pubkey = blst_core::AggregatePublicKey::aggregate(&signing_keys).to_public_key();
+        let signature = self.0.to_signature();
+        let pk_affine = &pubkey as *const blst_core::PublicKey as *const blst::blst_p1_affine;
+        let sig_affine = &signature as *const blst_core::Signature as *const blst::blst_p2_affine;
+
+        let mut lhs = blst::blst_fp12::default();
+        let mut rhs = blst::blst_fp12::default();
+        let mut product = blst::blst_fp12::default();
+
+        // Checks `e(pk, H(msg)) == e(G1, sig)` as `e(pk, H(msg)) * e(G1, sig)^-1 == 1`, where the
+        // inverse is the conjugate (before the final exponentiation). As in `blst`'s own
+        // verification, the aggregate public key at infinity and signatures outside of G2 are
+        // rejected.
+        //
+        // Safe since `blst_core::PublicKey` and `blst_core::Signature` only contain a
+        // `blst_p1_affine` and a `blst_p2_affine`, respectively.
+        unsafe {
+            if blst::blst_p1_affine_is_inf(pk_affine) || !blst::blst_p2_affine_in_g2(sig_affine) {
+                return false;
+            }
+
+            blst::blst_miller_loop(&mut lhs, prepared.point().as_affine(), pk_affine);
+            blst::blst_miller_loop(&mut rhs, sig_affine, blst::blst_p1_affine_generator());
+            blst::blst_fp12_conjugate(&mut rhs);
+            blst::blst_fp12_mul(&mut product, &lhs, &rhs);
+            blst::blst_final_exp(&mut lhs, &product);
+            blst::blst_fp12_is_one(&lhs)
+        }
+    }
+
+    fn aggregate_verify<M: SignableMessage>(
+        &self,
+        msgs: &[M],
         pubkeys: &[&GenericPublicKey<blst_core::PublicKey>],
     ) -> bool {
         let pubkeys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
-        let msgs = msgs.iter().map(|hash| hash.as_bytes()).collect::<Vec<_>>();
+        let msgs = msgs
+            .iter()
+            .map(|msg| msg.signing_bytes())
+            .collect::<Vec<_>>();
+        let signature = self.0.clone().to_signature();
+        let valid = signature.aggregate_verify(&msgs, DST, &pubkeys) == BLST_ERROR::BLST_SUCCESS;
+
+        #[cfg(all(feature = "audit-pairings", debug_assertions))]
+        debug_assert_eq!(
+            valid,
+            super::audit::milagro_aggregate_verify(
+                &TAggregateSignature::serialize(self),
+                &msgs,
+                &pubkeys.iter().map(|pk| pk.compress()).collect::<Vec<_>>(),
+            ),
+            "blst and milagro disagree on aggregate_verify"
+        );
+
+        valid
+    }
+
+    fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msg: M,
+        agg_pubkey: &GenericAggregatePublicKey<blst_core::PublicKey, BlstAggregatePublicKey>,
+    ) -> bool {
+        let pubkey = agg_pubkey.point().0.to_public_key();
+        let signature = self.0.clone().to_signature();
+        signature.verify(msg.signing_bytes(), DST, &[], &pubkey) == BLST_ERROR::BLST_SUCCESS
+    }
+
+    fn aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        agg_pubkeys: &[&GenericAggregatePublicKey<blst_core::PublicKey, BlstAggregatePublicKey>],
+    ) -> bool {
+        let pubkeys = agg_pubkeys
+            .iter()
+            .map(|agg_pubkey| agg_pubkey.point().0.to_public_key())
+            .collect::<Vec<_>>();
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+        let msgs = msgs
+            .iter()
+            .map(|msg| msg.signing_bytes())
+            .collect::<Vec<_>>();
         let signature = self.0.clone().to_signature();
-        signature.aggregate_verify(&msgs, DST, &pubkeys) == BLST_ERROR::BLST_SUCCESS
+        signature.aggregate_verify(&msgs, DST, &pubkey_refs) == BLST_ERROR::BLST_SUCCESS
+    }
+
+    fn fast_aggregate_verify_multiple_with_rng<R: RngCore + CryptoRng>(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<blst_core::PublicKey>]],
+        rng: &mut R,
+    ) -> bool {
+        if signatures.is_empty()
+            || signatures.len() != msgs.len()
+            || signatures.len() != pubkeys.len()
+            || pubkeys.iter().any(|signing_keys| signing_keys.is_empty())
+        {
+            return false;
+        }
+
+        let rands = (0..signatures.len())
+            .map(|_| random_scalar(rng))
+            .collect::<Vec<_>>();
+        let msgs_refs = msgs.iter().map(|msg| msg.as_bytes()).collect::<Vec<_>>();
+        let sigs = signatures
+            .iter()
+            .map(|sig| sig.0.to_signature())
+            .collect::<Vec<_>>();
+        let pks = pubkeys
+            .iter()
+            .map(|signing_keys| {
+                let signing_keys = signing_keys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
+                blst_core::AggregatePublicKey::aggregate(&signing_keys).to_public_key()
+            })
+            .collect::<Vec<_>>();
+
+        let sig_refs = sigs.iter().collect::<Vec<_>>();
+        let pks_refs = pks.iter().collect::<Vec<_>>();
+
+        let err = blst_core::Signature::verify_multiple_aggregate_signatures(
+            &msgs_refs, DST, &pks_refs, &sig_refs, &rands, RAND_BITS,
+        );
+
+        err == BLST_ERROR::BLST_SUCCESS
+    }
+
+    fn fast_aggregate_verify_multiple_pre_aggregated(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        agg_pubkeys: &[&GenericAggregatePublicKey<blst_core::PublicKey, BlstAggregatePublicKey>],
+    ) -> bool {
+        if signatures.is_empty()
+            || signatures.len() != msgs.len()
+            || signatures.len() != agg_pubkeys.len()
+        {
+            return false;
+        }
+
+        let rng = &mut rand::thread_rng();
+        let rands = (0..signatures.len())
+            .map(|_| random_scalar(rng))
+            .collect::<Vec<_>>();
+        let msgs_refs = msgs.iter().map(|msg| msg.as_bytes()).collect::<Vec<_>>();
+        let sigs = signatures
+            .iter()
+            .map(|sig| sig.0.to_signature())
+            .collect::<Vec<_>>();
+        let pks = agg_pubkeys
+            .iter()
+            .map(|agg_pubkey| agg_pubkey.point().0.to_public_key())
+            .collect::<Vec<_>>();
+
+        let sig_refs = sigs.iter().collect::<Vec<_>>();
+        let pks_refs = pks.iter().collect::<Vec<_>>();
+
+        let err = blst_core::Signature::verify_multiple_aggregate_signatures(
+            &msgs_refs, DST, &pks_refs, &sig_refs, &rands, RAND_BITS,
+        );
+
+        err == BLST_ERROR::BLST_SUCCESS
+    }
+}
+
+impl
+    TAggregateSignatureUncompressed<
+        blst_core::PublicKey,
+        BlstAggregatePublicKey,
+        blst_core::Signature,
+    > for BlstAggregateSignature
+{
+    fn serialize_uncompressed(&self) -> [u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN] {
+        self.0.to_signature().serialize()
+    }
+
+    fn deserialize_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        blst_core::Signature::deserialize(bytes)
+            .map_err(Into::into)
+            .map(|sig| blst_core::AggregateSignature::from_signature(&sig))
+            .map(Self)
     }
 }
 
 impl TSecretKey<blst_core::Signature, blst_core::PublicKey> for blst_core::SecretKey {
-    fn random() -> Self {
-        let rng = &mut rand::thread_rng();
-        let ikm: [u8; 32] = rng.gen();
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut ikm = [0; 32];
+        rng.fill_bytes(&mut ikm);
 
-        Self::key_gen(&ikm, &[]).unwrap()
+        let sk = Self::key_gen(&ikm, &[]).unwrap();
+        ikm.zeroize();
+        sk
     }
 
     fn public_key(&self) -> blst_core::PublicKey {
@@ -260,7 +682,11 @@ impl TSecretKey<blst_core::Signature, blst_core::PublicKey> for blst_core::Secre
     }
 
     fn sign(&self, msg: Hash256) -> blst_core::Signature {
-        self.sign(msg.as_bytes(), DST, &[])
+        self.sign_with_dst(msg.as_bytes(), DST)
+    }
+
+    fn sign_bytes(&self, msg: &[u8]) -> blst_core::Signature {
+        self.sign_with_dst(msg, DST)
     }
 
     fn serialize(&self) -> ZeroizeHash {
@@ -270,4 +696,36 @@ impl TSecretKey<blst_core::Signature, blst_core::PublicKey> for blst_core::Secre
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(&bytes).map_err(Into::into)
     }
+
+    fn is_valid_scalar(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice {
+        let mut scalar = blst_scalar::default();
+
+        // Safe since `bytes` is always `SECRET_KEY_BYTES_LEN` long.
+        let in_range = unsafe {
+            blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+            blst::blst_scalar_fr_check(&scalar)
+        };
+        zeroize_scalar(&mut scalar);
+
+        // The pinned `blst` revision predates `blst_sk_check`, so reject zero separately.
+        Choice::from(in_range as u8) & !bytes.ct_eq(&[0; SECRET_KEY_BYTES_LEN])
+    }
+
+    fn zeroize(&mut self) {
+        // Safe since `blst_core::SecretKey` is a plain scalar which does not contain any pointers,
+        // so the all-zeros bit pattern is valid.
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                self as *mut Self as *mut u8,
+                std::mem::size_of::<Self>(),
+            )
+        };
+        bytes.zeroize();
+    }
+}
+
+impl TSecretKeyWithDst<blst_core::Signature, blst_core::PublicKey> for blst_core::SecretKey {
+    fn sign_with_dst(&self, msg: &[u8], dst: &[u8]) -> blst_core::Signature {
+        self.sign(msg, dst, &[])
+    }
 }
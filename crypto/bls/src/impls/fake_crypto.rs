@@ -1,11 +1,18 @@
 use crate::{
-    generic_aggregate_public_key::TAggregatePublicKey,
-    generic_aggregate_signature::TAggregateSignature,
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
+    generic_aggregate_signature::{TAggregateSignature, TAggregateSignatureUncompressed},
     generic_public_key::{GenericPublicKey, TPublicKey, PUBLIC_KEY_BYTES_LEN},
-    generic_secret_key::{TSecretKey, SECRET_KEY_BYTES_LEN},
-    generic_signature::{TSignature, SIGNATURE_BYTES_LEN},
-    Error, Hash256, ZeroizeHash, INFINITY_PUBLIC_KEY, INFINITY_SIGNATURE,
+    generic_secret_key::{TSecretKey, TSecretKeyWithDst, SECRET_KEY_BYTES_LEN},
+    generic_signature::{
+        TSignature, TSignatureWithDst, SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN,
+    },
+    scalar::Scalar,
+    Error, Hash256, SignableMessage, ZeroizeHash, INFINITY_PUBLIC_KEY, INFINITY_SIGNATURE,
 };
+use rand::{CryptoRng, RngCore};
+use subtle::Choice;
+use zeroize::Zeroize;
+
 /// Provides the externally-facing, core BLS types.
 pub mod types {
     pub use super::verify_signature_sets;
@@ -50,6 +57,14 @@ impl TPublicKey for PublicKey {
         pubkey.0[..].copy_from_slice(&bytes[0..PUBLIC_KEY_BYTES_LEN]);
         Ok(pubkey)
     }
+
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TPublicKey>::deserialize(bytes)
+    }
+
+    fn mul(&self, _scalar: &ZeroizeHash) -> Self {
+        self.clone()
+    }
 }
 
 impl Eq for PublicKey {}
@@ -63,11 +78,19 @@ impl PartialEq for PublicKey {
 #[derive(Clone)]
 pub struct AggregatePublicKey([u8; PUBLIC_KEY_BYTES_LEN]);
 
-impl TAggregatePublicKey for AggregatePublicKey {
+impl TAggregatePublicKey<PublicKey> for AggregatePublicKey {
     fn infinity() -> Self {
         Self([0; PUBLIC_KEY_BYTES_LEN])
     }
 
+    fn aggregate(_pubkeys: &[&GenericPublicKey<PublicKey>]) -> Self {
+        Self::infinity()
+    }
+
+    fn add_assign_aggregate(&mut self, _other: &Self) {
+        // Do nothing.
+    }
+
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         let mut bytes = [0; PUBLIC_KEY_BYTES_LEN];
         bytes[..].copy_from_slice(&self.0);
@@ -111,7 +134,25 @@ impl TSignature<PublicKey> for Signature {
         Ok(signature)
     }
 
-    fn verify(&self, _pubkey: &PublicKey, _msg: Hash256) -> bool {
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TSignature<PublicKey>>::deserialize(bytes)
+    }
+
+    fn check_encoding(_bytes: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn verify<M: SignableMessage>(&self, _pubkey: &PublicKey, _msg: M) -> bool {
+        true
+    }
+
+    fn mul(&self, _scalar: &ZeroizeHash) -> Self {
+        self.clone()
+    }
+}
+
+impl TSignatureWithDst<PublicKey> for Signature {
+    fn verify_with_dst(&self, _pubkey: &PublicKey, _msg: &[u8], _dst: &[u8]) -> bool {
         true
     }
 }
@@ -144,6 +185,18 @@ impl TAggregateSignature<PublicKey, AggregatePublicKey, Signature> for Aggregate
         // Do nothing.
     }
 
+    fn sub_assign(&mut self, _other: &Signature) {
+        // Do nothing.
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.0[..] == INFINITY_SIGNATURE[..]
+    }
+
+    fn into_signature(self) -> Signature {
+        Signature(self.0)
+    }
+
     fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
         let mut bytes = [0; SIGNATURE_BYTES_LEN];
 
@@ -160,21 +213,74 @@ impl TAggregateSignature<PublicKey, AggregatePublicKey, Signature> for Aggregate
         Ok(Self(key))
     }
 
-    fn fast_aggregate_verify(
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TAggregateSignature<_, _, _>>::deserialize(bytes)
+    }
+
+    fn fast_aggregate_verify<M: SignableMessage>(
         &self,
-        _msg: Hash256,
+        _msg: M,
         _pubkeys: &[&GenericPublicKey<PublicKey>],
     ) -> bool {
         true
     }
 
-    fn aggregate_verify(
+    fn aggregate_verify<M: SignableMessage>(
         &self,
-        _msgs: &[Hash256],
+        _msgs: &[M],
         _pubkeys: &[&GenericPublicKey<PublicKey>],
     ) -> bool {
         true
     }
+
+    fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        _msg: M,
+        _agg_pubkey: &GenericAggregatePublicKey<PublicKey, AggregatePublicKey>,
+    ) -> bool {
+        true
+    }
+
+    fn aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        _msgs: &[M],
+        _agg_pubkeys: &[&GenericAggregatePublicKey<PublicKey, AggregatePublicKey>],
+    ) -> bool {
+        true
+    }
+
+    fn fast_aggregate_verify_multiple_with_rng<R: RngCore + CryptoRng>(
+        _signatures: &[&Self],
+        _msgs: &[Hash256],
+        _pubkeys: &[&[&GenericPublicKey<PublicKey>]],
+        _rng: &mut R,
+    ) -> bool {
+        true
+    }
+
+    fn fast_aggregate_verify_multiple_pre_aggregated(
+        _signatures: &[&Self],
+        _msgs: &[Hash256],
+        _agg_pubkeys: &[&GenericAggregatePublicKey<PublicKey, AggregatePublicKey>],
+    ) -> bool {
+        true
+    }
+}
+
+impl TAggregateSignatureUncompressed<PublicKey, AggregatePublicKey, Signature>
+    for AggregateSignature
+{
+    fn serialize_uncompressed(&self) -> [u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN] {
+        let mut bytes = [0; SIGNATURE_UNCOMPRESSED_BYTES_LEN];
+        bytes[..SIGNATURE_BYTES_LEN].copy_from_slice(&self.0);
+        bytes
+    }
+
+    fn deserialize_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        let mut key = [0; SIGNATURE_BYTES_LEN];
+        key[..].copy_from_slice(&bytes[..SIGNATURE_BYTES_LEN]);
+        Ok(Self(key))
+    }
 }
 
 impl Eq for AggregateSignature {}
@@ -189,7 +295,7 @@ impl PartialEq for AggregateSignature {
 pub struct SecretKey([u8; SECRET_KEY_BYTES_LEN]);
 
 impl TSecretKey<Signature, PublicKey> for SecretKey {
-    fn random() -> Self {
+    fn random_with_rng<R: RngCore + CryptoRng>(_rng: &mut R) -> Self {
         Self([0; SECRET_KEY_BYTES_LEN])
     }
 
@@ -201,6 +307,10 @@ impl TSecretKey<Signature, PublicKey> for SecretKey {
         Signature::infinity()
     }
 
+    fn sign_bytes(&self, _msg: &[u8]) -> Signature {
+        Signature::infinity()
+    }
+
     fn serialize(&self) -> ZeroizeHash {
         let mut bytes = [0; SECRET_KEY_BYTES_LEN];
         bytes[..].copy_from_slice(&self.0[..]);
@@ -208,8 +318,23 @@ impl TSecretKey<Signature, PublicKey> for SecretKey {
     }
 
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
-        let mut sk = Self::random();
+        let mut sk = Self([0; SECRET_KEY_BYTES_LEN]);
         sk.0[..].copy_from_slice(&bytes[0..SECRET_KEY_BYTES_LEN]);
         Ok(sk)
     }
+
+    fn is_valid_scalar(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice {
+        let is_valid = Scalar::from_bytes_be(bytes).map_or(false, |scalar| !scalar.is_zero());
+        Choice::from(is_valid as u8)
+    }
+
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl TSecretKeyWithDst<Signature, PublicKey> for SecretKey {
+    fn sign_with_dst(&self, _msg: &[u8], _dst: &[u8]) -> Signature {
+        Signature::infinity()
+    }
 }
@@ -1,14 +1,17 @@
 use crate::{
-    generic_aggregate_public_key::TAggregatePublicKey,
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
     generic_aggregate_signature::TAggregateSignature,
     generic_public_key::{GenericPublicKey, TPublicKey, PUBLIC_KEY_BYTES_LEN},
     generic_secret_key::{TSecretKey, SECRET_KEY_BYTES_LEN},
     generic_signature::{TSignature, SIGNATURE_BYTES_LEN},
-    Error, Hash256, ZeroizeHash, INFINITY_PUBLIC_KEY,
+    Error, Hash256, SignableMessage, ZeroizeHash, INFINITY_PUBLIC_KEY,
 };
 pub use milagro_bls as milagro;
-use rand::thread_rng;
+use milagro_bls::amcl_utils::{Big, MODBYTES};
+use rand::{CryptoRng, RngCore};
 use std::iter::ExactSizeIterator;
+use subtle::Choice;
+use zeroize::Zeroize;
 
 /// Provides the externally-facing, core BLS types.
 pub mod types {
@@ -74,6 +77,15 @@ pub fn verify_signature_sets<'a>(
         .unwrap_or(false)
 }
 
+/// Converts the big-endian `scalar` to a `Big`, which is `MODBYTES` long.
+fn big_from_bytes_be(scalar: &ZeroizeHash) -> Big {
+    let mut bytes = [0; MODBYTES];
+    bytes[MODBYTES - SECRET_KEY_BYTES_LEN..].copy_from_slice(scalar.as_bytes());
+    let big = Big::from_bytes(&bytes);
+    bytes.zeroize();
+    big
+}
+
 impl TPublicKey for milagro::PublicKey {
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         let mut bytes = [0; PUBLIC_KEY_BYTES_LEN];
@@ -84,13 +96,36 @@ impl TPublicKey for milagro::PublicKey {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(&bytes).map_err(Into::into)
     }
+
+    /// Milagro performs the subgroup check during deserialization.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TPublicKey>::deserialize(bytes)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        Self {
+            point: self.point.mul(&big_from_bytes_be(scalar)),
+        }
+    }
 }
 
-impl TAggregatePublicKey for milagro::AggregatePublicKey {
+impl TAggregatePublicKey<milagro::PublicKey> for milagro::AggregatePublicKey {
     fn infinity() -> Self {
         Self::from_bytes(&INFINITY_PUBLIC_KEY).expect("should decode infinity public key")
     }
 
+    fn aggregate(pubkeys: &[&GenericPublicKey<milagro::PublicKey>]) -> Self {
+        let mut aggregate = Self::infinity();
+        for pubkey in pubkeys {
+            aggregate.add(pubkey.point())
+        }
+        aggregate
+    }
+
+    fn add_assign_aggregate(&mut self, other: &Self) {
+        self.add_aggregate(other)
+    }
+
     fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
         let mut bytes = [0; PUBLIC_KEY_BYTES_LEN];
         bytes[..].copy_from_slice(&self.as_bytes());
@@ -115,8 +150,19 @@ impl TSignature<milagro::PublicKey> for milagro::Signature {
         milagro::Signature::from_bytes(&bytes).map_err(Error::MilagroError)
     }
 
-    fn verify(&self, pubkey: &milagro::PublicKey, msg: Hash256) -> bool {
-        self.verify(msg.as_bytes(), pubkey)
+    /// Milagro performs the subgroup check during deserialization.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TSignature<milagro::PublicKey>>::deserialize(bytes)
+    }
+
+    fn verify<M: SignableMessage>(&self, pubkey: &milagro::PublicKey, msg: M) -> bool {
+        self.verify(msg.signing_bytes(), pubkey)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        Self {
+            point: self.point.mul(&big_from_bytes_be(scalar)),
+        }
     }
 }
 
@@ -135,6 +181,16 @@ impl TAggregateSignature<milagro::PublicKey, milagro::AggregatePublicKey, milagr
         self.add_aggregate(other)
     }
 
+    fn sub_assign(&mut self, other: &milagro::Signature) {
+        let mut negated = other.clone();
+        negated.point.neg();
+        self.add(&negated)
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.point.is_infinity()
+    }
+
     fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
         let mut bytes = [0; SIGNATURE_BYTES_LEN];
 
@@ -147,29 +203,151 @@ impl TAggregateSignature<milagro::PublicKey, milagro::AggregatePublicKey, milagr
         milagro::AggregateSignature::from_bytes(&bytes).map_err(Error::MilagroError)
     }
 
-    fn fast_aggregate_verify(
+    /// Milagro performs the subgroup check during deserialization.
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TAggregateSignature<_, _, _>>::deserialize(bytes)
+    }
+
+    fn fast_aggregate_verify<M: SignableMessage>(
         &self,
-        msg: Hash256,
+        msg: M,
         pubkeys: &[&GenericPublicKey<milagro::PublicKey>],
     ) -> bool {
         let pubkeys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
-        self.fast_aggregate_verify(msg.as_bytes(), &pubkeys)
+        self.fast_aggregate_verify(msg.signing_bytes(), &pubkeys)
     }
 
-    fn aggregate_verify(
+    fn aggregate_verify<M: SignableMessage>(
         &self,
-        msgs: &[Hash256],
+        msgs: &[M],
         pubkeys: &[&GenericPublicKey<milagro::PublicKey>],
     ) -> bool {
         let pubkeys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
-        let msgs = msgs.iter().map(|hash| hash.as_bytes()).collect::<Vec<_>>();
-        self.aggregate_verify(&msgs, &pubkeys)
+        let msgs = msgs
+            .iter()
+            .map(|msg| msg.signing_bytes())
+            .collect::<Vec<_>>();
+        let valid = self.aggregate_verify(&msgs, &pubkeys);
+
+        #[cfg(all(feature = "audit-pairings", debug_assertions))]
+        debug_assert_eq!(
+            valid,
+            super::audit::blst_aggregate_verify(
+                &TAggregateSignature::serialize(self),
+                &msgs,
+                &pubkeys
+                    .iter()
+                    .map(|pk| TPublicKey::serialize(*pk))
+                    .collect::<Vec<_>>(),
+            ),
+            "milagro and blst disagree on aggregate_verify"
+        );
+
+        valid
+    }
+
+    fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msg: M,
+        agg_pubkey: &GenericAggregatePublicKey<milagro::PublicKey, milagro::AggregatePublicKey>,
+    ) -> bool {
+        self.fast_aggregate_verify_pre_aggregated(msg.signing_bytes(), agg_pubkey.point())
+    }
+
+    fn aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        agg_pubkeys: &[&GenericAggregatePublicKey<
+            milagro::PublicKey,
+            milagro::AggregatePublicKey,
+        >],
+    ) -> bool {
+        let pubkeys = agg_pubkeys
+            .iter()
+            .map(|agg_pubkey| milagro::PublicKey {
+                point: agg_pubkey.point().point.clone(),
+            })
+            .collect::<Vec<_>>();
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+        let msgs = msgs
+            .iter()
+            .map(|msg| msg.signing_bytes())
+            .collect::<Vec<_>>();
+        self.aggregate_verify(&msgs, &pubkey_refs)
+    }
+
+    fn fast_aggregate_verify_multiple_with_rng<R: RngCore + CryptoRng>(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<milagro::PublicKey>]],
+        rng: &mut R,
+    ) -> bool {
+        if signatures.is_empty()
+            || signatures.len() != msgs.len()
+            || signatures.len() != pubkeys.len()
+        {
+            return false;
+        }
+
+        pubkeys
+            .iter()
+            .map(|signing_keys| {
+                let mut aggregate =
+                    milagro::AggregatePublicKey::from_public_key(signing_keys.first()?.point());
+
+                for signing_key in signing_keys.iter().skip(1) {
+                    aggregate.add(signing_key.point())
+                }
+
+                Some(aggregate)
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|aggregates| {
+                milagro::AggregateSignature::verify_multiple_aggregate_signatures(
+                    rng,
+                    signatures
+                        .iter()
+                        .zip(aggregates.iter())
+                        .zip(msgs.iter())
+                        .map(|((signature, aggregate), msg)| {
+                            (*signature, aggregate, msg.as_bytes())
+                        }),
+                )
+            })
+            .unwrap_or(false)
+    }
+
+    fn fast_aggregate_verify_multiple_pre_aggregated(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        agg_pubkeys: &[&GenericAggregatePublicKey<
+            milagro::PublicKey,
+            milagro::AggregatePublicKey,
+        >],
+    ) -> bool {
+        if signatures.is_empty()
+            || signatures.len() != msgs.len()
+            || signatures.len() != agg_pubkeys.len()
+        {
+            return false;
+        }
+
+        milagro::AggregateSignature::verify_multiple_aggregate_signatures(
+            &mut rand::thread_rng(),
+            signatures
+                .iter()
+                .zip(agg_pubkeys.iter())
+                .zip(msgs.iter())
+                .map(|((signature, agg_pubkey), msg)| {
+                    (*signature, agg_pubkey.point(), msg.as_bytes())
+                }),
+        )
     }
 }
 
 impl TSecretKey<milagro::Signature, milagro::PublicKey> for milagro::SecretKey {
-    fn random() -> Self {
-        Self::random(&mut thread_rng())
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self::random(rng)
     }
 
     fn public_key(&self) -> milagro::PublicKey {
@@ -182,6 +360,11 @@ impl TSecretKey<milagro::Signature, milagro::PublicKey> for milagro::SecretKey {
         milagro::Signature { point }
     }
 
+    fn sign_bytes(&self, msg: &[u8]) -> milagro::Signature {
+        let point = milagro::Signature::new(msg, self).point;
+        milagro::Signature { point }
+    }
+
     fn serialize(&self) -> ZeroizeHash {
         let mut bytes = [0; SECRET_KEY_BYTES_LEN];
 
@@ -194,4 +377,27 @@ impl TSecretKey<milagro::Signature, milagro::PublicKey> for milagro::SecretKey {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(&bytes).map_err(Into::into)
     }
+
+    fn is_valid_scalar(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice {
+        // milagro performs its range check when decoding a key, so the decoded key is discarded.
+        match Self::from_bytes(bytes) {
+            Ok(mut sk) => {
+                <Self as TSecretKey<milagro::Signature, milagro::PublicKey>>::zeroize(&mut sk);
+                Choice::from(1)
+            }
+            Err(_) => Choice::from(0),
+        }
+    }
+
+    fn zeroize(&mut self) {
+        // Safe since `milagro::SecretKey` is a plain big integer which does not contain any
+        // pointers, so the all-zeros bit pattern is valid.
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                self as *mut Self as *mut u8,
+                std::mem::size_of::<Self>(),
+            )
+        };
+        bytes.zeroize();
+    }
 }
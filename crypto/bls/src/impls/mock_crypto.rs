@@ -0,0 +1,512 @@
+//! A deterministic, hash-based backend which is only useful for testing.
+//!
+//! Unlike `fake_crypto`, verification is not always successful: a signature is derived from the
+//! public key, message and DST with SHA-256, so signing with the wrong key or verifying the wrong
+//! message is detected. Aggregation adds signatures together as 768-bit big-endian integers
+//! (wrapping on overflow), which is cheap, commutative and, unlike XOR, does not cancel out
+//! duplicate signatures.
+//!
+//! Anyone who knows a public key can produce signatures for it, so this backend provides no
+//! security whatsoever.
+use crate::{
+    generic_aggregate_public_key::{GenericAggregatePublicKey, TAggregatePublicKey},
+    generic_aggregate_signature::{TAggregateSignature, TAggregateSignatureUncompressed},
+    generic_public_key::{GenericPublicKey, TPublicKey, PUBLIC_KEY_BYTES_LEN},
+    generic_secret_key::{TSecretKey, TSecretKeyWithDst, CURVE_ORDER, SECRET_KEY_BYTES_LEN},
+    generic_signature::{
+        TSignature, TSignatureWithDst, SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN,
+    },
+    scalar::Scalar,
+    Error, Hash256, SignableMessage, ZeroizeHash, DST, INFINITY_SIGNATURE,
+};
+use eth2_hashing::hash;
+use rand::{CryptoRng, RngCore};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+/// Provides the externally-facing, core BLS types.
+pub mod types {
+    pub use super::verify_signature_sets;
+    pub use super::AggregatePublicKey;
+    pub use super::AggregateSignature;
+    pub use super::PublicKey;
+    pub use super::SecretKey;
+    pub use super::Signature;
+    pub use super::SignatureSet;
+}
+
+pub type SignatureSet<'a> = crate::generic_signature_set::GenericSignatureSet<
+    'a,
+    PublicKey,
+    AggregatePublicKey,
+    Signature,
+    AggregateSignature,
+>;
+
+pub fn verify_signature_sets<'a>(
+    mut signature_sets: impl ExactSizeIterator<Item = &'a SignatureSet<'a>>,
+) -> bool {
+    if signature_sets.len() == 0 {
+        return false;
+    }
+
+    signature_sets.all(|signature_set| {
+        // As per the other backends, an infinity signature and a single infinity pubkey is always
+        // declared valid.
+        if signature_set.signature.is_infinity
+            && signature_set.signing_keys.len() == 1
+            && signature_set.signing_keys[0].is_infinity
+        {
+            return true;
+        }
+
+        let signing_keys = signature_set
+            .signing_keys
+            .iter()
+            .map(|pk| pk.as_ref())
+            .collect::<Vec<_>>();
+
+        !signing_keys.is_empty()
+            && signature_set.signature.point().map_or(false, |signature| {
+                signature.fast_aggregate_verify(signature_set.message, &signing_keys)
+            })
+    })
+}
+
+/// Returns the signature by `pubkey` across `msg`, using the `dst` domain separation tag.
+///
+/// Each 32-byte chunk of the signature is `sha256(chunk_index || pubkey || dst_len || dst || msg)`.
+fn mock_signature(pubkey: &PublicKey, msg: &[u8], dst: &[u8]) -> [u8; SIGNATURE_BYTES_LEN] {
+    let mut signature = [0; SIGNATURE_BYTES_LEN];
+
+    for (i, chunk) in signature.chunks_mut(32).enumerate() {
+        let mut preimage = Vec::with_capacity(1 + PUBLIC_KEY_BYTES_LEN + 8 + dst.len() + msg.len());
+        preimage.push(i as u8);
+        preimage.extend_from_slice(&pubkey.0);
+        preimage.extend_from_slice(&(dst.len() as u64).to_le_bytes());
+        preimage.extend_from_slice(dst);
+        preimage.extend_from_slice(msg);
+        chunk.copy_from_slice(&hash(&preimage));
+    }
+
+    signature
+}
+
+/// Returns the sum of the signatures by each `(msg, pubkey)` in `pairs`, using the Eth2 DST.
+fn mock_aggregate_signature<'a, M, I>(pairs: I) -> [u8; SIGNATURE_BYTES_LEN]
+where
+    M: SignableMessage + 'a,
+    I: Iterator<Item = (&'a M, &'a PublicKey)>,
+{
+    let mut aggregate = [0; SIGNATURE_BYTES_LEN];
+    for (msg, pubkey) in pairs {
+        add_assign(
+            &mut aggregate,
+            &mock_signature(pubkey, msg.signing_bytes(), DST),
+        );
+    }
+    aggregate
+}
+
+/// Sets `a` to `a + b`, treating both as equal-length big-endian integers and wrapping on
+/// overflow.
+fn add_assign(a: &mut [u8], b: &[u8]) {
+    let mut carry = 0;
+    for (a, b) in a.iter_mut().zip(b.iter()).rev() {
+        let sum = u16::from(*a) + u16::from(*b) + carry;
+        *a = sum as u8;
+        carry = sum >> 8;
+    }
+}
+
+/// Sets `a` to `a - b`, treating both as equal-length big-endian integers and wrapping on
+/// underflow.
+fn sub_assign(a: &mut [u8], b: &[u8]) {
+    let mut borrow = 0;
+    for (a, b) in a.iter_mut().zip(b.iter()).rev() {
+        let difference = i16::from(*a) - i16::from(*b) - borrow;
+        *a = difference as u8;
+        borrow = if difference < 0 { 1 } else { 0 };
+    }
+}
+
+/// Sets `product` to `a * scalar`, treating `a` as a big-endian integer (wrapping on overflow)
+/// and `scalar` as a big-endian integer, by double-and-add with `add_assign`.
+///
+/// Signatures are not derived linearly from secret keys, so the product of a signature is not a
+/// valid signature; this only exists so the generic blinding and threshold code may run.
+fn mul(product: &mut [u8], a: &[u8], scalar: &[u8]) {
+    product.iter_mut().for_each(|byte| *byte = 0);
+    for byte in scalar {
+        for bit in (0..8).rev() {
+            let double = product.to_vec();
+            add_assign(product, &double);
+            if (byte >> bit) & 1 == 1 {
+                add_assign(product, a);
+            }
+        }
+    }
+}
+
+/// Serializes a signature, representing the identity (all zeros) as `INFINITY_SIGNATURE`.
+fn serialize_signature(bytes: &[u8; SIGNATURE_BYTES_LEN]) -> [u8; SIGNATURE_BYTES_LEN] {
+    if bytes[..] == [0; SIGNATURE_BYTES_LEN][..] {
+        INFINITY_SIGNATURE
+    } else {
+        *bytes
+    }
+}
+
+/// The inverse of `serialize_signature`.
+fn deserialize_signature(bytes: &[u8]) -> Result<[u8; SIGNATURE_BYTES_LEN], Error> {
+    if bytes.len() != SIGNATURE_BYTES_LEN {
+        return Err(Error::InvalidByteLength {
+            got: bytes.len(),
+            expected: SIGNATURE_BYTES_LEN,
+        });
+    }
+
+    let mut signature = [0; SIGNATURE_BYTES_LEN];
+    if bytes != &INFINITY_SIGNATURE[..] {
+        signature.copy_from_slice(bytes);
+    }
+    Ok(signature)
+}
+
+#[derive(Clone)]
+pub struct PublicKey([u8; PUBLIC_KEY_BYTES_LEN]);
+
+impl TPublicKey for PublicKey {
+    fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
+        self.0
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != PUBLIC_KEY_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: PUBLIC_KEY_BYTES_LEN,
+            });
+        }
+
+        let mut pubkey = Self([0; PUBLIC_KEY_BYTES_LEN]);
+        pubkey.0[..].copy_from_slice(bytes);
+        Ok(pubkey)
+    }
+
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TPublicKey>::deserialize(bytes)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        let mut product = Self([0; PUBLIC_KEY_BYTES_LEN]);
+        mul(&mut product.0, &self.0, scalar.as_bytes());
+        product
+    }
+}
+
+impl Eq for PublicKey {}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+/// Holds each of the aggregated public keys, since verification requires the individual keys.
+///
+/// Serializes to the sum of the public keys, so a deserialized aggregate public key only contains
+/// a single public key.
+#[derive(Clone)]
+pub struct AggregatePublicKey(Vec<PublicKey>);
+
+impl TAggregatePublicKey<PublicKey> for AggregatePublicKey {
+    fn infinity() -> Self {
+        Self(vec![])
+    }
+
+    fn aggregate(pubkeys: &[&GenericPublicKey<PublicKey>]) -> Self {
+        Self(pubkeys.iter().map(|pk| pk.point().clone()).collect())
+    }
+
+    fn add_assign_aggregate(&mut self, other: &Self) {
+        self.0.extend_from_slice(&other.0)
+    }
+
+    fn serialize(&self) -> [u8; PUBLIC_KEY_BYTES_LEN] {
+        let mut bytes = [0; PUBLIC_KEY_BYTES_LEN];
+        for pubkey in &self.0 {
+            add_assign(&mut bytes, &pubkey.0);
+        }
+        bytes
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(vec![<PublicKey as TPublicKey>::deserialize(bytes)?]))
+    }
+}
+
+impl Eq for AggregatePublicKey {}
+
+impl PartialEq for AggregatePublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.serialize()[..] == other.serialize()[..]
+    }
+}
+
+#[derive(Clone)]
+pub struct Signature([u8; SIGNATURE_BYTES_LEN]);
+
+impl TSignature<PublicKey> for Signature {
+    fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        serialize_signature(&self.0)
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        deserialize_signature(bytes).map(Self)
+    }
+
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TSignature<PublicKey>>::deserialize(bytes)
+    }
+
+    fn check_encoding(_bytes: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn verify<M: SignableMessage>(&self, pubkey: &PublicKey, msg: M) -> bool {
+        self.verify_with_dst(pubkey, msg.signing_bytes(), DST)
+    }
+
+    fn mul(&self, scalar: &ZeroizeHash) -> Self {
+        let mut product = Self([0; SIGNATURE_BYTES_LEN]);
+        mul(&mut product.0, &self.0, scalar.as_bytes());
+        product
+    }
+}
+
+impl TSignatureWithDst<PublicKey> for Signature {
+    fn verify_with_dst(&self, pubkey: &PublicKey, msg: &[u8], dst: &[u8]) -> bool {
+        self.0[..] == mock_signature(pubkey, msg, dst)[..]
+    }
+}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateSignature([u8; SIGNATURE_BYTES_LEN]);
+
+impl AggregateSignature {
+    /// Returns `true` if `self` is the sum of the signatures by `pubkeys` across `msg`.
+    fn verify_pubkeys<M: SignableMessage>(&self, msg: &M, pubkeys: &[&PublicKey]) -> bool {
+        let pairs = pubkeys.iter().map(|pubkey| (msg, *pubkey));
+        !pubkeys.is_empty() && self.0[..] == mock_aggregate_signature(pairs)[..]
+    }
+}
+
+impl TAggregateSignature<PublicKey, AggregatePublicKey, Signature> for AggregateSignature {
+    fn infinity() -> Self {
+        Self([0; SIGNATURE_BYTES_LEN])
+    }
+
+    fn add_assign(&mut self, other: &Signature) {
+        add_assign(&mut self.0, &other.0)
+    }
+
+    fn add_assign_aggregate(&mut self, other: &Self) {
+        add_assign(&mut self.0, &other.0)
+    }
+
+    fn sub_assign(&mut self, other: &Signature) {
+        sub_assign(&mut self.0, &other.0)
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.0[..] == [0; SIGNATURE_BYTES_LEN][..]
+    }
+
+    fn from_signature(signature: Signature) -> Self {
+        Self(signature.0)
+    }
+
+    fn into_signature(self) -> Signature {
+        Signature(self.0)
+    }
+
+    fn serialize(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        serialize_signature(&self.0)
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        deserialize_signature(bytes).map(Self)
+    }
+
+    fn deserialize_checked(bytes: &[u8]) -> Result<Self, Error> {
+        <Self as TAggregateSignature<_, _, _>>::deserialize(bytes)
+    }
+
+    fn fast_aggregate_verify<M: SignableMessage>(
+        &self,
+        msg: M,
+        pubkeys: &[&GenericPublicKey<PublicKey>],
+    ) -> bool {
+        let pubkeys = pubkeys.iter().map(|pk| pk.point()).collect::<Vec<_>>();
+        self.verify_pubkeys(&msg, &pubkeys)
+    }
+
+    fn aggregate_verify<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        pubkeys: &[&GenericPublicKey<PublicKey>],
+    ) -> bool {
+        let pairs = msgs.iter().zip(pubkeys.iter().map(|pk| pk.point()));
+        !msgs.is_empty()
+            && msgs.len() == pubkeys.len()
+            && self.0[..] == mock_aggregate_signature(pairs)[..]
+    }
+
+    fn fast_aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msg: M,
+        agg_pubkey: &GenericAggregatePublicKey<PublicKey, AggregatePublicKey>,
+    ) -> bool {
+        let pubkeys = agg_pubkey.point().0.iter().collect::<Vec<_>>();
+        self.verify_pubkeys(&msg, &pubkeys)
+    }
+
+    fn aggregate_verify_pre_aggregated<M: SignableMessage>(
+        &self,
+        msgs: &[M],
+        agg_pubkeys: &[&GenericAggregatePublicKey<PublicKey, AggregatePublicKey>],
+    ) -> bool {
+        let pairs = msgs
+            .iter()
+            .zip(agg_pubkeys.iter())
+            .flat_map(|(msg, agg_pubkey)| agg_pubkey.point().0.iter().map(move |pk| (msg, pk)));
+        !msgs.is_empty()
+            && msgs.len() == agg_pubkeys.len()
+            && self.0[..] == mock_aggregate_signature(pairs)[..]
+    }
+
+    fn fast_aggregate_verify_multiple_with_rng<R: RngCore + CryptoRng>(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        pubkeys: &[&[&GenericPublicKey<PublicKey>]],
+        _rng: &mut R,
+    ) -> bool {
+        signatures.len() == msgs.len()
+            && signatures.len() == pubkeys.len()
+            && signatures
+                .iter()
+                .zip(msgs.iter().zip(pubkeys.iter()))
+                .all(|(signature, (msg, pubkeys))| signature.fast_aggregate_verify(*msg, pubkeys))
+    }
+
+    fn fast_aggregate_verify_multiple_pre_aggregated(
+        signatures: &[&Self],
+        msgs: &[Hash256],
+        agg_pubkeys: &[&GenericAggregatePublicKey<PublicKey, AggregatePublicKey>],
+    ) -> bool {
+        signatures.len() == msgs.len()
+            && signatures.len() == agg_pubkeys.len()
+            && signatures
+                .iter()
+                .zip(msgs.iter().zip(agg_pubkeys.iter()))
+                .all(|(signature, (msg, agg_pubkey))| {
+                    signature.fast_aggregate_verify_pre_aggregated(*msg, agg_pubkey)
+                })
+    }
+}
+
+impl TAggregateSignatureUncompressed<PublicKey, AggregatePublicKey, Signature>
+    for AggregateSignature
+{
+    fn serialize_uncompressed(&self) -> [u8; SIGNATURE_UNCOMPRESSED_BYTES_LEN] {
+        let mut bytes = [0; SIGNATURE_UNCOMPRESSED_BYTES_LEN];
+        bytes[..SIGNATURE_BYTES_LEN].copy_from_slice(&self.serialize());
+        bytes
+    }
+
+    fn deserialize_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != SIGNATURE_UNCOMPRESSED_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_UNCOMPRESSED_BYTES_LEN,
+            });
+        }
+
+        <Self as TAggregateSignature<_, _, _>>::deserialize(&bytes[..SIGNATURE_BYTES_LEN])
+    }
+}
+
+impl Eq for AggregateSignature {}
+
+impl PartialEq for AggregateSignature {
+    fn eq(&self, other: &Self) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+#[derive(Clone)]
+pub struct SecretKey([u8; SECRET_KEY_BYTES_LEN]);
+
+impl TSecretKey<Signature, PublicKey> for SecretKey {
+    /// Rejection-samples until the bytes represent a non-zero scalar less than the
+    /// `CURVE_ORDER`, so the key is valid for the real backends too.
+    fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut sk = Self([0; SECRET_KEY_BYTES_LEN]);
+        loop {
+            rng.fill_bytes(&mut sk.0);
+            if sk.0 < CURVE_ORDER && sk.0 != [0; SECRET_KEY_BYTES_LEN] {
+                return sk;
+            }
+        }
+    }
+
+    /// Returns a public key derived from the hash of `self`, prefixed with a zero byte so it can
+    /// never equal the `INFINITY_PUBLIC_KEY`.
+    fn public_key(&self) -> PublicKey {
+        let mut pubkey = PublicKey([0; PUBLIC_KEY_BYTES_LEN]);
+        pubkey.0[1..33].copy_from_slice(&hash(&self.0));
+        pubkey
+    }
+
+    fn sign(&self, msg: Hash256) -> Signature {
+        self.sign_with_dst(msg.as_bytes(), DST)
+    }
+
+    fn sign_bytes(&self, msg: &[u8]) -> Signature {
+        self.sign_with_dst(msg, DST)
+    }
+
+    fn serialize(&self) -> ZeroizeHash {
+        let mut bytes = [0; SECRET_KEY_BYTES_LEN];
+        bytes[..].copy_from_slice(&self.0[..]);
+        bytes.into()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        let mut sk = Self([0; SECRET_KEY_BYTES_LEN]);
+        sk.0[..].copy_from_slice(&bytes[0..SECRET_KEY_BYTES_LEN]);
+        Ok(sk)
+    }
+
+    fn is_valid_scalar(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice {
+        let is_valid = Scalar::from_bytes_be(bytes).map_or(false, |scalar| !scalar.is_zero());
+        Choice::from(is_valid as u8)
+    }
+
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl TSecretKeyWithDst<Signature, PublicKey> for SecretKey {
+    fn sign_with_dst(&self, msg: &[u8], dst: &[u8]) -> Signature {
+        Signature(mock_signature(&self.public_key(), msg, dst))
+    }
+}
@@ -1,3 +1,7 @@
+#[cfg(all(feature = "audit-pairings", debug_assertions))]
+mod audit;
+#[cfg(feature = "supranational")]
 pub mod blst;
 pub mod fake_crypto;
 pub mod milagro;
+pub mod mock_crypto;
@@ -6,43 +6,146 @@
 //! may contain logic that affects the outcomes of cryptographic operations.
 //!
 //! A source of complexity in this crate is that *multiple* BLS implementations (a.k.a. "backends")
-//! are supported via compile-time flags. There are three backends supported via features:
+//! are supported via compile-time flags. There are four backends supported via features:
 //!
 //! - `supranational`: the pure-assembly, highly optimized version from the `blst` crate.
 //! - `milagro`: the classic pure-Rust `milagro_bls` crate.
 //! - `fake_crypto`: an always-returns-valid implementation that is only useful for testing
 //!     scenarios which intend to *ignore* real cryptography.
+//! - `mock_crypto`: a deterministic, hash-based implementation that is only useful for testing.
+//!     Verification is cheap, but still fails for the wrong key or message.
+//!
+//! The `supranational` backend is the default, and is overridden by any of the other backend
+//! features. `fake_crypto` also overrides `milagro`. Enabling `mock_crypto` together with
+//! `milagro` or `fake_crypto` is a compile error. `blst` is only compiled with the `supranational` feature, which `hash_to_g2`,
+//! `PreparedMessage`, `audit-pairings` and `test_backends` also require.
+//!
+//! The `supranational-portable` (or `force-portable`) feature builds `blst` without ADX
+//! instructions. Use `active_backend` to check which backend was compiled in.
+//!
+//! The `arbitrary` feature implements `arbitrary::Arbitrary` using arbitrary bytes, which are
+//! useful for fuzzing decoding. The `arbitrary-valid` feature additionally adds the
+//! `ValidPublicKey`, `ValidSignature` and `ValidAggregateSignature` wrappers, whose
+//! `arbitrary::Arbitrary` impls generate valid points, which are useful for fuzzing verification.
+//!
+//! The `lenient-hex` feature makes the `0x` prefix optional when deserializing keys and
+//! signatures from human-readable formats (e.g., JSON), for compatibility with producers which
+//! omit it. Odd-length hex and incorrect byte lengths are still rejected.
+//!
+//! Signatures are serialized as hex strings in human-readable formats. Fields which must use an
+//! array of bytes instead may opt in with `#[serde(with = "bls::serde_byte_array")]`.
+//!
+//! The `pubkey-cache` feature exposes `AggregatePublicKeyCache`, an LRU cache of aggregate public
+//! keys, keyed by validator indices, which avoids re-aggregating stable sets of validators (e.g.,
+//! committees).
+//!
+//! The `track-count` feature exposes `AggregateSignatureWithCount`, which counts the signatures
+//! aggregated into an `AggregateSignature` for diagnostics. The count is not serialized.
+//!
+//! The `rayon` feature aggregates committees in parallel in `AggregatePublicKey::aggregate_many`.
+//!
+//! The `tracing` feature opens a `tracing` span (at the `TRACE` level) around
+//! `AggregateSignature::fast_aggregate_verify` and `AggregateSignature::aggregate_verify`, recording
+//! the number of public keys and the result.
+//!
+//! The `smallvec` feature adds `Signature::serialize_smallvec`, which avoids a heap allocation
+//! per signature when collecting many serialized signatures.
+//!
+//! The `audit-pairings` feature makes the `supranational` and `milagro` backends repeat each
+//! `aggregate_verify` with the other backend and `debug_assert!` that they agree. It is compiled
+//! out of builds without debug assertions.
+//!
+//! The `unstable-internals` feature exposes the underlying backend points (e.g.,
+//! `AggregateSignature::as_raw`). These have no stability guarantees.
+//!
+//! The `test_backends` feature exposes the `test_backends` module, which checks that the
+//! `milagro` and `supranational` backends agree with each other.
 //!
 //! This crate uses traits to reduce code-duplication between the two implementations. For example,
 //! the `GenericPublicKey` struct exported from this crate is generic across the `TPublicKey` trait
 //! (i.e., `PublicKey<TPublicKey>`). `TPublicKey` is implemented by all three backends (see the
 //! `impls.rs` module). When compiling with the `milagro` feature, we export
 //! `type PublicKey = GenericPublicKey<milagro::PublicKey>`.
+//!
+//! ## WebAssembly
+//!
+//! The `milagro` backend builds for `wasm32-unknown-unknown` with
+//! `--no-default-features --features milagro` (see `examples/wasm` for a `wasm-pack` crate).
+//! The pinned `blst` revision compiles C and assembly via `cc` without a WASM configuration, so
+//! the `supranational` feature must be disabled. Otherwise:
+//!
+//! - `eth2_hashing` uses `ring` natively, which does not build for WASM, however it switches to
+//!     the pure-Rust `sha2` on `wasm32` targets.
+//! - `rand::rngs::OsRng` (used by `SecretKey::random` and batch verification) requires a
+//!     JavaScript entropy source on WASM, which must be enabled via `getrandom`.
+//! - The `rayon` feature requires threads, which are unavailable on WASM.
 
 #[macro_use]
 mod macros;
+mod backend_info;
+mod bytes_visitor;
+mod deposit;
 mod generic_aggregate_public_key;
+#[cfg(feature = "pubkey-cache")]
+mod generic_aggregate_public_key_cache;
 mod generic_aggregate_signature;
+#[cfg(feature = "track-count")]
+mod generic_aggregate_signature_with_count;
+mod generic_aggregate_verifier;
+mod generic_blind;
+mod generic_cached_tree_hash_signature;
 mod generic_keypair;
 mod generic_public_key;
 mod generic_public_key_bytes;
 mod generic_secret_key;
 mod generic_signature;
+mod generic_signature_batch;
 mod generic_signature_bytes;
 mod generic_signature_set;
+mod generic_threshold;
+mod generic_verified_signature;
 mod get_withdrawal_credentials;
+#[cfg(feature = "supranational")]
+mod hash_to_curve;
+mod participation_report;
+mod scalar;
+mod signable_message;
+mod signature_envelope;
+mod signing_root;
+mod verify_report;
 mod zeroize_hash;
 
 pub mod impls;
+pub mod scheme;
+pub mod serde_byte_array;
+#[cfg(feature = "test_backends")]
+pub mod test_backends;
 
+pub use backend_info::{active_backend, enabled_backend, BackendInfo};
+pub use deposit::verify_deposit;
+pub use generic_aggregate_signature::SYNC_COMMITTEE_SIZE;
+pub use generic_blind::BlindingFactor;
 pub use generic_public_key::{INFINITY_PUBLIC_KEY, PUBLIC_KEY_BYTES_LEN};
-pub use generic_secret_key::SECRET_KEY_BYTES_LEN;
-pub use generic_signature::{INFINITY_SIGNATURE, SIGNATURE_BYTES_LEN};
+pub use generic_secret_key::{DST, POP_DST, SECRET_KEY_BYTES_LEN};
+pub use generic_signature::{
+    INFINITY_SIGNATURE, NONE_SIGNATURE, SIGNATURE_BYTES_LEN, SIGNATURE_UNCOMPRESSED_BYTES_LEN,
+};
 pub use get_withdrawal_credentials::get_withdrawal_credentials;
+#[cfg(feature = "supranational")]
+pub use hash_to_curve::{hash_to_g2, G2Point, PreparedMessage};
+pub use participation_report::ParticipationReport;
+pub use signable_message::SignableMessage;
+pub use signature_envelope::{
+    SignatureEnvelope, SIGNATURE_ENVELOPE_BYTES_LEN, SIGNATURE_ENVELOPE_VERSION,
+};
+pub use signing_root::signing_root;
+pub use verify_report::VerifyReport;
 pub use zeroize_hash::ZeroizeHash;
 
+#[cfg(feature = "supranational")]
 use blst::BLST_ERROR as BlstError;
 use milagro_bls::AmclError;
+use std::fmt;
 
 pub type Hash256 = ethereum_types::H256;
 
@@ -51,11 +154,189 @@ pub enum Error {
     /// An error was raised from the Milagro BLS library.
     MilagroError(AmclError),
     /// An error was raised from the Supranational BLST BLS library.
+    #[cfg(feature = "supranational")]
     BlstError(BlstError),
     /// The provided bytes were an incorrect length.
     InvalidByteLength { got: usize, expected: usize },
     /// The provided secret key bytes were an incorrect length.
     InvalidSecretKeyLength { got: usize, expected: usize },
+    /// The provided secret key bytes were not a valid scalar (i.e., zero or not less than the
+    /// curve order).
+    InvalidSecretKeyBytes,
+    /// A verification was attempted without any public keys.
+    NoPublicKeys,
+    /// A verification was attempted against the "empty" aggregate signature.
+    EmptyAggregate,
+    /// A verification was attempted against the "empty" signature.
+    EmptySignature,
+    /// The signature did not verify against the given public keys and message(s).
+    InvalidSignature,
+    /// The provided bytes represent a point which is not in the correct prime-order subgroup.
+    InvalidSubgroup,
+    /// The public key at infinity was supplied to a function which does not accept it (e.g., when
+    /// aggregating public keys or recovering a threshold public key).
+    InvalidInfinityPublicKey,
+    /// The provided bytes are not the canonical encoding of a point (e.g., a coordinate is not
+    /// reduced modulo the field modulus, or the flag bits are inconsistent).
+    NonCanonicalEncoding,
+    /// The flag bits of the provided public key bytes are malformed (e.g., the compression flag
+    /// is not set, or the infinity flag is set with a non-zero `x`).
+    InvalidFlagBits,
+    /// The provided string was not valid `0x`-prefixed hex.
+    InvalidHex(String),
+    /// Fewer shares were provided than the threshold required to recover a value.
+    InsufficientShares { got: usize, threshold: usize },
+    /// A share index was zero.
+    InvalidShareIndex(u64),
+    /// A share index was repeated (e.g., a stale share was signed with again).
+    DuplicateShareIndex(u64),
+    /// An error was raised whilst reading bytes from a stream.
+    IoError(String),
+    /// The public key of a keypair was not derived from its secret key.
+    InconsistentKeypair,
+    /// The length of a bitfield did not match the number of public keys it selects from.
+    InvalidBitfieldLength { got: usize, expected: usize },
+    /// A sync committee participation bitfield was not `SYNC_COMMITTEE_SIZE` bits.
+    InvalidParticipationLength { got: usize, expected: usize },
+    /// The number of messages did not match the number of public keys.
+    InvalidMessageCount { got: usize, expected: usize },
+    /// A versioned encoding had a version which is not supported.
+    UnsupportedVersion(u8),
+    /// The entries of a batch at the given indices were invalid.
+    InvalidSignatures(Vec<usize>),
+    /// More entries of a batch were invalid than the budget allowed (see
+    /// `GenericSignatureBatch::with_max_fallback_items`).
+    TooManyInvalid,
+    /// No public key is known for the given validator index.
+    UnknownValidator(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MilagroError(e) => write!(f, "milagro error: {:?}", e),
+            #[cfg(feature = "supranational")]
+            Error::BlstError(e) => write!(f, "blst error: {:?}", e),
+            Error::InvalidByteLength { got, expected } => {
+                write!(f, "invalid byte length: got {}, expected {}", got, expected)
+            }
+            Error::InvalidSecretKeyLength { got, expected } => write!(
+                f,
+                "invalid secret key length: got {}, expected {}",
+                got, expected
+            ),
+            Error::InvalidSecretKeyBytes => write!(f, "secret key is not a valid scalar"),
+            Error::NoPublicKeys => write!(f, "verification requires at least one public key"),
+            Error::EmptyAggregate => write!(
+                f,
+                "cannot verify the empty aggregate signature (no signatures were added)"
+            ),
+            Error::EmptySignature => write!(f, "cannot verify the empty signature"),
+            Error::InvalidSignature => write!(f, "signature is invalid"),
+            Error::InvalidSubgroup => write!(f, "point is not in the prime-order subgroup"),
+            Error::InvalidInfinityPublicKey => {
+                write!(f, "the public key at infinity is not a valid public key")
+            }
+            Error::NonCanonicalEncoding => write!(f, "point encoding is not canonical"),
+            Error::InvalidFlagBits => write!(f, "public key flag bits are malformed"),
+            Error::InvalidHex(e) => write!(f, "invalid hex: {}", e),
+            Error::InsufficientShares { got, threshold } => write!(
+                f,
+                "insufficient shares: got {}, threshold is {}",
+                got, threshold
+            ),
+            Error::InvalidShareIndex(index) => write!(f, "invalid share index: {}", index),
+            Error::DuplicateShareIndex(index) => write!(f, "duplicate share index: {}", index),
+            Error::IoError(e) => write!(f, "io error: {}", e),
+            Error::InconsistentKeypair => {
+                write!(f, "public key was not derived from the secret key")
+            }
+            Error::InvalidBitfieldLength { got, expected } => write!(
+                f,
+                "invalid bitfield length: got {}, expected {}",
+                got, expected
+            ),
+            Error::InvalidParticipationLength { got, expected } => write!(
+                f,
+                "invalid participation bitfield length: got {}, expected {}",
+                got, expected
+            ),
+            Error::InvalidMessageCount { got, expected } => write!(
+                f,
+                "invalid message count: got {}, expected {}",
+                got, expected
+            ),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported encoding version: {}", version)
+            }
+            Error::InvalidSignatures(indices) => {
+                write!(f, "invalid entries in batch: {:?}", indices)
+            }
+            Error::TooManyInvalid => write!(f, "too many invalid entries in batch"),
+            Error::UnknownValidator(index) => write!(f, "unknown validator index: {}", index),
+        }
+    }
+}
+
+impl Error {
+    /// Returns the reason a point was rejected, if `self` was raised whilst decoding or checking
+    /// a point.
+    ///
+    /// The errors of each backend are mapped to the same taxonomy (see `BackendError`), so they
+    /// can be logged consistently. `Error::InvalidSubgroup` (raised by this crate's own subgroup
+    /// check) maps to `BackendError::PointNotInGroup`.
+    pub fn backend_error(&self) -> Option<BackendError> {
+        match self {
+            #[cfg(feature = "supranational")]
+            Error::BlstError(e) => Some(match e {
+                BlstError::BLST_BAD_ENCODING => BackendError::BadEncoding,
+                BlstError::BLST_POINT_NOT_ON_CURVE => BackendError::PointNotOnCurve,
+                BlstError::BLST_POINT_NOT_IN_GROUP => BackendError::PointNotInGroup,
+                _ => BackendError::Other,
+            }),
+            Error::MilagroError(e) => Some(match e {
+                AmclError::InvalidPoint => BackendError::InvalidPoint,
+                _ => BackendError::Other,
+            }),
+            Error::InvalidSubgroup => Some(BackendError::PointNotInGroup),
+            _ => None,
+        }
+    }
+
+    /// Returns a description of the reason a point was rejected (see `Self::backend_error`).
+    pub fn backend_message(&self) -> Option<&'static str> {
+        self.backend_error().map(BackendError::message)
+    }
+}
+
+/// The reason a BLS backend rejected a point, created by `Error::backend_error`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackendError {
+    /// The bytes are not a valid encoding (e.g., the flag bits are inconsistent or a coordinate is
+    /// not reduced modulo the field modulus).
+    BadEncoding,
+    /// The encoded coordinate is not that of a point on the curve.
+    PointNotOnCurve,
+    /// The point is on the curve, but not in the prime-order subgroup.
+    PointNotInGroup,
+    /// The point is not on the curve or not in the subgroup. Raised by backends which do not
+    /// distinguish the two (i.e., `milagro`).
+    InvalidPoint,
+    /// Any other backend error (e.g., a failed verification).
+    Other,
+}
+
+impl BackendError {
+    /// Returns a description of `self`.
+    pub fn message(self) -> &'static str {
+        match self {
+            BackendError::BadEncoding => "bad point encoding",
+            BackendError::PointNotOnCurve => "point is not on the curve",
+            BackendError::PointNotInGroup => "point is not in the prime-order subgroup",
+            BackendError::InvalidPoint => "point is not on the curve or not in the subgroup",
+            BackendError::Other => "backend error",
+        }
+    }
 }
 
 impl From<AmclError> for Error {
@@ -64,6 +345,7 @@ impl From<AmclError> for Error {
     }
 }
 
+#[cfg(feature = "supranational")]
 impl From<BlstError> for Error {
     fn from(e: BlstError) -> Error {
         Error::BlstError(e)
@@ -72,13 +354,23 @@ impl From<BlstError> for Error {
 
 /// Generic implementations which are only generally useful for docs.
 pub mod generics {
+    pub use crate::generic_aggregate_public_key::GenericAggregatePublicKey;
+    #[cfg(feature = "pubkey-cache")]
+    pub use crate::generic_aggregate_public_key_cache::GenericAggregatePublicKeyCache;
     pub use crate::generic_aggregate_signature::GenericAggregateSignature;
+    #[cfg(feature = "track-count")]
+    pub use crate::generic_aggregate_signature_with_count::GenericAggregateSignatureWithCount;
+    pub use crate::generic_aggregate_verifier::GenericAggregateVerifier;
+    pub use crate::generic_blind::{GenericBlindedMessage, GenericBlindedSignature};
+    pub use crate::generic_cached_tree_hash_signature::GenericCachedTreeHashSignature;
     pub use crate::generic_keypair::GenericKeypair;
     pub use crate::generic_public_key::GenericPublicKey;
     pub use crate::generic_public_key_bytes::GenericPublicKeyBytes;
     pub use crate::generic_secret_key::GenericSecretKey;
     pub use crate::generic_signature::GenericSignature;
+    pub use crate::generic_signature_batch::{GenericSignatureBatch, GenericSignatureBatchEntry};
     pub use crate::generic_signature_bytes::GenericSignatureBytes;
+    pub use crate::generic_verified_signature::GenericVerifiedSignature;
 }
 
 /// Defines all the fundamental BLS points which should be exported by this crate by making
@@ -95,15 +387,56 @@ macro_rules! define_mod {
 
             pub type PublicKey = GenericPublicKey<bls_variant::PublicKey>;
             pub type PublicKeyBytes = GenericPublicKeyBytes<bls_variant::PublicKey>;
+            pub type AggregatePublicKey =
+                GenericAggregatePublicKey<bls_variant::PublicKey, bls_variant::AggregatePublicKey>;
+            #[cfg(feature = "pubkey-cache")]
+            pub type AggregatePublicKeyCache = GenericAggregatePublicKeyCache<
+                bls_variant::PublicKey,
+                bls_variant::AggregatePublicKey,
+            >;
             pub type Signature = GenericSignature<bls_variant::PublicKey, bls_variant::Signature>;
+            pub type CachedTreeHashSignature =
+                GenericCachedTreeHashSignature<bls_variant::PublicKey, bls_variant::Signature>;
             pub type AggregateSignature = GenericAggregateSignature<
                 bls_variant::PublicKey,
                 bls_variant::AggregatePublicKey,
                 bls_variant::Signature,
                 bls_variant::AggregateSignature,
             >;
+            #[cfg(feature = "track-count")]
+            pub type AggregateSignatureWithCount = GenericAggregateSignatureWithCount<
+                bls_variant::PublicKey,
+                bls_variant::AggregatePublicKey,
+                bls_variant::Signature,
+                bls_variant::AggregateSignature,
+            >;
+            pub type AggregateVerifier<'a> = GenericAggregateVerifier<
+                'a,
+                bls_variant::PublicKey,
+                bls_variant::AggregatePublicKey,
+                bls_variant::Signature,
+                bls_variant::AggregateSignature,
+            >;
+            pub type VerifiedSignature<'a> = GenericVerifiedSignature<
+                'a,
+                bls_variant::PublicKey,
+                bls_variant::AggregatePublicKey,
+                bls_variant::Signature,
+                bls_variant::AggregateSignature,
+            >;
             pub type SignatureBytes =
                 GenericSignatureBytes<bls_variant::PublicKey, bls_variant::Signature>;
+            pub type BlindedMessage =
+                GenericBlindedMessage<bls_variant::PublicKey, bls_variant::Signature>;
+            pub type BlindedSignature =
+                GenericBlindedSignature<bls_variant::PublicKey, bls_variant::Signature>;
+            pub type SignatureBatch<'a> = GenericSignatureBatch<
+                'a,
+                bls_variant::PublicKey,
+                bls_variant::AggregatePublicKey,
+                bls_variant::Signature,
+                bls_variant::AggregateSignature,
+            >;
             pub type SecretKey = GenericSecretKey<
                 bls_variant::Signature,
                 bls_variant::PublicKey,
@@ -114,17 +447,121 @@ macro_rules! define_mod {
                 bls_variant::SecretKey,
                 bls_variant::Signature,
             >;
+
+            impl PublicKey {
+                /// Returns `true` if each `items[i].1` is a signature by `self` across
+                /// `items[i].0`, using a single randomized batch verification.
+                ///
+                /// This is faster than verifying each signature individually. Returns `false` if
+                /// `items` is empty.
+                pub fn verify_batch(&self, items: &[(crate::Hash256, &Signature)]) -> bool {
+                    self.verify_batch_attributed(items).is_ok()
+                }
+
+                /// Equivalent to `Self::verify_batch`, except the indices of the invalid items
+                /// are returned on failure (as `Error::InvalidSignatures`).
+                pub fn verify_batch_attributed<'a>(
+                    &'a self,
+                    items: &[(crate::Hash256, &'a Signature)],
+                ) -> Result<(), crate::Error> {
+                    SignatureBatch::verify_single_key(self, items)
+                }
+            }
+
+            impl Signature {
+                /// Aggregates all of `signatures` into a new aggregate signature.
+                ///
+                /// Equivalent to `AggregateSignature::aggregate`, so returns
+                /// `AggregateSignature::empty()` if `signatures` is empty.
+                pub fn aggregate(signatures: &[&Signature]) -> AggregateSignature {
+                    AggregateSignature::aggregate(signatures.iter().copied())
+                }
+            }
+
+            /// Blind signatures, where the signer signs a message without learning it.
+            pub mod blind {
+                use super::{bls_variant, BlindedMessage, BlindedSignature, Signature};
+                use crate::{generic_blind, BlindingFactor, Hash256};
+
+                /// Blinds `msg` with `blinding`, so it may be given to the signer for
+                /// `SecretKey::sign_blinded`.
+                pub fn blind_message(msg: Hash256, blinding: &BlindingFactor) -> BlindedMessage {
+                    generic_blind::blind_message::<_, _, bls_variant::SecretKey>(msg, blinding)
+                }
+
+                /// Removes `blinding` from `blinded_signature`, returning a signature across the
+                /// message that was given to `blind_message`.
+                pub fn unblind(
+                    blinded_signature: &BlindedSignature,
+                    blinding: &BlindingFactor,
+                ) -> Signature {
+                    generic_blind::unblind(blinded_signature, blinding)
+                }
+            }
+
+            /// Recovery of threshold (i.e., Shamir secret-shared) signatures and public keys.
+            pub mod threshold {
+                use super::{bls_variant, PublicKey, Signature};
+                use crate::{generic_threshold, Error};
+
+                pub type SignerShare = generic_threshold::GenericSignerShare<
+                    bls_variant::Signature,
+                    bls_variant::PublicKey,
+                    bls_variant::SecretKey,
+                >;
+
+                /// Recovers a signature from `threshold` signature shares, via Lagrange
+                /// interpolation.
+                pub fn recover_signature(
+                    shares: &[(u64, Signature)],
+                    threshold: usize,
+                ) -> Result<Signature, Error> {
+                    generic_threshold::recover_signature::<
+                        _,
+                        bls_variant::AggregatePublicKey,
+                        _,
+                        bls_variant::AggregateSignature,
+                    >(shares, threshold)
+                }
+
+                /// Recovers a public key from `threshold` public key shares, via Lagrange
+                /// interpolation.
+                pub fn recover_public_key(
+                    shares: &[(u64, PublicKey)],
+                    threshold: usize,
+                ) -> Result<PublicKey, Error> {
+                    generic_threshold::recover_public_key::<_, bls_variant::AggregatePublicKey>(
+                        shares, threshold,
+                    )
+                }
+            }
+
+            #[cfg(feature = "arbitrary-valid")]
+            impl_arbitrary_valid!();
         }
     };
 }
 
 define_mod!(milagro_implementations, crate::impls::milagro::types);
+#[cfg(feature = "supranational")]
 define_mod!(blst_implementations, crate::impls::blst::types);
 #[cfg(feature = "fake_crypto")]
 define_mod!(
     fake_crypto_implementations,
     crate::impls::fake_crypto::types
 );
+#[cfg(feature = "mock_crypto")]
+define_mod!(
+    mock_crypto_implementations,
+    crate::impls::mock_crypto::types
+);
+
+#[cfg(all(
+    feature = "mock_crypto",
+    not(feature = "fake_crypto"),
+    not(feature = "milagro")
+))]
+pub use mock_crypto_implementations::*;
 
 #[cfg(all(feature = "milagro", not(feature = "fake_crypto"),))]
 pub use milagro_implementations::*;
@@ -132,7 +569,8 @@ pub use milagro_implementations::*;
 #[cfg(all(
     feature = "supranational",
     not(feature = "fake_crypto"),
-    not(feature = "milagro")
+    not(feature = "milagro"),
+    not(feature = "mock_crypto")
 ))]
 pub use blst_implementations::*;
 
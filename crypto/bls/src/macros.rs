@@ -78,6 +78,9 @@ macro_rules! impl_ssz_decode {
 
 /// Contains the functions required for a `serde::Serialize` implementation.
 ///
+/// Human-readable formats (e.g., JSON) use a 0x-prefixed hex string, whilst other formats (e.g.,
+/// bincode) use the raw bytes.
+///
 /// Does not include the `Impl` section since it gets very complicated when it comes to generics.
 macro_rules! impl_serde_serialize {
     () => {
@@ -85,13 +88,20 @@ macro_rules! impl_serde_serialize {
         where
             S: Serializer,
         {
-            serializer.serialize_str(&hex_encode(self.serialize().to_vec()))
+            if serializer.is_human_readable() {
+                serializer.serialize_str(&hex_encode(self.serialize().to_vec()))
+            } else {
+                serializer.serialize_bytes(&self.serialize())
+            }
         }
     };
 }
 
 /// Contains the functions required for a `serde::Deserialize` implementation.
 ///
+/// Accepts the formats produced by `impl_serde_serialize`. With the `lenient-hex` feature, the
+/// `0x` prefix is optional in human-readable formats.
+///
 /// Does not include the `Impl` section since it gets very complicated when it comes to generics.
 macro_rules! impl_serde_deserialize {
     () => {
@@ -99,7 +109,11 @@ macro_rules! impl_serde_deserialize {
         where
             D: Deserializer<'de>,
         {
-            let bytes = deserializer.deserialize_str(PrefixedHexVisitor)?;
+            let bytes = if deserializer.is_human_readable() {
+                deserializer.deserialize_str($crate::bytes_visitor::HexStringVisitor)?
+            } else {
+                deserializer.deserialize_bytes($crate::bytes_visitor::BytesVisitor)?
+            };
             Self::deserialize(&bytes[..])
                 .map_err(|e| serde::de::Error::custom(format!("invalid pubkey ({:?})", e)))
         }
@@ -117,6 +131,137 @@ macro_rules! impl_debug {
     };
 }
 
+/// Contains the functions required for a `Display` implementation.
+///
+/// Does not include the `Impl` section since it gets very complicated when it comes to generics.
+macro_rules! impl_display {
+    () => {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", hex_encode(&self.serialize().to_vec()))
+        }
+    };
+}
+
+/// Contains the functions required for a `FromStr` implementation, deferring to the
+/// `TryFrom<&str>` implementation.
+///
+/// Does not include the `Impl` section since it gets very complicated when it comes to generics.
+macro_rules! impl_from_str {
+    () => {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::try_from(s)
+        }
+    };
+}
+
+/// Contains the functions required for a `TryFrom<&str>` implementation, parsing `0x`-prefixed
+/// hex.
+///
+/// Does not include the `Impl` section since it gets very complicated when it comes to generics.
+macro_rules! impl_try_from_str {
+    ($byte_size: expr) => {
+        type Error = Error;
+
+        fn try_from(s: &str) -> Result<Self, Self::Error> {
+            if !s.starts_with("0x") {
+                return Err(Error::InvalidHex("missing 0x prefix".to_string()));
+            }
+
+            let bytes = hex::decode(&s[2..]).map_err(|e| Error::InvalidHex(format!("{:?}", e)))?;
+
+            if bytes.len() != $byte_size {
+                return Err(Error::InvalidByteLength {
+                    got: bytes.len(),
+                    expected: $byte_size,
+                });
+            }
+
+            Self::deserialize(&bytes)
+        }
+    };
+}
+
+/// Contains a `serialize_into` function, which writes the compressed bytes of `self` directly
+/// into a caller-provided buffer.
+///
+/// Intended to be included in an inherent `impl` section which provides `write_compressed`.
+macro_rules! impl_serialize_into {
+    ($byte_size: expr) => {
+        /// Writes the compressed bytes of `self` into the start of `out`, which is useful when
+        /// writing into a larger buffer. Unlike `Self::serialize`, the backend writes directly into
+        /// `out` (where it supports doing so).
+        ///
+        /// Returns `Error::InvalidByteLength` if `out` is shorter than the serialized length.
+        pub fn serialize_into(&self, out: &mut [u8]) -> Result<(), Error> {
+            if out.len() < $byte_size {
+                return Err(Error::InvalidByteLength {
+                    got: out.len(),
+                    expected: $byte_size,
+                });
+            }
+
+            self.write_compressed(&mut out[..$byte_size]);
+            Ok(())
+        }
+    };
+}
+
+/// Contains a `deserialize_from` function, which reads and deserializes exactly the serialized
+/// length from a `std::io::Read`.
+///
+/// Intended to be included in an inherent `impl` section which provides `deserialize`.
+macro_rules! impl_deserialize_from {
+    ($byte_size: expr) => {
+        /// Reads exactly the compressed length from `reader` and deserializes it with
+        /// `Self::deserialize`, which is useful for streaming decoders.
+        ///
+        /// Returns `Error::IoError` if `reader` fails or does not contain enough bytes.
+        pub fn deserialize_from<R: std::io::Read>(reader: &mut R) -> Result<Self, Error> {
+            let mut bytes = [0; $byte_size];
+            reader
+                .read_exact(&mut bytes)
+                .map_err(|e| Error::IoError(e.to_string()))?;
+            Self::deserialize(&bytes)
+        }
+    };
+}
+
+/// Contains the functions required for a `TryFrom<&[u8]>` implementation.
+///
+/// Does not include the `Impl` section since it gets very complicated when it comes to generics.
+macro_rules! impl_try_from_slice {
+    ($byte_size: expr) => {
+        type Error = Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+            if bytes.len() != $byte_size {
+                return Err(Error::InvalidByteLength {
+                    got: bytes.len(),
+                    expected: $byte_size,
+                });
+            }
+
+            Self::deserialize(bytes)
+        }
+    };
+}
+
+/// Contains the functions required for a `TryFrom<Vec<u8>>` implementation, deferring to the
+/// `TryFrom<&[u8]>` implementation.
+///
+/// Does not include the `Impl` section since it gets very complicated when it comes to generics.
+macro_rules! impl_try_from_vec {
+    () => {
+        type Error = Error;
+
+        fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+            Self::try_from(&bytes[..])
+        }
+    };
+}
+
 /// Contains the functions required for an `Arbitrary` implementation.
 ///
 /// Does not include the `Impl` section since it gets very complicated when it comes to generics.
@@ -130,3 +275,97 @@ macro_rules! impl_arbitrary {
         }
     };
 }
+
+/// Defines the `ValidPublicKey`, `ValidSignature` and `ValidAggregateSignature` wrappers, which
+/// implement `Arbitrary` such that they are always valid points, by deriving them from an
+/// arbitrary secret key.
+///
+/// They are wrappers (rather than impls on `PublicKey`, etc.) so that enabling the feature does not
+/// change the behaviour of the existing `Arbitrary` impls for other crates in the build.
+///
+/// Must be invoked in a module which defines those types (see `define_mod`).
+#[cfg(feature = "arbitrary-valid")]
+macro_rules! impl_arbitrary_valid {
+    () => {
+        /// Returns a valid secret key derived from arbitrary bytes.
+        fn arbitrary_secret_key(
+            u: &mut arbitrary::Unstructured<'_>,
+        ) -> arbitrary::Result<SecretKey> {
+            let mut bytes = [0u8; crate::SECRET_KEY_BYTES_LEN];
+            u.fill_buffer(&mut bytes)?;
+            // Clearing the two most-significant bits ensures the scalar is less than the curve
+            // order, whilst setting the least-significant bit ensures it is non-zero.
+            bytes[0] &= 0x3f;
+            bytes[crate::SECRET_KEY_BYTES_LEN - 1] |= 1;
+            SecretKey::deserialize(&bytes).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+
+        /// Returns a message derived from arbitrary bytes.
+        fn arbitrary_message(
+            u: &mut arbitrary::Unstructured<'_>,
+        ) -> arbitrary::Result<crate::Hash256> {
+            let mut bytes = [0u8; 32];
+            u.fill_buffer(&mut bytes)?;
+            Ok(crate::Hash256::from_slice(&bytes))
+        }
+
+        /// A `PublicKey` which is always a valid point when generated by `Arbitrary`.
+        #[derive(Clone, Debug)]
+        pub struct ValidPublicKey(pub PublicKey);
+
+        impl arbitrary::Arbitrary for ValidPublicKey {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+                Ok(Self(arbitrary_secret_key(u)?.public_key()))
+            }
+        }
+
+        /// A `Signature` which is always a valid point when generated by `Arbitrary`.
+        #[derive(Clone, Debug)]
+        pub struct ValidSignature(pub Signature);
+
+        impl arbitrary::Arbitrary for ValidSignature {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+                let sk = arbitrary_secret_key(u)?;
+                Ok(Self(sk.sign(arbitrary_message(u)?)))
+            }
+        }
+
+        /// An `AggregateSignature` which is always a valid point when generated by `Arbitrary`.
+        #[derive(Clone, Debug)]
+        pub struct ValidAggregateSignature(pub AggregateSignature);
+
+        /// Aggregates between one and four signatures across the same message.
+        impl arbitrary::Arbitrary for ValidAggregateSignature {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+                let msg = arbitrary_message(u)?;
+                let n = 1 + <u8 as arbitrary::Arbitrary>::arbitrary(u)? % 4;
+                let mut aggregate = AggregateSignature::infinity();
+                for _ in 0..n {
+                    aggregate.add_assign(&arbitrary_secret_key(u)?.sign(msg));
+                }
+                Ok(Self(aggregate))
+            }
+        }
+    };
+}
+
+/// Evaluates `$verify` (a `bool`) within a `tracing` span named `$name`, recording the number of
+/// public keys and the result.
+///
+/// Expands to just `$verify` when the `tracing` feature is disabled.
+macro_rules! traced_verify {
+    ($name: expr, $num_pubkeys: expr, $verify: expr) => {{
+        #[cfg(feature = "tracing")]
+        let span =
+            tracing::trace_span!($name, pubkeys = $num_pubkeys, valid = tracing::field::Empty);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+
+        let valid: bool = $verify;
+
+        #[cfg(feature = "tracing")]
+        span.record("valid", &valid);
+
+        valid
+    }};
+}
@@ -0,0 +1,16 @@
+/// The participation of a committee in an aggregate signature, created by
+/// `GenericAggregateSignature::verify_and_report_participation`.
+///
+/// An aggregate signature does not reveal which public keys contributed to it, so participation is
+/// derived from the bitfield which accompanies it (e.g., the `aggregation_bits` of an
+/// attestation). The report is only created once the signature has been verified against the
+/// participants, so the bitfield can be trusted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParticipationReport {
+    /// The number of committee members whose bit was set.
+    pub participants: usize,
+    /// The number of committee members whose bit was not set.
+    pub non_participants: usize,
+    /// The index in the committee of each member whose bit was not set, in ascending order.
+    pub non_participant_indices: Vec<usize>,
+}
@@ -0,0 +1,173 @@
+//! Arithmetic modulo the curve order, for the scalars used by blind signatures and threshold
+//! recovery.
+//!
+//! A `Scalar` is a fixed-size array which is zeroized on drop, so secret scalars (e.g., a
+//! `BlindingFactor`) are never copied into a heap-allocated integer. Each operation performs the
+//! same sequence of steps regardless of the values involved.
+
+use crate::{generic_secret_key::CURVE_ORDER, ZeroizeHash, SECRET_KEY_BYTES_LEN};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// The number of 64-bit limbs in a `Scalar`.
+const LIMBS: usize = 4;
+
+/// The number of bits in a `Scalar`.
+const BITS: usize = LIMBS * 64;
+
+/// An integer modulo the curve order, as little-endian 64-bit limbs.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub(crate) struct Scalar([u64; LIMBS]);
+
+impl Scalar {
+    /// Returns the scalar `0`.
+    pub fn zero() -> Self {
+        Self([0; LIMBS])
+    }
+
+    /// Returns `n` as a scalar.
+    pub fn from_u64(n: u64) -> Self {
+        // The curve order is greater than `u64::MAX`, so `n` is never reduced.
+        Self([n, 0, 0, 0])
+    }
+
+    /// Instantiates `Self` from big-endian bytes, returning `None` if they are not less than the
+    /// curve order.
+    pub fn from_bytes_be(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Option<Self> {
+        let scalar = Self(limbs_from_bytes_be(bytes));
+        let (_, borrow) = sub_limbs(&scalar.0, &modulus());
+        if borrow == 1 {
+            Some(scalar)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a `Choice` which is set if the big-endian `bytes` are a non-zero scalar less than
+    /// the curve order, without branching on `bytes`.
+    pub fn is_valid_secret_key(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> Choice {
+        let mut limbs = limbs_from_bytes_be(bytes);
+        let (mut difference, borrow) = sub_limbs(&limbs, &modulus());
+        let is_zero = limbs.iter().fold(0, |acc, limb| acc | limb).ct_eq(&0);
+        limbs.zeroize();
+        difference.zeroize();
+
+        Choice::from(borrow as u8) & !is_zero
+    }
+
+    /// Serialize `self` as big-endian bytes.
+    pub fn to_bytes_be(&self) -> ZeroizeHash {
+        let mut bytes = ZeroizeHash::zero();
+        for (chunk, limb) in bytes.as_mut_bytes().chunks_mut(8).zip(self.0.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().fold(0, |acc, limb| acc | limb) == 0
+    }
+
+    /// Returns `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        // Both are less than the curve order (which is less than 2^255), so the sum never
+        // overflows and at most one subtraction is required.
+        let (sum, _) = add_limbs(&self.0, &other.0);
+        let (reduced, borrow) = sub_limbs(&sum, &modulus());
+        Self::select(&Self(reduced), &Self(sum), Choice::from(borrow as u8))
+    }
+
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        let (difference, borrow) = sub_limbs(&self.0, &other.0);
+        let (wrapped, _) = add_limbs(&difference, &modulus());
+        Self::select(
+            &Self(difference),
+            &Self(wrapped),
+            Choice::from(borrow as u8),
+        )
+    }
+
+    /// Returns `self * other`, by double-and-add across every bit of `other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut product = Self::zero();
+        for i in (0..BITS).rev() {
+            product = product.add(&product);
+            let sum = product.add(self);
+            product = Self::select(&product, &sum, bit(&other.0, i));
+        }
+        product
+    }
+
+    /// Returns the multiplicative inverse of `self`, or zero if `self` is zero.
+    pub fn invert(&self) -> Self {
+        // The curve order is prime, so the inverse is `self ^ (r - 2)`.
+        let (exponent, _) = sub_limbs(&modulus(), &[2, 0, 0, 0]);
+
+        let mut power = Self::from_u64(1);
+        for i in (0..BITS).rev() {
+            power = power.mul(&power);
+            let product = power.mul(self);
+            power = Self::select(&power, &product, bit(&exponent, i));
+        }
+        power
+    }
+
+    /// Returns `b` if `choice` is set, otherwise `a`.
+    fn select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut selected = Self::zero();
+        for (limb, (a, b)) in selected.0.iter_mut().zip(a.0.iter().zip(b.0.iter())) {
+            *limb = u64::conditional_select(a, b, choice);
+        }
+        selected
+    }
+}
+
+/// Returns the curve order as little-endian limbs.
+fn modulus() -> [u64; LIMBS] {
+    limbs_from_bytes_be(&CURVE_ORDER)
+}
+
+/// Converts big-endian bytes to little-endian limbs.
+fn limbs_from_bytes_be(bytes: &[u8; SECRET_KEY_BYTES_LEN]) -> [u64; LIMBS] {
+    let mut limbs = [0; LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8).rev()) {
+        let mut limb_bytes = [0; 8];
+        limb_bytes.copy_from_slice(chunk);
+        *limb = u64::from_be_bytes(limb_bytes);
+    }
+    limbs
+}
+
+/// Returns a `Choice` which is set if bit `i` of `limbs` is one.
+fn bit(limbs: &[u64; LIMBS], i: usize) -> Choice {
+    Choice::from(((limbs[i / 64] >> (i % 64)) & 1) as u8)
+}
+
+/// Returns `a + b` and the carry out of the most-significant limb.
+fn add_limbs(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> ([u64; LIMBS], u64) {
+    let mut sum = [0; LIMBS];
+    let mut carry = 0;
+    for i in 0..LIMBS {
+        let wide = u128::from(a[i]) + u128::from(b[i]) + u128::from(carry);
+        sum[i] = wide as u64;
+        carry = (wide >> 64) as u64;
+    }
+    (sum, carry)
+}
+
+/// Returns `a - b` (wrapping) and the borrow out of the most-significant limb.
+fn sub_limbs(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> ([u64; LIMBS], u64) {
+    let mut difference = [0; LIMBS];
+    let mut borrow = 0;
+    for i in 0..LIMBS {
+        let wide = u128::from(a[i])
+            .wrapping_sub(u128::from(b[i]))
+            .wrapping_sub(u128::from(borrow));
+        difference[i] = wide as u64;
+        borrow = (wide >> 127) as u64;
+    }
+    (difference, borrow)
+}
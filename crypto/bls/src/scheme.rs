@@ -0,0 +1,58 @@
+//! Provides the message-augmentation scheme from the IRTF BLS signature draft, for protocols
+//! which do not use proofs of possession.
+//!
+//! Eth2 uses the proof-of-possession scheme (see `DST` and `POP_DST`), which remains the default
+//! throughout this crate. With message augmentation, the signer's compressed public key is
+//! prepended to each message before it is hashed, so rogue-key attacks are prevented without a
+//! proof of possession.
+//!
+//! Only the `supranational` backend supports custom domain separation tags.
+
+use crate::{
+    generic_public_key::{GenericPublicKey, TPublicKey},
+    generic_secret_key::{GenericSecretKey, TSecretKeyWithDst},
+    generic_signature::{GenericSignature, TSignature, TSignatureWithDst},
+};
+
+/// The domain separation tag used by the message-augmentation scheme.
+pub const AUG_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_";
+
+/// Returns `pubkey || msg`, the bytes which are hashed by the message-augmentation scheme.
+fn augment<Pub: TPublicKey>(pubkey: &GenericPublicKey<Pub>, msg: &[u8]) -> Vec<u8> {
+    let mut augmented = pubkey.serialize().to_vec();
+    augmented.extend_from_slice(msg);
+    augmented
+}
+
+/// Signs `msg` with `secret_key` under the message-augmentation scheme (i.e., signs
+/// `secret_key.public_key() || msg` using the `AUG_DST`).
+///
+/// Verify with `verify_augmented`.
+pub fn sign_augmented<Sig, Pub, Sec>(
+    secret_key: &GenericSecretKey<Sig, Pub, Sec>,
+    msg: &[u8],
+) -> GenericSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+    Pub: TPublicKey,
+    Sec: TSecretKeyWithDst<Sig, Pub>,
+{
+    secret_key.sign_with_dst(&augment(&secret_key.public_key(), msg), AUG_DST)
+}
+
+/// Returns `true` if `signature` is a signature across `msg` by `pubkey` under the
+/// message-augmentation scheme.
+///
+/// Always returns `false` if `pubkey` is the public key at infinity or `signature` is the "empty"
+/// value.
+pub fn verify_augmented<Pub, Sig>(
+    signature: &GenericSignature<Pub, Sig>,
+    pubkey: &GenericPublicKey<Pub>,
+    msg: &[u8],
+) -> bool
+where
+    Sig: TSignatureWithDst<Pub>,
+    Pub: TPublicKey + Clone,
+{
+    signature.verify_with_dst(pubkey, &augment(pubkey, msg), AUG_DST)
+}
@@ -0,0 +1,93 @@
+//! Serializes signatures as an array of bytes (rather than a hex string) in human-readable
+//! formats, for schemas which require it.
+//!
+//! Opt in per field with `#[serde(with = "bls::serde_byte_array")]`. Deserialization accepts
+//! either an array of bytes or a hex string (as per the default `Deserialize` implementation).
+//! Non-human-readable formats (e.g., bincode) use the raw bytes, as per the default
+//! implementations.
+
+use crate::{
+    bytes_visitor::{BytesVisitor, HexOrByteArrayVisitor},
+    generic_aggregate_signature::{GenericAggregateSignature, TAggregateSignature},
+    generic_signature::{GenericSignature, TSignature},
+    generic_signature_bytes::GenericSignatureBytes,
+    Error, SIGNATURE_BYTES_LEN,
+};
+use serde::de::{Deserializer, Error as _};
+use serde::ser::Serializer;
+
+/// Implemented on the signature types which may be used with `serde_byte_array`.
+pub trait SignatureByteArray: Sized {
+    /// Serialize `self` as compressed bytes.
+    fn to_byte_array(&self) -> [u8; SIGNATURE_BYTES_LEN];
+
+    /// Deserialize `Self` from compressed bytes.
+    fn from_byte_array(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+impl<Pub, Sig> SignatureByteArray for GenericSignature<Pub, Sig>
+where
+    Sig: TSignature<Pub>,
+{
+    fn to_byte_array(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        self.serialize()
+    }
+
+    fn from_byte_array(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize(bytes)
+    }
+}
+
+impl<Pub, AggPub, Sig, AggSig> SignatureByteArray
+    for GenericAggregateSignature<Pub, AggPub, Sig, AggSig>
+where
+    Sig: TSignature<Pub>,
+    AggSig: TAggregateSignature<Pub, AggPub, Sig>,
+{
+    fn to_byte_array(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        self.serialize()
+    }
+
+    fn from_byte_array(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize(bytes)
+    }
+}
+
+impl<Pub, Sig> SignatureByteArray for GenericSignatureBytes<Pub, Sig> {
+    fn to_byte_array(&self) -> [u8; SIGNATURE_BYTES_LEN] {
+        self.serialize()
+    }
+
+    fn from_byte_array(bytes: &[u8]) -> Result<Self, Error> {
+        Self::deserialize(bytes)
+    }
+}
+
+/// Serializes `signature` as an array of bytes in human-readable formats.
+pub fn serialize<T, S>(signature: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SignatureByteArray,
+    S: Serializer,
+{
+    let bytes = signature.to_byte_array();
+    if serializer.is_human_readable() {
+        serializer.collect_seq(bytes.iter())
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserializes a signature from either an array of bytes or a hex string in human-readable
+/// formats.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: SignatureByteArray,
+    D: Deserializer<'de>,
+{
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_any(HexOrByteArrayVisitor)?
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)?
+    };
+    T::from_byte_array(&bytes).map_err(|e| D::Error::custom(format!("invalid signature ({:?})", e)))
+}
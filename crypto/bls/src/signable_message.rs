@@ -0,0 +1,22 @@
+use crate::Hash256;
+
+/// A message which may be signed, or have a signature across it verified.
+///
+/// The bytes returned by `Self::signing_bytes` are hashed to a curve point by the BLS backend, so
+/// messages may be of arbitrary length.
+pub trait SignableMessage {
+    /// Returns the bytes which are signed.
+    fn signing_bytes(&self) -> &[u8];
+}
+
+impl SignableMessage for Hash256 {
+    fn signing_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> SignableMessage for &'a [u8] {
+    fn signing_bytes(&self) -> &[u8] {
+        self
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Error, SIGNATURE_BYTES_LEN};
+
+/// The version of the current signature encoding (i.e., compressed G2 points, as per the Eth2
+/// specification).
+pub const SIGNATURE_ENVELOPE_VERSION: u8 = 0;
+
+/// The byte-length of a `SignatureEnvelope` (a 1-byte version tag followed by the payload).
+pub const SIGNATURE_ENVELOPE_BYTES_LEN: usize = 1 + SIGNATURE_BYTES_LEN;
+
+/// A serialized signature, prefixed with the version of its encoding.
+///
+/// Allows the signature encoding to be changed in future without ambiguity, since decoders reject
+/// any version they do not support. Only `SIGNATURE_ENVELOPE_VERSION` is presently supported.
+///
+/// This is only intended for protocols which opt into versioning; SSZ still uses the unversioned
+/// encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignatureEnvelope {
+    version: u8,
+    payload: [u8; SIGNATURE_BYTES_LEN],
+}
+
+impl SignatureEnvelope {
+    /// Instantiates `Self` from the `payload` of a signature encoded as per `version`.
+    ///
+    /// Returns `Error::UnsupportedVersion` if `version` is not supported.
+    pub fn new(version: u8, payload: [u8; SIGNATURE_BYTES_LEN]) -> Result<Self, Error> {
+        if version != SIGNATURE_ENVELOPE_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        Ok(Self { version, payload })
+    }
+
+    /// Returns the version of the encoding of `self.payload()`.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the encoded signature.
+    pub fn payload(&self) -> &[u8; SIGNATURE_BYTES_LEN] {
+        &self.payload
+    }
+
+    /// Serialize `self` as the version tag followed by the payload.
+    pub fn serialize(&self) -> [u8; SIGNATURE_ENVELOPE_BYTES_LEN] {
+        let mut bytes = [0; SIGNATURE_ENVELOPE_BYTES_LEN];
+        bytes[0] = self.version;
+        bytes[1..].copy_from_slice(&self.payload);
+        bytes
+    }
+
+    /// Deserialize `self` from a version tag followed by the payload.
+    ///
+    /// The version is checked before the length, so an unknown version is always reported as
+    /// `Error::UnsupportedVersion` (a future version may have a different payload length).
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        let (&version, payload) = bytes.split_first().ok_or(Error::InvalidByteLength {
+            got: 0,
+            expected: SIGNATURE_ENVELOPE_BYTES_LEN,
+        })?;
+
+        if version != SIGNATURE_ENVELOPE_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        if payload.len() != SIGNATURE_BYTES_LEN {
+            return Err(Error::InvalidByteLength {
+                got: bytes.len(),
+                expected: SIGNATURE_ENVELOPE_BYTES_LEN,
+            });
+        }
+
+        let mut array = [0; SIGNATURE_BYTES_LEN];
+        array.copy_from_slice(payload);
+        Self::new(version, array)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::Hash256;
+
+/// Returns the root which is signed for an object with `object_root` in `domain`.
+///
+/// Equivalent to `hash_tree_root(SigningData { object_root, domain })` (i.e.,
+/// `compute_signing_root` in the consensus spec), without depending upon the `types` crate.
+pub fn signing_root(object_root: Hash256, domain: Hash256) -> Hash256 {
+    let mut leaves = [0; 64];
+    leaves[..32].copy_from_slice(object_root.as_bytes());
+    leaves[32..].copy_from_slice(domain.as_bytes());
+    tree_hash::merkle_root(&leaves, 2)
+}
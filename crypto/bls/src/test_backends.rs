@@ -0,0 +1,44 @@
+//! Provides functions for checking that the BLS backends agree with each other.
+//!
+//! Only available with the `test_backends` feature.
+
+use crate::{blst_implementations, milagro_implementations, Hash256};
+
+/// Deserializes `sig_bytes` and `pubkey_bytes` with each backend and verifies the signature across
+/// `msg`, returning the result of the verification.
+///
+/// A failure to deserialize either the signature or public key is treated as a failed
+/// verification. The backends must also agree on whether or not the bytes are valid.
+///
+/// ## Panics
+///
+/// If the backends do not produce identical results.
+pub fn assert_backends_agree(sig_bytes: &[u8], msg: Hash256, pubkey_bytes: &[u8]) -> bool {
+    let milagro = milagro_verify(sig_bytes, msg, pubkey_bytes);
+    let blst = blst_verify(sig_bytes, msg, pubkey_bytes);
+
+    assert_eq!(
+        milagro, blst,
+        "milagro and blst disagree on verification (None indicates invalid bytes)"
+    );
+
+    milagro.unwrap_or(false)
+}
+
+/// Verifies with the milagro backend, returning `None` if the bytes cannot be deserialized.
+fn milagro_verify(sig_bytes: &[u8], msg: Hash256, pubkey_bytes: &[u8]) -> Option<bool> {
+    use milagro_implementations::{PublicKey, Signature};
+
+    let signature = Signature::deserialize(sig_bytes).ok()?;
+    let pubkey = PublicKey::deserialize(pubkey_bytes).ok()?;
+    Some(signature.verify(&pubkey, msg))
+}
+
+/// Verifies with the blst backend, returning `None` if the bytes cannot be deserialized.
+fn blst_verify(sig_bytes: &[u8], msg: Hash256, pubkey_bytes: &[u8]) -> Option<bool> {
+    use blst_implementations::{PublicKey, Signature};
+
+    let signature = Signature::deserialize(sig_bytes).ok()?;
+    let pubkey = PublicKey::deserialize(pubkey_bytes).ok()?;
+    Some(signature.verify(&pubkey, msg))
+}
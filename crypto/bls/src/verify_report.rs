@@ -0,0 +1,19 @@
+/// A summary of a `GenericAggregateSignature::fast_aggregate_verify`, created by
+/// `GenericAggregateSignature::verify_report`.
+///
+/// Intended for diagnosing signatures which persistently fail to verify, since a `bool` does not
+/// indicate which of the inputs was at fault.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerifyReport {
+    /// The number of public keys which were verified against.
+    pub num_pubkeys: usize,
+    /// True if any of the public keys was the public key at infinity.
+    pub has_infinity_pubkey: bool,
+    /// True if the signature was the "empty" value.
+    pub signature_is_empty: bool,
+    /// The `0x`-prefixed hex encoding of the aggregate of the public keys, or `None` if they
+    /// could not be aggregated (e.g., there were none).
+    pub aggregate_pubkey: Option<String>,
+    /// True if the signature is valid.
+    pub valid: bool,
+}
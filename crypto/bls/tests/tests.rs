@@ -1,14 +1,83 @@
-use bls::{Hash256, INFINITY_PUBLIC_KEY, INFINITY_SIGNATURE};
+use bls::{
+    BlindingFactor, Error, Hash256, VerifyReport, INFINITY_PUBLIC_KEY, INFINITY_SIGNATURE,
+    NONE_SIGNATURE, PUBLIC_KEY_BYTES_LEN, SIGNATURE_ENVELOPE_BYTES_LEN, SIGNATURE_ENVELOPE_VERSION,
+};
+use rand_chacha::{
+    rand_core::{self, CryptoRng, RngCore, SeedableRng},
+    ChaCha20Rng,
+};
 use ssz::{Decode, Encode};
 use std::borrow::Cow;
+use std::convert::TryFrom;
 use std::fmt::Debug;
+use tree_hash::TreeHash;
+use zeroize::Zeroize;
 
 fn ssz_round_trip<T: Encode + Decode + PartialEq + Debug>(item: T) {
     assert_eq!(item, T::from_ssz_bytes(&item.as_ssz_bytes()).unwrap());
 }
 
+/// Drops `value` without releasing its backing store, then returns the bytes left behind.
+///
+/// The bytes are read with volatile reads, so the compiler cannot elide the writes made by
+/// `Drop` as dead stores.
+fn bytes_after_drop<T>(value: T) -> Vec<u8> {
+    let mut value = std::mem::ManuallyDrop::new(value);
+    let ptr: *mut T = &mut *value;
+
+    // Safe since `value` is never used after it is dropped, and its backing store remains valid
+    // until the end of this function.
+    unsafe {
+        std::ptr::drop_in_place(ptr);
+        (0..std::mem::size_of::<T>())
+            .map(|i| std::ptr::read_volatile((ptr as *const u8).add(i)))
+            .collect()
+    }
+}
+
+/// Wraps a seeded `ChaCha20Rng`, counting the number of times it is drawn from.
+struct CountingRng {
+    inner: ChaCha20Rng,
+    draws: usize,
+}
+
+impl CountingRng {
+    fn new(seed: [u8; 32]) -> Self {
+        Self {
+            inner: ChaCha20Rng::from_seed(seed),
+            draws: 0,
+        }
+    }
+}
+
+impl RngCore for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 1;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.draws += 1;
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.draws += 1;
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for CountingRng {}
+
+/// Instantiates the tests which are shared by every real backend, where `$not_in_group_error` is
+/// the `BackendError` the backend raises for a point outside the prime-order subgroup.
 macro_rules! test_suite {
-    ($impls: ident) => {
+    ($impls: ident, $not_in_group_error: expr) => {
         use super::*;
         use bls::$impls::*;
 
@@ -19,596 +88,4001 @@ macro_rules! test_suite {
             SecretKey::deserialize(&secret_bytes).unwrap()
         }
 
+        /// Pins the big-endian byte layout of secret keys, public keys and signatures, so a backend
+        /// which interprets any of them as little-endian fails.
         #[test]
-        fn infinity_agg_sig() {
-            assert_eq!(
-                &AggregateSignature::infinity().serialize()[..],
-                &INFINITY_SIGNATURE[..]
-            );
+        fn golden_byte_layouts() {
+            // Secret keys are big-endian scalars: `1` is the last byte and maps to the generator.
+            let mut one = [0; 32];
+            one[31] = 1;
+            let sk = SecretKey::deserialize(&one).unwrap();
+            assert_eq!(sk.serialize().as_bytes(), &one[..]);
             assert_eq!(
-                AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
-                AggregateSignature::infinity(),
+                hex::encode(&sk.public_key().serialize()[..]),
+                "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
             );
-        }
-
-        #[test]
-        fn ssz_round_trip_multiple_types() {
-            let mut agg_sig = AggregateSignature::infinity();
-            ssz_round_trip(agg_sig.clone());
-
-            let msg = Hash256::from_low_u64_be(42);
-            let secret = secret_from_u64(42);
 
-            let sig = secret.sign(msg);
-            ssz_round_trip(sig.clone());
+            // Key pairs from the Eth2 BLS test vectors.
+            let vectors = [
+                (
+                    "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+                    "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+                ),
+                (
+                    "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+                    "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+                ),
+                (
+                    "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+                    "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+                ),
+            ];
+            for (sk_hex, pk_hex) in &vectors {
+                let sk = SecretKey::deserialize(&hex::decode(sk_hex).unwrap()).unwrap();
+                assert_eq!(hex::encode(sk.serialize().as_bytes()), *sk_hex);
+                assert_eq!(hex::encode(&sk.public_key().serialize()[..]), *pk_hex);
+            }
 
-            agg_sig.add_assign(&sig);
-            ssz_round_trip(agg_sig);
+            // The G2 generator plus twice the G2 generator is three times the G2 generator.
+            let g2 = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+            let g2_times_two = "aa4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c335771638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053";
+            let g2_times_three = "89380275bbc8e5dcea7dc4dd7e0550ff2ac480905396eda55062650f8d251c96eb480673937cc6d9d6a44aaa56ca66dc122915c824a0857e2ee414a3dccb23ae691ae54329781315a0c75df1c04d6d7a50a030fc866f09d516020ef82324afae";
+            let aggregate = AggregateSignature::aggregate(&[
+                Signature::deserialize(&hex::decode(g2).unwrap()).unwrap(),
+                Signature::deserialize(&hex::decode(g2_times_two).unwrap()).unwrap(),
+            ]);
+            assert_eq!(hex::encode(&aggregate.serialize()[..]), g2_times_three);
         }
 
         #[test]
-        fn ssz_round_trip_sig_empty() {
-            ssz_round_trip(Signature::empty())
+        fn secret_key_debug_is_redacted() {
+            let sk = secret_from_u64(42);
+            let bytes = sk.serialize();
+            let debug = format!("{:?}", sk);
+
+            assert_eq!(debug, "SecretKey(<redacted>)");
+            assert!(!debug.contains(&hex::encode(bytes.as_bytes())));
+            for byte in bytes.as_bytes() {
+                assert!(!debug.contains(&format!("{:02x}", byte)));
+            }
         }
 
         #[test]
-        fn ssz_round_trip_agg_sig_empty() {
-            ssz_round_trip(AggregateSignature::empty())
+        fn random_with_rng_is_deterministic() {
+            let sk_a = SecretKey::random_with_rng(&mut ChaCha20Rng::from_seed([42; 32]));
+            let sk_b = SecretKey::random_with_rng(&mut ChaCha20Rng::from_seed([42; 32]));
+            let sk_c = SecretKey::random_with_rng(&mut ChaCha20Rng::from_seed([43; 32]));
+            assert_eq!(sk_a.serialize().as_bytes(), sk_b.serialize().as_bytes());
+            assert!(sk_a.serialize().as_bytes() != sk_c.serialize().as_bytes());
+
+            let kp_a = Keypair::random_with_rng(&mut ChaCha20Rng::from_seed([42; 32]));
+            let kp_b = Keypair::random_with_rng(&mut ChaCha20Rng::from_seed([42; 32]));
+            assert_eq!(kp_a.pk, kp_b.pk);
+            assert_eq!(kp_a.pk, sk_a.public_key());
         }
 
         #[test]
-        fn ssz_round_trip_agg_sig_infinity() {
-            ssz_round_trip(AggregateSignature::infinity())
-        }
+        fn secret_key_deserialize_ct() {
+            let sk = secret_from_u64(42);
+            let mut bytes = [0; 32];
+            bytes.copy_from_slice(sk.serialize().as_bytes());
 
-        #[test]
-        fn partial_eq_empty_sig() {
-            assert_eq!(Signature::empty(), Signature::empty())
+            let decoded = SecretKey::deserialize_ct(&bytes);
+            assert!(bool::from(decoded.is_some()));
+            assert_eq!(
+                decoded.unwrap().serialize().as_bytes(),
+                sk.serialize().as_bytes()
+            );
+
+            assert!(bool::from(SecretKey::deserialize_ct(&[0; 32]).is_none()));
+            assert!(bool::from(SecretKey::deserialize_ct(&[0xff; 32]).is_none()));
         }
 
         #[test]
-        fn partial_eq_empty_sig_and_non_empty_sig() {
-            assert!(Signature::empty() != SignatureTester::default().sig)
+        fn keypair_from_components() {
+            let sk = secret_from_u64(42);
+            let pk = sk.public_key();
+
+            let keypair = Keypair::from_components(pk.clone(), sk.clone()).unwrap();
+            assert_eq!(keypair.pk, pk);
+            assert_eq!(Keypair::from_secret_key(sk.clone()).pk, pk);
+            assert_eq!(
+                Keypair::from_components(secret_from_u64(43).public_key(), sk).err(),
+                Some(Error::InconsistentKeypair)
+            );
         }
 
         #[test]
-        fn partial_eq_empty_agg_sig() {
-            assert_eq!(AggregateSignature::empty(), AggregateSignature::empty())
+        fn public_key_fingerprint() {
+            let pubkey = secret_from_u64(42).public_key();
+            let fingerprint = pubkey.fingerprint();
+
+            assert_eq!(pubkey.clone().fingerprint(), fingerprint);
+            assert_eq!(
+                pubkey.fingerprint_hex(),
+                format!("0x{}", hex::encode(fingerprint))
+            );
+            assert_ne!(secret_from_u64(43).public_key().fingerprint(), fingerprint);
         }
 
         #[test]
-        fn partial_eq_empty_agg_sig_and_real_agg_sig() {
-            assert!(
-                AggregateSignature::empty() != AggregateSignatureTester::new_with_single_msg(1).sig
-            )
+        fn public_key_hash_and_ord() {
+            use std::collections::hash_map::DefaultHasher;
+            use std::collections::{BTreeSet, HashSet};
+            use std::hash::{Hash, Hasher};
+
+            let hash = |pk: &PublicKey| {
+                let mut hasher = DefaultHasher::new();
+                pk.hash(&mut hasher);
+                hasher.finish()
+            };
+
+            let a = secret_from_u64(1).public_key();
+            let a_copy = PublicKey::deserialize(&a.serialize()).unwrap();
+            let b = secret_from_u64(2).public_key();
+
+            assert_eq!(hash(&a), hash(&a_copy));
+            assert_eq!(a.cmp(&a_copy), std::cmp::Ordering::Equal);
+            assert_eq!(a.cmp(&b), a.serialize()[..].cmp(&b.serialize()[..]));
+            assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+
+            let set: HashSet<_> = vec![a.clone(), a_copy.clone(), b.clone()]
+                .into_iter()
+                .collect();
+            assert_eq!(set.len(), 2);
+
+            let sorted: Vec<_> = vec![b.clone(), a_copy, b.clone(), a.clone()]
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let mut expected = vec![a, b];
+            expected.sort_by(|x, y| x.serialize()[..].cmp(&y.serialize()[..]));
+            assert_eq!(sorted, expected);
         }
 
         #[test]
-        fn partial_eq_infinity_agg_sig() {
+        fn serialize_into_and_deserialize_from() {
+            let sk = secret_from_u64(42);
+            let pubkey = sk.public_key();
+            let signature = sk.sign(Hash256::from_low_u64_be(42));
+            let aggregate = AggregateSignature::aggregate(&[signature.clone()]);
+
+            // Write all three into a single buffer, with some leading bytes.
+            let mut buf = vec![0xff; 2 + 48 + 96 + 96];
+            pubkey.serialize_into(&mut buf[2..]).unwrap();
+            signature.serialize_into(&mut buf[2 + 48..]).unwrap();
+            aggregate.serialize_into(&mut buf[2 + 48 + 96..]).unwrap();
+            assert_eq!(&buf[..2], &[0xff, 0xff]);
+            assert_eq!(&buf[2..2 + 48], &pubkey.serialize()[..]);
+            assert_eq!(&buf[2 + 48 + 96..], &aggregate.serialize()[..]);
+
+            // The empty and infinity values are written the same as by `serialize`.
+            for aggregate in &[AggregateSignature::empty(), AggregateSignature::infinity()] {
+                let mut out = [0xff; 96];
+                aggregate.serialize_into(&mut out).unwrap();
+                assert_eq!(&out[..], &aggregate.serialize()[..]);
+            }
+            let mut out = [0xff; 96];
+            Signature::empty().serialize_into(&mut out).unwrap();
+            assert_eq!(&out[..], &Signature::empty().serialize()[..]);
+
+            let mut reader = &buf[2..];
+            assert_eq!(PublicKey::deserialize_from(&mut reader), Ok(pubkey.clone()));
             assert_eq!(
-                AggregateSignature::infinity(),
-                AggregateSignature::infinity()
-            )
+                Signature::deserialize_from(&mut reader),
+                Ok(signature.clone())
+            );
+            assert_eq!(
+                AggregateSignature::deserialize_from(&mut reader),
+                Ok(aggregate)
+            );
+            assert!(reader.is_empty());
+            assert!(matches!(
+                Signature::deserialize_from(&mut reader),
+                Err(Error::IoError(_))
+            ));
+
+            assert_eq!(
+                signature.serialize_into(&mut [0; 95]),
+                Err(Error::InvalidByteLength {
+                    got: 95,
+                    expected: 96
+                })
+            );
+            assert_eq!(
+                pubkey.serialize_into(&mut []),
+                Err(Error::InvalidByteLength {
+                    got: 0,
+                    expected: 48
+                })
+            );
         }
 
         #[test]
-        fn partial_eq_infinity_agg_sig_and_real_agg_sig() {
-            assert!(
-                AggregateSignature::infinity()
-                    != AggregateSignatureTester::new_with_single_msg(1).sig
-            )
+        fn wrong_length_signature_bytes() {
+            let bytes = secret_from_u64(42)
+                .sign(Hash256::from_low_u64_be(42))
+                .serialize();
+            let mut long = bytes.to_vec();
+            long.push(0);
+
+            for bytes in &[&bytes[..95], &long[..]] {
+                let ssz_error = Some(ssz::DecodeError::InvalidByteLength {
+                    len: bytes.len(),
+                    expected: 96,
+                });
+                let error = Some(Error::InvalidByteLength {
+                    got: bytes.len(),
+                    expected: 96,
+                });
+
+                assert_eq!(AggregateSignature::from_ssz_bytes(bytes).err(), ssz_error);
+                assert_eq!(Signature::from_ssz_bytes(bytes).err(), ssz_error);
+                assert_eq!(AggregateSignature::deserialize(bytes).err(), error);
+                assert_eq!(Signature::deserialize(bytes).err(), error);
+            }
         }
 
         #[test]
-        fn partial_eq_infinity_agg_sig_and_empty_agg_sig() {
-            assert!(AggregateSignature::infinity() != AggregateSignature::empty())
-        }
+        fn public_key_serialize_many() {
+            let pubkeys = (0..bls::SYNC_COMMITTEE_SIZE as u64)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+
+            let bytes = PublicKey::serialize_many(&pubkeys);
+            assert_eq!(bytes.len(), 512 * 48);
+            assert_eq!(&bytes[48..96], &pubkeys[1].serialize()[..]);
+            assert_eq!(PublicKey::deserialize_many_checked(&bytes), Ok(pubkeys));
+
+            assert!(PublicKey::serialize_many(&[]).is_empty());
+            assert_eq!(PublicKey::deserialize_many_checked(&[]), Ok(vec![]));
+            assert_eq!(
+                PublicKey::deserialize_many_checked(&bytes[..100]),
+                Err(Error::InvalidByteLength {
+                    got: 100,
+                    expected: 48
+                })
+            );
 
-        /// A helper struct for composing tests via the builder pattern.
-        struct SignatureTester {
-            sig: Signature,
-            pubkey: PublicKey,
-            msg: Hash256,
+            let mut with_invalid = bytes[..96].to_vec();
+            with_invalid.extend_from_slice(&hex::decode(PUBKEY_NOT_IN_G1).unwrap());
+            assert!(PublicKey::deserialize_many_checked(&with_invalid).is_err());
         }
 
-        impl Default for SignatureTester {
-            fn default() -> Self {
-                let secret = SecretKey::deserialize(&[42; 32]).unwrap();
-                let pubkey = secret.public_key();
-                let msg = Hash256::from_low_u64_be(42);
+        #[test]
+        fn wrong_length_public_key_bytes() {
+            let bytes = secret_from_u64(42).public_key().serialize();
+            let mut long = bytes.to_vec();
+            long.push(0);
+
+            for bytes in &[&bytes[..47], &long[..]] {
+                let error = Some(Error::InvalidByteLength {
+                    got: bytes.len(),
+                    expected: 48,
+                });
 
-                Self {
-                    sig: secret.sign(msg),
-                    pubkey,
-                    msg,
-                }
+                assert_eq!(PublicKey::deserialize(bytes).err(), error);
+                assert_eq!(PublicKey::deserialize_checked(bytes).err(), error);
+                assert_eq!(AggregatePublicKey::deserialize(bytes).err(), error);
             }
+
+            assert_eq!(
+                Error::InvalidByteLength {
+                    got: 47,
+                    expected: 48
+                }
+                .to_string(),
+                "invalid byte length: got 47, expected 48"
+            );
         }
 
-        impl SignatureTester {
-            pub fn infinity_sig(mut self) -> Self {
-                self.sig = Signature::deserialize(&INFINITY_SIGNATURE[..]).unwrap();
-                self
-            }
+        #[test]
+        fn try_from_bytes() {
+            use std::convert::{TryFrom, TryInto};
 
-            pub fn infinity_pubkey(mut self) -> Self {
-                self.pubkey = PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap();
-                self
-            }
+            let sk = secret_from_u64(42);
+            let pubkey = sk.public_key();
+            let signature = sk.sign(Hash256::from_low_u64_be(42));
+            let aggregate = AggregateSignature::aggregate(&[signature.clone()]);
+            let agg_pubkey = AggregatePublicKey::aggregate(&[&pubkey]).unwrap();
 
-            pub fn assert_verify(self, is_valid: bool) {
-                assert_eq!(self.sig.verify(&self.pubkey, self.msg), is_valid);
+            let pubkey_bytes = pubkey.serialize();
+            let signature_bytes = signature.serialize();
 
-                // Check a single-signature signature set.
-                assert_eq!(
-                    SignatureSet::single_pubkey(&self.sig, Cow::Borrowed(&self.pubkey), self.msg,)
-                        .verify(),
-                    is_valid
-                )
-            }
+            assert_eq!(PublicKey::try_from(&pubkey_bytes[..]), Ok(pubkey.clone()));
+            assert_eq!(PublicKey::try_from(pubkey_bytes.to_vec()), Ok(pubkey));
+            assert_eq!(
+                Signature::try_from(&signature_bytes[..]),
+                Ok(signature.clone())
+            );
+            assert_eq!(Signature::try_from(signature_bytes.to_vec()), Ok(signature));
+            assert_eq!(
+                AggregateSignature::try_from(&signature_bytes[..]),
+                Ok(aggregate.clone())
+            );
+            assert_eq!(
+                AggregateSignature::try_from(signature_bytes.to_vec()),
+                Ok(aggregate.clone())
+            );
+            assert_eq!(
+                AggregatePublicKey::try_from(&pubkey_bytes[..])
+                    .unwrap()
+                    .serialize()[..],
+                agg_pubkey.serialize()[..]
+            );
+            assert_eq!(
+                AggregatePublicKey::try_from(pubkey_bytes.to_vec())
+                    .unwrap()
+                    .serialize()[..],
+                agg_pubkey.serialize()[..]
+            );
+
+            let decoded: Result<AggregateSignature, Error> = signature_bytes[..].try_into();
+            assert_eq!(decoded, Ok(aggregate));
+
+            let length_error = |got, expected| Some(Error::InvalidByteLength { got, expected });
+            assert_eq!(
+                PublicKey::try_from(&pubkey_bytes[1..]).err(),
+                length_error(47, 48)
+            );
+            assert_eq!(Signature::try_from(vec![0; 97]).err(), length_error(97, 96));
+            assert_eq!(
+                AggregateSignature::try_from(&[0u8; 0][..]).err(),
+                length_error(0, 96)
+            );
+            assert_eq!(
+                AggregatePublicKey::try_from(&pubkey_bytes[1..]).err(),
+                length_error(47, 48)
+            );
         }
 
         #[test]
-        fn standard_signature_is_valid_with_standard_pubkey() {
-            SignatureTester::default().assert_verify(true)
+        fn zeroize_secret_key() {
+            let mut secret = secret_from_u64(42);
+            assert!(secret.serialize().as_bytes() != &[0; 32][..]);
+
+            secret.zeroize();
+            assert_eq!(secret.serialize().as_bytes(), &[0; 32][..]);
         }
 
         #[test]
-        fn infinity_signature_is_valid_with_infinity_pubkey() {
-            SignatureTester::default()
-                .infinity_sig()
-                .infinity_pubkey()
-                .assert_verify(true)
+        fn secret_key_is_zeroized_on_drop() {
+            let secret = secret_from_u64(42);
+            assert!(secret.serialize().as_bytes() != &[0; 32][..]);
+            assert!(bytes_after_drop(secret).iter().all(|byte| *byte == 0));
+
+            let serialized = secret_from_u64(42).serialize();
+            assert!(serialized.as_bytes() != &[0; 32][..]);
+            assert!(bytes_after_drop(serialized).iter().all(|byte| *byte == 0));
         }
 
         #[test]
-        fn infinity_signature_is_invalid_with_standard_pubkey() {
-            SignatureTester::default()
-                .infinity_sig()
-                .assert_verify(false)
+        fn deserialize_out_of_range_secret_key() {
+            let curve_order =
+                hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")
+                    .unwrap();
+
+            for bytes in &[curve_order, vec![0; 32], vec![255; 32]] {
+                assert!(
+                    matches!(
+                        SecretKey::deserialize(bytes),
+                        Err(Error::InvalidSecretKeyBytes)
+                    ),
+                    "should reject {:?}",
+                    bytes
+                );
+
+                let mut array = [0; 32];
+                array.copy_from_slice(bytes);
+                assert!(bool::from(SecretKey::deserialize_ct(&array).is_none()));
+            }
+
+            let mut largest = [0; 32];
+            largest.copy_from_slice(
+                &hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000")
+                    .unwrap(),
+            );
+            assert!(SecretKey::deserialize(&largest).is_ok());
+            assert!(bool::from(SecretKey::deserialize_ct(&largest).is_some()));
         }
 
         #[test]
-        fn standard_signature_is_invalid_with_infinity_pubkey() {
-            SignatureTester::default()
-                .infinity_pubkey()
-                .assert_verify(false)
+        fn secret_key_constant_time_eq() {
+            assert!(secret_from_u64(1) == secret_from_u64(1));
+            assert!(secret_from_u64(1) != secret_from_u64(2));
         }
 
-        /// A helper struct for composing tests via the builder pattern.
-        struct AggregateSignatureTester {
-            sig: AggregateSignature,
-            pubkeys: Vec<PublicKey>,
-            msgs: Vec<Hash256>,
+        #[test]
+        fn infinity_agg_sig() {
+            assert_eq!(
+                &AggregateSignature::infinity().serialize()[..],
+                &INFINITY_SIGNATURE[..]
+            );
+            assert_eq!(
+                AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
+                AggregateSignature::infinity(),
+            );
         }
 
-        impl AggregateSignatureTester {
-            fn new_with_single_msg(num_pubkeys: u64) -> Self {
-                let mut pubkeys = Vec::with_capacity(num_pubkeys as usize);
-                let mut sig = AggregateSignature::infinity();
-                let msg = Hash256::from_low_u64_be(42);
+        #[test]
+        fn aggregate_into_matches_add_assign() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (1..4)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect::<Vec<_>>();
+
+            let mut expected = AggregateSignature::empty();
+            let mut aggregate = AggregateSignature::empty();
+            for signature in &signatures {
+                expected.add_assign(signature);
+                signature.clone().aggregate_into(&mut aggregate);
+                assert_eq!(aggregate, expected);
+            }
 
-                for i in 0..num_pubkeys {
-                    let secret = secret_from_u64(i);
-                    pubkeys.push(secret.public_key());
-                    sig.add_assign(&secret.sign(msg));
-                }
+            Signature::empty().aggregate_into(&mut aggregate);
+            assert_eq!(aggregate, expected);
+        }
 
-                Self {
-                    sig,
-                    pubkeys,
-                    msgs: vec![msg],
-                }
-            }
+        #[test]
+        fn into_signature() {
+            let msg = Hash256::from_low_u64_be(42);
+            let sk = secret_from_u64(0);
+            let signature = sk.sign(msg);
 
-            pub fn empty_sig(mut self) -> Self {
-                self.sig = AggregateSignature::empty();
-                self
-            }
+            let mut aggregate = AggregateSignature::empty();
+            aggregate.add_assign(&signature);
+            let converted = aggregate.into_signature().unwrap();
+            assert_eq!(converted, signature);
+            assert!(converted.verify(&sk.public_key(), msg));
 
-            pub fn wrong_sig(mut self) -> Self {
-                let sk = SecretKey::deserialize(&[1; 32]).unwrap();
-                self.sig = AggregateSignature::infinity();
-                self.sig.add_assign(&sk.sign(Hash256::from_low_u64_be(1)));
-                self
-            }
+            let infinity = AggregateSignature::infinity().into_signature().unwrap();
+            assert_eq!(
+                infinity,
+                Signature::deserialize(&INFINITY_SIGNATURE).unwrap()
+            );
 
-            pub fn infinity_sig(mut self) -> Self {
-                self.sig = AggregateSignature::deserialize(&INFINITY_SIGNATURE[..]).unwrap();
-                self
-            }
+            assert!(AggregateSignature::empty().into_signature().is_none());
+        }
 
-            pub fn aggregate_empty_sig(mut self) -> Self {
-                self.sig.add_assign(&Signature::empty());
-                self
-            }
+        #[test]
+        fn sum_matches_fold() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (0..4)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect::<Vec<_>>();
 
-            pub fn aggregate_empty_agg_sig(mut self) -> Self {
-                self.sig.add_assign_aggregate(&AggregateSignature::empty());
-                self
+            let mut expected = AggregateSignature::empty();
+            for signature in &signatures {
+                expected.add_assign(signature);
             }
 
-            pub fn aggregate_infinity_sig(mut self) -> Self {
-                self.sig
-                    .add_assign(&Signature::deserialize(&INFINITY_SIGNATURE[..]).unwrap());
-                self
-            }
+            assert_eq!(signatures.iter().sum::<AggregateSignature>(), expected);
+            assert_eq!(
+                signatures.clone().into_iter().sum::<AggregateSignature>(),
+                expected
+            );
 
-            pub fn single_infinity_pubkey(mut self) -> Self {
-                self.pubkeys = vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap()];
-                self
-            }
+            let aggregates = signatures
+                .chunks(2)
+                .map(AggregateSignature::aggregate)
+                .collect::<Vec<_>>();
+            assert_eq!(aggregates.iter().sum::<AggregateSignature>(), expected);
+            assert_eq!(aggregates.into_iter().sum::<AggregateSignature>(), expected);
+
+            assert!(Vec::<Signature>::new()
+                .into_iter()
+                .sum::<AggregateSignature>()
+                .is_empty());
+            assert!(Vec::<AggregateSignature>::new()
+                .iter()
+                .sum::<AggregateSignature>()
+                .is_empty());
+        }
 
-            pub fn push_infinity_pubkey(mut self) -> Self {
-                self.pubkeys
-                    .push(PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap());
-                self
-            }
+        #[test]
+        fn sub_assign_removes_contributor() {
+            let msg = Hash256::from_low_u64_be(42);
+            let secrets = (0..3).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secrets.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let signatures = secrets.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>();
+
+            let mut aggregate = AggregateSignature::aggregate(&signatures);
+            aggregate.sub_assign(&signatures[1]);
+
+            let expected =
+                AggregateSignature::aggregate(&[signatures[0].clone(), signatures[2].clone()]);
+            assert_eq!(aggregate.serialize(), expected.serialize());
+            assert!(aggregate.fast_aggregate_verify(msg, &[&pubkeys[0], &pubkeys[2]]));
+            assert!(!aggregate.fast_aggregate_verify(msg, &pubkeys.iter().collect::<Vec<_>>()));
+
+            // Removing the remaining contributors produces the signature at infinity.
+            aggregate.sub_assign(&signatures[0]);
+            aggregate.sub_assign(&signatures[2]);
+            assert!(aggregate.is_infinity());
+
+            // Removing an "empty" signature has no effect.
+            let mut aggregate = expected.clone();
+            aggregate.sub_assign(&Signature::empty());
+            assert_eq!(aggregate, expected);
+
+            // Subtracting from the "empty" value gives the negation.
+            let mut negated = AggregateSignature::empty();
+            negated.sub_assign(&signatures[0]);
+            assert!(!negated.is_infinity());
+            assert_ne!(negated.serialize(), signatures[0].serialize());
+            negated.add_assign(&signatures[0]);
+            assert_eq!(negated.serialize(), INFINITY_SIGNATURE);
+        }
 
-            pub fn assert_single_message_verify(self, is_valid: bool) {
-                assert!(self.msgs.len() == 1);
-                let msg = self.msgs.first().unwrap();
-                let pubkeys = self.pubkeys.iter().collect::<Vec<_>>();
+        #[test]
+        fn deserialize_many_and_verify() {
+            let secrets = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secrets.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let msgs = (0..2).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+            let committees = vec![
+                pubkeys[..2].iter().collect::<Vec<_>>(),
+                pubkeys[2..].iter().collect::<Vec<_>>(),
+            ];
+            let committee_refs = committees.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+            let signatures = vec![
+                AggregateSignature::aggregate(&[
+                    secrets[0].sign(msgs[0]),
+                    secrets[1].sign(msgs[0]),
+                ]),
+                AggregateSignature::aggregate(&[
+                    secrets[2].sign(msgs[1]),
+                    secrets[3].sign(msgs[1]),
+                ]),
+            ];
+            let bytes = signatures
+                .iter()
+                .flat_map(|sig| sig.serialize().to_vec())
+                .collect::<Vec<_>>();
 
-                assert_eq!(
-                    self.sig.fast_aggregate_verify(*msg, &pubkeys),
-                    is_valid,
-                    "fast_aggregate_verify expected {} but got {}",
-                    is_valid,
-                    !is_valid
-                );
+            assert_eq!(
+                AggregateSignature::deserialize_many(&bytes).unwrap(),
+                signatures
+            );
+            assert_eq!(
+                AggregateSignature::deserialize_many_and_verify(&bytes, &msgs, &committee_refs)
+                    .unwrap(),
+                signatures
+            );
+            assert!(AggregateSignature::deserialize_many(&[])
+                .unwrap()
+                .is_empty());
 
-                let msgs = pubkeys.iter().map(|_| msg.clone()).collect::<Vec<_>>();
+            assert_eq!(
+                AggregateSignature::deserialize_many(&bytes[1..]),
+                Err(Error::InvalidByteLength {
+                    got: 191,
+                    expected: 96
+                })
+            );
+            assert_eq!(
+                AggregateSignature::deserialize_many_and_verify(
+                    &bytes,
+                    &msgs[..1],
+                    &committee_refs
+                ),
+                Err(Error::InvalidMessageCount {
+                    got: 1,
+                    expected: 2
+                })
+            );
 
-                assert_eq!(
-                    self.sig.aggregate_verify(&msgs, &pubkeys),
-                    is_valid,
-                    "aggregate_verify expected {} but got {}",
-                    is_valid,
-                    !is_valid
-                );
-            }
+            let swapped = [msgs[1], msgs[0]];
+            assert_eq!(
+                AggregateSignature::deserialize_many_and_verify(&bytes, &swapped, &committee_refs),
+                Err(Error::InvalidSignature)
+            );
+
+            let mut with_empty = bytes.clone();
+            with_empty[..96].copy_from_slice(&NONE_SIGNATURE);
+            assert_eq!(
+                AggregateSignature::deserialize_many_and_verify(
+                    &with_empty,
+                    &msgs,
+                    &committee_refs
+                ),
+                Err(Error::EmptyAggregate)
+            );
         }
 
-        /// An aggregate without any signatures should not verify.
         #[test]
-        fn fast_aggregate_verify_0_pubkeys() {
-            AggregateSignatureTester::new_with_single_msg(0).assert_single_message_verify(false)
+        fn aggregate_verify_grouped() {
+            let secrets = (0..6).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secrets.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let msgs = (0..6)
+                .map(|i| Hash256::from_low_u64_be(i / 3))
+                .collect::<Vec<_>>();
+            let aggregate = AggregateSignature::aggregate(
+                &secrets
+                    .iter()
+                    .zip(msgs.iter())
+                    .map(|(sk, msg)| sk.sign(*msg))
+                    .collect::<Vec<_>>(),
+            );
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+
+            // A degenerate grouping with one public key per message matches `aggregate_verify`.
+            let degenerate = msgs
+                .iter()
+                .zip(pubkey_refs.iter())
+                .map(|(msg, pk)| (*msg, vec![*pk]))
+                .collect::<Vec<_>>();
+            assert!(aggregate.aggregate_verify(&msgs, &pubkey_refs));
+            assert!(aggregate.aggregate_verify_grouped(&degenerate));
+
+            let grouped = vec![
+                (msgs[0], pubkey_refs[..3].to_vec()),
+                (msgs[3], pubkey_refs[3..].to_vec()),
+            ];
+            assert!(aggregate.aggregate_verify_grouped(&grouped));
+
+            let swapped = vec![
+                (msgs[3], pubkey_refs[..3].to_vec()),
+                (msgs[0], pubkey_refs[3..].to_vec()),
+            ];
+            assert!(!aggregate.aggregate_verify_grouped(&swapped));
+            assert!(!aggregate.aggregate_verify_grouped(&grouped[..1]));
+            assert!(!aggregate.aggregate_verify_grouped(&[]));
+            assert!(!aggregate.aggregate_verify_grouped(&[(msgs[0], vec![])]));
         }
 
-        /// An aggregate of size 1 should verify.
         #[test]
-        fn fast_aggregate_verify_1_pubkey() {
-            AggregateSignatureTester::new_with_single_msg(1).assert_single_message_verify(true)
+        fn cached_tree_hash_signature() {
+            let signature = secret_from_u64(0).sign(Hash256::from_low_u64_be(42));
+            let other = secret_from_u64(1).sign(Hash256::from_low_u64_be(42));
+
+            assert_eq!(
+                CachedTreeHashSignature::tree_hash_type(),
+                tree_hash::TreeHashType::Vector
+            );
+
+            let mut cached = CachedTreeHashSignature::new(signature.clone());
+            assert_eq!(cached.tree_hash_root_cached(), signature.tree_hash_root());
+            assert_eq!(cached.tree_hash_root_cached(), signature.tree_hash_root());
+            assert_eq!(cached.tree_hash_root(), signature.tree_hash_root());
+
+            *cached.signature_mut() = other.clone();
+            assert_eq!(cached.tree_hash_root_cached(), other.tree_hash_root());
+
+            cached.set_signature(Signature::empty());
+            assert_eq!(
+                cached.tree_hash_root_cached(),
+                Signature::empty().tree_hash_root()
+            );
+
+            // The cache may be shared between threads.
+            let cached = std::sync::Arc::new(CachedTreeHashSignature::new(signature.clone()));
+            let handles = (0..4)
+                .map(|_| {
+                    let cached = cached.clone();
+                    std::thread::spawn(move || cached.tree_hash_root_cached())
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                assert_eq!(handle.join().unwrap(), signature.tree_hash_root());
+            }
         }
 
-        /// An aggregate of size 128 should verify.
         #[test]
-        fn fast_aggregate_verify_128_pubkeys() {
-            AggregateSignatureTester::new_with_single_msg(128).assert_single_message_verify(true)
+        fn extend_matches_fold() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (0..4)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect::<Vec<_>>();
+            let expected = AggregateSignature::aggregate(&signatures);
+
+            let mut aggregate = AggregateSignature::empty();
+            aggregate.extend(&signatures[..2]);
+            aggregate.extend(signatures[2..].to_vec());
+            assert_eq!(aggregate, expected);
+
+            let aggregates = signatures
+                .chunks(2)
+                .map(AggregateSignature::aggregate)
+                .collect::<Vec<_>>();
+            let mut aggregate = AggregateSignature::empty();
+            aggregate.extend(&aggregates[..1]);
+            aggregate.extend(aggregates[1..].to_vec());
+            assert_eq!(aggregate, expected);
+
+            // Extending with nothing leaves the aggregate unchanged.
+            for initial in &[AggregateSignature::empty(), expected.clone()] {
+                let mut aggregate = initial.clone();
+                aggregate.extend(Vec::<Signature>::new());
+                aggregate.extend(Vec::<AggregateSignature>::new().iter());
+                assert_eq!(&aggregate, initial);
+            }
         }
 
-        /// The infinity signature should not verify against 1 non-infinity pubkey.
         #[test]
-        fn fast_aggregate_verify_infinity_signature_with_1_regular_public_key() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .infinity_sig()
-                .assert_single_message_verify(false)
+        fn aggregate_empty_and_infinity() {
+            let empty_sig = Signature::empty();
+            let infinity_sig = Signature::deserialize(&INFINITY_SIGNATURE).unwrap();
+            let aggregate = |infinity: bool| {
+                if infinity {
+                    AggregateSignature::infinity()
+                } else {
+                    AggregateSignature::empty()
+                }
+            };
+            let signature = |infinity: bool| {
+                if infinity {
+                    &infinity_sig
+                } else {
+                    &empty_sig
+                }
+            };
+
+            for &self_infinity in &[false, true] {
+                for &other_infinity in &[false, true] {
+                    let expect_infinity = self_infinity || other_infinity;
+                    let expected_bytes = if expect_infinity {
+                        INFINITY_SIGNATURE
+                    } else {
+                        [0; 96]
+                    };
+
+                    let mut results = vec![aggregate(self_infinity), aggregate(self_infinity)];
+                    let mut multiple = aggregate(self_infinity);
+                    results[0].add_assign(signature(other_infinity));
+                    results[1].add_assign_aggregate(&aggregate(other_infinity));
+                    multiple.add_assign_multiple(&[signature(other_infinity)]);
+                    results.push(multiple);
+                    let mut owned = aggregate(self_infinity);
+                    signature(other_infinity).clone().aggregate_into(&mut owned);
+                    results.push(owned);
+
+                    for result in results {
+                        assert_eq!(result.is_empty(), !expect_infinity);
+                        assert_eq!(result.is_infinity(), expect_infinity);
+                        assert_eq!(&result.serialize()[..], &expected_bytes[..]);
+                    }
+                }
+            }
         }
 
-        /// The infinity signature should not verify against 128 non-infinity pubkeys.
+        /// The `deserialization_G2` cases from ethereum/bls12-381-tests, which are not part of
+        /// the spec tests used by `ef_tests`.
+        ///
+        /// Only the checked paths enforce the canonical encoding; `deserialize` (and therefore
+        /// SSZ decoding and `SignatureBytes::decompress`) is left to the backend, as before.
         #[test]
-        fn fast_aggregate_verify_infinity_signature_with_128_regular_public_keys() {
-            AggregateSignatureTester::new_with_single_msg(128)
-                .infinity_sig()
-                .assert_single_message_verify(false)
+        fn deserialize_checked_rejects_non_canonical_encodings() {
+            let non_canonical = [
+                // deserialization_fails_xim_equal_to_modulus
+                "9a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                // deserialization_fails_xim_greater_than_modulus
+                "9a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaac000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                // deserialization_fails_xre_equal_to_modulus
+                "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+                // deserialization_fails_xre_greater_than_modulus
+                "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaac",
+                // deserialization_fails_with_b_flag_and_a_flag_true
+                "e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                // deserialization_fails_with_b_flag_and_x_nonzero
+                "c123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+                // deserialization_fails_with_wrong_c_flag
+                "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+            ];
+
+            for hex in &non_canonical {
+                let bytes = hex::decode(hex).unwrap();
+                assert_eq!(
+                    Signature::deserialize_checked(&bytes),
+                    Err(Error::NonCanonicalEncoding)
+                );
+                assert_eq!(
+                    AggregateSignature::deserialize_checked(&bytes),
+                    Err(Error::NonCanonicalEncoding)
+                );
+
+                assert_ne!(
+                    Signature::deserialize(&bytes),
+                    Err(Error::NonCanonicalEncoding)
+                );
+                assert_ne!(
+                    Signature::from_ssz_bytes(&bytes),
+                    Err(ssz::DecodeError::BytesInvalid(format!(
+                        "{:?}",
+                        Error::NonCanonicalEncoding
+                    )))
+                );
+                assert_ne!(
+                    AggregateSignature::deserialize(&bytes),
+                    Err(Error::NonCanonicalEncoding)
+                );
+                assert_ne!(
+                    SignatureBytes::deserialize(&bytes).unwrap().decompress(),
+                    Err(Error::NonCanonicalEncoding)
+                );
+            }
+
+            // deserialization_succeeds_infinity_with_true_b_flag
+            assert!(Signature::deserialize_checked(&INFINITY_SIGNATURE).is_ok());
+
+            // deserialization_fails_infinity_with_false_b_flag: the encoding is canonical, but
+            // it is rejected by the backend.
+            let mut infinity_with_false_b_flag = [0; 96];
+            infinity_with_false_b_flag[0] = 0x80;
+            assert!(Signature::deserialize_checked(&infinity_with_false_b_flag).is_err());
+
+            // deserialization_fails_too_few_bytes and deserialization_fails_too_many_bytes
+            let valid = secret_from_u64(42)
+                .sign(Hash256::from_low_u64_be(42))
+                .serialize();
+            assert_eq!(
+                Signature::deserialize(&valid[1..]),
+                Err(Error::InvalidByteLength {
+                    got: 95,
+                    expected: 96
+                })
+            );
+            assert_eq!(
+                Signature::deserialize(&[&valid[..], &[0xff]].concat()),
+                Err(Error::InvalidByteLength {
+                    got: 97,
+                    expected: 96
+                })
+            );
+
+            // deserialization_succeeds_correct_point
+            assert_eq!(
+                Signature::deserialize_checked(&valid).unwrap().serialize()[..],
+                valid[..]
+            );
+            assert_eq!(
+                SignatureBytes::deserialize(&valid)
+                    .unwrap()
+                    .decompress()
+                    .unwrap()
+                    .serialize()[..],
+                valid[..]
+            );
         }
 
-        /// The infinity signature and one infinity pubkey should verify.
+        /// The all-zeros `NONE_SIGNATURE` is not a canonical encoding, but it is deliberately
+        /// accepted as the "empty" signature, which never verifies.
         #[test]
-        fn fast_aggregate_verify_infinity_signature_with_one_infinity_pubkey() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .infinity_sig()
-                .single_infinity_pubkey()
-                .assert_single_message_verify(true)
+        fn none_signature_deserializes_as_empty() {
+            let sk = secret_from_u64(42);
+            let msg = Hash256::from_low_u64_be(42);
+
+            for signature in &[
+                Signature::deserialize(&NONE_SIGNATURE).unwrap(),
+                Signature::deserialize_checked(&NONE_SIGNATURE).unwrap(),
+                SignatureBytes::empty().decompress().unwrap(),
+            ] {
+                assert!(signature.is_empty());
+                assert!(!signature.verify(&sk.public_key(), msg));
+            }
+
+            let aggregate = AggregateSignature::deserialize_checked(&NONE_SIGNATURE).unwrap();
+            assert!(aggregate.is_empty());
+            assert!(!aggregate.fast_aggregate_verify(msg, &[&sk.public_key()]));
         }
 
-        /// Adding a infinity signature (without an infinity pubkey) should verify.
         #[test]
-        fn fast_aggregate_verify_with_one_aggregated_infinity_sig() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .aggregate_infinity_sig()
-                .assert_single_message_verify(true)
+        fn infinity_agg_sig_is_not_empty() {
+            let infinity = AggregateSignature::infinity();
+            assert!(infinity.is_infinity());
+            assert!(!infinity.is_empty());
+
+            let empty = AggregateSignature::empty();
+            assert!(!empty.is_infinity());
+            assert!(empty.is_empty());
+
+            let deserialized = AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap();
+            assert!(deserialized.is_infinity());
+            assert!(!deserialized.is_empty());
+
+            let deserialized = AggregateSignature::deserialize(&[0; 96]).unwrap();
+            assert!(!deserialized.is_infinity());
+            assert!(deserialized.is_empty());
+
+            let sig = secret_from_u64(1).sign(Hash256::from_low_u64_be(1));
+            assert!(!AggregateSignature::aggregate(&[sig]).is_infinity());
         }
 
-        /// Adding four infinity signatures (without any infinity pubkeys) should verify.
         #[test]
-        fn fast_aggregate_verify_with_four_aggregated_infinity_sig() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .aggregate_infinity_sig()
-                .aggregate_infinity_sig()
-                .aggregate_infinity_sig()
-                .aggregate_infinity_sig()
-                .assert_single_message_verify(true)
+        fn ssz_round_trip_multiple_types() {
+            let mut agg_sig = AggregateSignature::infinity();
+            ssz_round_trip(agg_sig.clone());
+
+            let msg = Hash256::from_low_u64_be(42);
+            let secret = secret_from_u64(42);
+
+            let sig = secret.sign(msg);
+            ssz_round_trip(sig.clone());
+
+            agg_sig.add_assign(&sig);
+            ssz_round_trip(agg_sig);
         }
 
-        /// Adding a infinity pubkey and an infinity signature should verify.
         #[test]
-        fn fast_aggregate_verify_with_one_additional_infinity_pubkey_and_matching_sig() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .aggregate_infinity_sig()
-                .push_infinity_pubkey()
-                .assert_single_message_verify(true)
+        fn ssz_list_round_trip() {
+            #[derive(Debug, PartialEq, ssz_derive::Encode, ssz_derive::Decode)]
+            struct Container {
+                slot: u64,
+                signatures: Vec<AggregateSignature>,
+                count: u64,
+            }
+
+            assert!(<AggregateSignature as Encode>::is_ssz_fixed_len());
+            assert_eq!(<AggregateSignature as Encode>::ssz_fixed_len(), 96);
+            assert!(<AggregateSignature as Decode>::is_ssz_fixed_len());
+            assert_eq!(<AggregateSignature as Decode>::ssz_fixed_len(), 96);
+
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (0..3)
+                .map(|i| AggregateSignature::aggregate(&[secret_from_u64(i).sign(msg)]))
+                .collect::<Vec<_>>();
+
+            ssz_round_trip(signatures.clone());
+
+            let container = Container {
+                slot: 1,
+                signatures: signatures.clone(),
+                count: 3,
+            };
+            let bytes = container.as_ssz_bytes();
+
+            // Two `u64` fields and one offset, followed by each signature inline.
+            assert_eq!(bytes.len(), 8 + 4 + 8 + 96 * signatures.len());
+            for (i, signature) in signatures.iter().enumerate() {
+                let start = 20 + 96 * i;
+                assert_eq!(&bytes[start..start + 96], &signature.serialize()[..]);
+            }
+            ssz_round_trip(container);
         }
 
-        /// Adding a single infinity pubkey **without** updating the signature **should verify**.
         #[test]
-        fn fast_aggregate_verify_with_one_additional_infinity_pubkey() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .push_infinity_pubkey()
-                .assert_single_message_verify(true)
+        fn ssz_round_trip_sig_empty() {
+            ssz_round_trip(Signature::empty())
         }
 
-        /// Adding multiple infinity pubkeys **without** updating the signature **should verify**.
         #[test]
-        fn fast_aggregate_verify_with_four_additional_infinity_pubkeys() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .push_infinity_pubkey()
-                .push_infinity_pubkey()
-                .push_infinity_pubkey()
-                .push_infinity_pubkey()
-                .assert_single_message_verify(true)
+        fn sig_empty_round_trip() {
+            let empty = Signature::empty();
+            assert!(empty.is_empty());
+            assert_eq!(empty.serialize(), NONE_SIGNATURE);
+
+            let decoded = Signature::deserialize(&NONE_SIGNATURE).unwrap();
+            assert!(decoded.is_empty());
+            assert_eq!(decoded, empty);
+
+            assert!(!Signature::deserialize(&INFINITY_SIGNATURE)
+                .unwrap()
+                .is_empty());
+            assert!(!SignatureTester::default().sig.is_empty());
         }
 
-        /// The wrong signature should not verify.
         #[test]
-        fn fast_aggregate_verify_wrong_signature() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .wrong_sig()
-                .assert_single_message_verify(false)
+        fn ssz_round_trip_agg_sig_empty() {
+            ssz_round_trip(AggregateSignature::empty())
         }
 
-        /// An "empty" signature should not verify.
         #[test]
-        fn fast_aggregate_verify_empty_signature() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .empty_sig()
-                .assert_single_message_verify(false)
+        fn ssz_round_trip_agg_sig_infinity() {
+            ssz_round_trip(AggregateSignature::infinity())
         }
 
-        /// Aggregating an "empty" signature should have no effect.
         #[test]
-        fn fast_aggregate_verify_with_aggregated_empty_sig() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .aggregate_empty_sig()
-                .assert_single_message_verify(true)
+        fn partial_eq_empty_sig() {
+            assert_eq!(Signature::empty(), Signature::empty())
         }
 
-        /// Aggregating an "empty" aggregate signature should have no effect.
         #[test]
-        fn fast_aggregate_verify_with_aggregated_empty_agg_sig() {
-            AggregateSignatureTester::new_with_single_msg(1)
-                .aggregate_empty_agg_sig()
-                .assert_single_message_verify(true)
+        fn partial_eq_empty_sig_and_non_empty_sig() {
+            assert!(Signature::empty() != SignatureTester::default().sig)
         }
 
-        /// A helper struct to make it easer to deal with `SignatureSet` lifetimes.
-        struct OwnedSignatureSet {
-            signature: AggregateSignature,
-            signing_keys: Vec<PublicKey>,
-            message: Hash256,
-            should_be_valid: bool,
+        #[test]
+        fn semantically_eq_agg_sig() {
+            let empty = AggregateSignature::empty();
+            let infinity = AggregateSignature::infinity();
+            let sig = AggregateSignatureTester::new_with_single_msg(1).sig;
+
+            assert!(empty != infinity);
+            assert!(empty.semantically_eq(&infinity));
+            assert!(infinity.semantically_eq(&empty));
+            assert!(empty.semantically_eq(&empty));
+            assert!(sig.semantically_eq(&sig.clone()));
+            assert!(!sig.semantically_eq(&empty));
+            assert!(!infinity.semantically_eq(&sig));
         }
 
-        impl OwnedSignatureSet {
-            pub fn multiple_pubkeys(&self) -> SignatureSet {
-                let signing_keys = self.signing_keys.iter().map(Cow::Borrowed).collect();
-                SignatureSet::multiple_pubkeys(&self.signature, signing_keys, self.message)
-            }
-
-            pub fn run_checks(&self) {
-                assert_eq!(
-                    self.multiple_pubkeys().verify(),
-                    self.should_be_valid,
-                    "multiple pubkey expected {} but got {}",
-                    self.should_be_valid,
-                    !self.should_be_valid
-                )
-            }
+        #[test]
+        fn partial_eq_empty_agg_sig() {
+            assert_eq!(AggregateSignature::empty(), AggregateSignature::empty())
         }
 
-        /// A helper struct for composing tests via the builder pattern.
-        #[derive(Default)]
-        struct SignatureSetTester {
-            owned_sets: Vec<OwnedSignatureSet>,
+        #[test]
+        fn partial_eq_empty_agg_sig_and_real_agg_sig() {
+            assert!(
+                AggregateSignature::empty() != AggregateSignatureTester::new_with_single_msg(1).sig
+            )
         }
 
-        impl SignatureSetTester {
-            pub fn push_valid_set(mut self, num_signers: usize) -> Self {
-                let mut signature = AggregateSignature::infinity();
-                let message = Hash256::from_low_u64_be(42);
+        #[test]
+        fn partial_eq_infinity_agg_sig() {
+            assert_eq!(
+                AggregateSignature::infinity(),
+                AggregateSignature::infinity()
+            )
+        }
 
-                let signing_keys = (0..num_signers)
-                    .map(|i| {
-                        let secret = secret_from_u64(i as u64);
-                        signature.add_assign(&secret.sign(message));
+        #[test]
+        fn partial_eq_infinity_agg_sig_and_real_agg_sig() {
+            assert!(
+                AggregateSignature::infinity()
+                    != AggregateSignatureTester::new_with_single_msg(1).sig
+            )
+        }
 
-                        secret.public_key()
-                    })
-                    .collect();
+        #[test]
+        fn partial_eq_infinity_agg_sig_and_empty_agg_sig() {
+            assert!(AggregateSignature::infinity() != AggregateSignature::empty())
+        }
 
-                self.owned_sets.push(OwnedSignatureSet {
-                    signature,
-                    signing_keys,
-                    message,
-                    should_be_valid: true,
-                });
+        #[test]
+        fn aggregate_empty_iterator() {
+            let signatures: Vec<Signature> = vec![];
+            assert!(AggregateSignature::aggregate(&signatures).is_empty());
+        }
 
-                self
-            }
+        #[test]
+        fn signature_aggregate() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (0..4)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect::<Vec<_>>();
+            let refs = signatures.iter().collect::<Vec<_>>();
+
+            assert!(Signature::aggregate(&[]).is_empty());
+            assert_eq!(
+                Signature::aggregate(&refs[..1]),
+                AggregateSignature::aggregate(&signatures[..1])
+            );
+            assert_eq!(
+                Signature::aggregate(&refs),
+                AggregateSignature::aggregate(&signatures)
+            );
+        }
+
+        #[test]
+        fn aggregate_matches_add_assign() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (0..4)
+                .map(|i| secret_from_u64(i).sign(msg))
+                .collect::<Vec<_>>();
+
+            let mut expected = AggregateSignature::infinity();
+            for signature in &signatures {
+                expected.add_assign(signature);
+            }
+
+            assert_eq!(AggregateSignature::aggregate(&signatures), expected);
+        }
+
+        #[test]
+        fn aggregate_signature_string_round_trip() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = AggregateSignature::aggregate(&[secret_from_u64(0).sign(msg)]);
+
+            let string = signature.to_string();
+            assert_eq!(
+                string,
+                format!("0x{}", hex::encode(&signature.serialize()[..]))
+            );
+            assert_eq!(string.parse::<AggregateSignature>(), Ok(signature));
+        }
+
+        #[test]
+        fn empty_aggregate_signature_string_round_trip() {
+            let signature = AggregateSignature::empty();
+
+            let string = signature.to_string();
+            assert_eq!(string, format!("0x{}", "00".repeat(96)));
+            assert_eq!(string.parse::<AggregateSignature>(), Ok(signature));
+        }
+
+        #[test]
+        fn aggregate_signature_from_str_errors() {
+            assert_eq!(
+                "0x0000".parse::<AggregateSignature>(),
+                Err(Error::InvalidByteLength {
+                    got: 2,
+                    expected: 96
+                })
+            );
+            assert!(matches!(
+                "00".repeat(96).parse::<AggregateSignature>(),
+                Err(Error::InvalidHex(_))
+            ));
+            assert!(matches!(
+                "0xzz".parse::<AggregateSignature>(),
+                Err(Error::InvalidHex(_))
+            ));
+        }
+
+        #[cfg(feature = "unstable-internals")]
+        #[test]
+        fn aggregate_signature_raw_round_trip() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = AggregateSignature::aggregate(&[secret_from_u64(0).sign(msg)]);
+            let raw = signature.as_raw().unwrap().clone();
+            assert_eq!(AggregateSignature::from_raw(raw), signature);
+
+            let infinity = AggregateSignature::infinity();
+            let raw = infinity.as_raw().unwrap().clone();
+            assert_eq!(AggregateSignature::from_raw(raw), infinity);
+
+            assert!(AggregateSignature::empty().as_raw().is_none());
+        }
+
+        #[test]
+        fn add_assign_multiple_matches_add_assign() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = vec![
+                secret_from_u64(0).sign(msg),
+                Signature::empty(),
+                secret_from_u64(1).sign(msg),
+            ];
+            let signature_refs = signatures.iter().collect::<Vec<_>>();
+
+            for initial in &[AggregateSignature::empty(), AggregateSignature::infinity()] {
+                let mut expected = initial.clone();
+                for signature in &signatures {
+                    expected.add_assign(signature);
+                }
+
+                let mut aggregate = initial.clone();
+                aggregate.add_assign_multiple(&signature_refs);
+                assert_eq!(aggregate, expected);
+            }
+
+            let mut aggregate = AggregateSignature::empty();
+            aggregate.add_assign_multiple(&[]);
+            assert!(aggregate.is_empty());
+        }
+
+        #[test]
+        fn serde_formats_round_trip() {
+            let msg = Hash256::from_low_u64_be(42);
+            let sk = secret_from_u64(0);
+            let pubkey = sk.public_key();
+            let signature = sk.sign(msg);
+            let aggregate = AggregateSignature::aggregate(&[signature.clone()]);
+
+            // Human-readable formats use 0x-prefixed hex.
+            let json = serde_json::to_string(&signature).unwrap();
+            assert_eq!(
+                json,
+                format!("\"0x{}\"", hex::encode(&signature.serialize()[..]))
+            );
+            assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+            let json = serde_json::to_string(&aggregate).unwrap();
+            assert_eq!(
+                serde_json::from_str::<AggregateSignature>(&json).unwrap(),
+                aggregate
+            );
+            let json = serde_json::to_string(&pubkey).unwrap();
+            assert_eq!(serde_json::from_str::<PublicKey>(&json).unwrap(), pubkey);
+
+            // Other formats use the raw bytes, plus bincode's 8-byte length prefix.
+            let bytes = bincode::serialize(&signature).unwrap();
+            assert_eq!(bytes.len(), 8 + 96);
+            assert_eq!(
+                bincode::deserialize::<Signature>(&bytes).unwrap(),
+                signature
+            );
+            let bytes = bincode::serialize(&aggregate).unwrap();
+            assert_eq!(
+                bincode::deserialize::<AggregateSignature>(&bytes).unwrap(),
+                aggregate
+            );
+            let bytes = bincode::serialize(&pubkey).unwrap();
+            assert_eq!(bytes.len(), 8 + 48);
+            assert_eq!(bincode::deserialize::<PublicKey>(&bytes).unwrap(), pubkey);
+        }
+
+        #[test]
+        fn signature_try_from_str() {
+            let signature = secret_from_u64(0).sign(Hash256::from_low_u64_be(42));
+            let hex = format!("0x{}", hex::encode(&signature.serialize()[..]));
+
+            assert_eq!(Signature::try_from(hex.as_str()), Ok(signature));
+            assert_eq!(
+                Signature::try_from(&hex[..hex.len() - 2]),
+                Err(Error::InvalidByteLength {
+                    got: 95,
+                    expected: 96
+                })
+            );
+            assert!(matches!(
+                Signature::try_from(&hex[2..]),
+                Err(Error::InvalidHex(_))
+            ));
+        }
+
+        #[test]
+        fn aggregate_signature_try_from_str() {
+            let aggregate = AggregateSignature::aggregate(&[
+                secret_from_u64(0).sign(Hash256::from_low_u64_be(42)),
+                secret_from_u64(1).sign(Hash256::from_low_u64_be(42)),
+            ]);
+            let hex = format!("0x{}", hex::encode(&aggregate.serialize()[..]));
+
+            assert_eq!(
+                AggregateSignature::try_from(hex.as_str()),
+                Ok(aggregate.clone())
+            );
+            assert_eq!(hex.parse::<AggregateSignature>(), Ok(aggregate));
+            assert_eq!(
+                AggregateSignature::try_from("0x00"),
+                Err(Error::InvalidByteLength {
+                    got: 1,
+                    expected: 96
+                })
+            );
+        }
+
+        #[test]
+        fn public_key_try_from_str() {
+            let pubkey = secret_from_u64(0).public_key();
+            let hex = format!("0x{}", hex::encode(&pubkey.serialize()[..]));
+
+            assert_eq!(PublicKey::try_from(hex.as_str()), Ok(pubkey));
+            assert_eq!(
+                PublicKey::try_from(&hex[..hex.len() - 2]),
+                Err(Error::InvalidByteLength {
+                    got: 47,
+                    expected: 48
+                })
+            );
+        }
+
+        #[test]
+        fn secret_key_try_from_str() {
+            let sk = secret_from_u64(0);
+            let hex = format!("0x{}", hex::encode(sk.serialize().as_bytes()));
+
+            assert!(SecretKey::try_from(hex.as_str()).unwrap() == sk);
+            assert_eq!(
+                SecretKey::try_from(&hex[..hex.len() - 2]).err(),
+                Some(Error::InvalidByteLength {
+                    got: 31,
+                    expected: 32
+                })
+            );
+            assert!(matches!(
+                SecretKey::try_from("0xzz"),
+                Err(Error::InvalidHex(_))
+            ));
+        }
+
+        #[test]
+        fn aggregate_public_key_encodings_round_trip() {
+            let pubkeys = (0..3)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+            let agg_pubkey =
+                AggregatePublicKey::aggregate(&pubkeys.iter().collect::<Vec<_>>()).unwrap();
+            let bytes = agg_pubkey.serialize();
+            assert_eq!(bytes.len(), PUBLIC_KEY_BYTES_LEN);
+
+            let ssz = agg_pubkey.as_ssz_bytes();
+            assert_eq!(&ssz[..], &bytes[..]);
+            assert_eq!(
+                AggregatePublicKey::from_ssz_bytes(&ssz)
+                    .unwrap()
+                    .serialize(),
+                bytes
+            );
+
+            let json = serde_json::to_string(&agg_pubkey).unwrap();
+            assert_eq!(json, format!("\"0x{}\"", hex::encode(&bytes[..])));
+            assert_eq!(
+                serde_json::from_str::<AggregatePublicKey>(&json)
+                    .unwrap()
+                    .serialize(),
+                bytes
+            );
+
+            let string = agg_pubkey.to_string();
+            assert_eq!(
+                string.parse::<AggregatePublicKey>().unwrap().serialize(),
+                bytes
+            );
+            assert_eq!(
+                "0x0000".parse::<AggregatePublicKey>().err(),
+                Some(Error::InvalidByteLength {
+                    got: 2,
+                    expected: PUBLIC_KEY_BYTES_LEN
+                })
+            );
+        }
+
+        #[test]
+        fn aggregate_public_key_empty() {
+            assert_eq!(
+                AggregatePublicKey::aggregate(&[]).err(),
+                Some(Error::NoPublicKeys)
+            );
+        }
+
+        #[test]
+        fn fast_aggregate_verify_pre_aggregated() {
+            let msg = Hash256::from_low_u64_be(42);
+            let secret_keys = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secret_keys
+                .iter()
+                .map(|sk| sk.public_key())
+                .collect::<Vec<_>>();
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &secret_keys
+                    .iter()
+                    .map(|sk| sk.sign(msg))
+                    .collect::<Vec<_>>(),
+            );
+
+            let agg_pubkey = AggregatePublicKey::aggregate(&pubkey_refs).unwrap();
+            assert!(signature.fast_aggregate_verify_pre_aggregated(msg, &agg_pubkey));
+            assert!(!signature
+                .fast_aggregate_verify_pre_aggregated(Hash256::from_low_u64_be(43), &agg_pubkey));
+
+            let partial_agg_pubkey = AggregatePublicKey::aggregate(&pubkey_refs[1..]).unwrap();
+            assert!(!signature.fast_aggregate_verify_pre_aggregated(msg, &partial_agg_pubkey));
+        }
+
+        #[test]
+        fn fast_aggregate_verify_prepared() {
+            let msg = Hash256::from_low_u64_be(42);
+            let secret_keys = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secret_keys
+                .iter()
+                .map(|sk| sk.public_key())
+                .collect::<Vec<_>>();
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &secret_keys
+                    .iter()
+                    .map(|sk| sk.sign(msg))
+                    .collect::<Vec<_>>(),
+            );
+
+            let prepared = bls::PreparedMessage::new(msg, bls::DST);
+            assert!(signature.fast_aggregate_verify(msg, &pubkey_refs));
+            assert!(signature.fast_aggregate_verify_prepared(&prepared, &pubkey_refs));
+            assert!(!signature.fast_aggregate_verify_prepared(&prepared, &pubkey_refs[1..]));
+            assert!(!signature.fast_aggregate_verify_prepared(&prepared, &[]));
+
+            let other = bls::PreparedMessage::new(Hash256::from_low_u64_be(43), bls::DST);
+            assert!(!signature.fast_aggregate_verify_prepared(&other, &pubkey_refs));
+
+            let other_dst = bls::PreparedMessage::new(msg, b"BLS_SIG_OTHER_DST_");
+            assert!(!signature.fast_aggregate_verify_prepared(&other_dst, &pubkey_refs));
+        }
+
+        /// A point which is on the G1 curve, but not in the G1 subgroup.
+        ///
+        /// This is the input of the `deserialization_fails_not_in_G1` case from
+        /// ethereum/bls12-381-tests. `x` is the repeating pattern `0x0123456789abcdef` with the
+        /// compression flag set (and the infinity and sort flags clear); `x^3 + 4` is a square, so
+        /// the point is on the curve, but multiplying it by the curve order does not give the
+        /// point at infinity. See `blst::points_outside_subgroup_are_on_the_curve`.
+        const PUBKEY_NOT_IN_G1: &str = "8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+        /// A point which is on the G2 curve, but not in the G2 subgroup.
+        ///
+        /// This is the input of the `deserialization_fails_not_in_G2` case from
+        /// ethereum/bls12-381-tests, constructed as per `PUBKEY_NOT_IN_G1` over the 96 bytes of
+        /// `x.c1 || x.c0` (where the curve is `y^2 = x^3 + 4(u + 1)`).
+        const SIGNATURE_NOT_IN_G2: &str = "8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+        #[test]
+        fn aggregate_signature_clone_from() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = |i| AggregateSignature::aggregate(&[secret_from_u64(i).sign(msg)]);
+            let values = vec![
+                signature(0),
+                signature(1),
+                AggregateSignature::infinity(),
+                AggregateSignature::empty(),
+            ];
+
+            for source in &values {
+                for dst in &values {
+                    let mut dst = dst.clone();
+                    dst.clone_from(source);
+                    assert_eq!(&dst, source);
+                    assert_eq!(dst.is_infinity(), source.is_infinity());
+                }
+            }
+
+            let mut scratch = signature(0);
+            scratch.clone_from(&signature(1));
+            assert!(scratch.fast_aggregate_verify(msg, &[&secret_from_u64(1).public_key()]));
+        }
+
+        #[test]
+        fn backend_message_for_malformed_point() {
+            let signature_bytes = hex::decode(SIGNATURE_NOT_IN_G2).unwrap();
+            let error = Signature::deserialize_checked(&signature_bytes).unwrap_err();
+            assert_eq!(error.backend_error(), Some($not_in_group_error));
+            assert_eq!(error.backend_message(), Some($not_in_group_error.message()));
+
+            let pubkey_bytes = hex::decode(PUBKEY_NOT_IN_G1).unwrap();
+            let error = PublicKey::deserialize_checked(&pubkey_bytes).unwrap_err();
+            assert_eq!(error.backend_error(), Some($not_in_group_error));
+
+            let error = Signature::deserialize(&[0; 95]).unwrap_err();
+            assert_eq!(error.backend_error(), None);
+            assert_eq!(error.backend_message(), None);
+        }
+
+        #[test]
+        fn from_bytes_checked() {
+            assert_eq!(
+                AggregateSignature::from_bytes_checked(NONE_SIGNATURE),
+                Ok(AggregateSignature::empty())
+            );
+            assert!(AggregateSignature::from_bytes_checked(INFINITY_SIGNATURE)
+                .unwrap()
+                .is_infinity());
+            assert_eq!(
+                AggregateSignature::from_bytes_unchecked(NONE_SIGNATURE),
+                Ok(AggregateSignature::empty())
+            );
+            assert_eq!(
+                AggregateSignature::from_bytes_unchecked(INFINITY_SIGNATURE),
+                Ok(AggregateSignature::infinity())
+            );
+            assert!(AggregateSignature::from_bytes_unchecked([0xff; 96]).is_err());
+
+            let mut signature_bytes = [0; 96];
+            signature_bytes.copy_from_slice(&hex::decode(SIGNATURE_NOT_IN_G2).unwrap());
+            assert!(AggregateSignature::from_bytes_checked(signature_bytes).is_err());
+
+            let pubkey = secret_from_u64(42).public_key();
+            assert_eq!(
+                PublicKey::from_bytes_checked(pubkey.serialize()),
+                Ok(pubkey)
+            );
+            assert!(PublicKey::from_bytes_checked(INFINITY_PUBLIC_KEY)
+                .unwrap()
+                .is_infinity());
+        }
+
+        #[test]
+        fn deserialize_checked_rejects_points_outside_subgroup() {
+            let pubkey_bytes = hex::decode(PUBKEY_NOT_IN_G1).unwrap();
+            assert!(PublicKey::deserialize_checked(&pubkey_bytes).is_err());
+
+            let signature_bytes = hex::decode(SIGNATURE_NOT_IN_G2).unwrap();
+            assert!(Signature::deserialize_checked(&signature_bytes).is_err());
+            assert!(AggregateSignature::deserialize_checked(&signature_bytes).is_err());
+        }
+
+        #[test]
+        fn deserialize_checked_accepts_valid_points() {
+            let tester = SignatureTester::default();
+            let pubkey_bytes = tester.pubkey.serialize();
+            let signature_bytes = tester.sig.serialize();
+
+            assert_eq!(
+                PublicKey::deserialize_checked(&pubkey_bytes).unwrap(),
+                tester.pubkey
+            );
+            assert_eq!(
+                Signature::deserialize_checked(&signature_bytes).unwrap(),
+                tester.sig
+            );
+            assert_eq!(
+                AggregateSignature::deserialize_checked(&signature_bytes).unwrap(),
+                AggregateSignature::deserialize(&signature_bytes).unwrap()
+            );
+            assert!(Signature::deserialize_checked(&INFINITY_SIGNATURE).is_ok());
+            assert!(AggregateSignature::deserialize_checked(&[0; 96])
+                .unwrap()
+                .is_empty());
+        }
+
+        /// A helper struct for composing tests via the builder pattern.
+        struct SignatureTester {
+            sig: Signature,
+            pubkey: PublicKey,
+            msg: Hash256,
+        }
+
+        impl Default for SignatureTester {
+            fn default() -> Self {
+                let secret = SecretKey::deserialize(&[42; 32]).unwrap();
+                let pubkey = secret.public_key();
+                let msg = Hash256::from_low_u64_be(42);
+
+                Self {
+                    sig: secret.sign(msg),
+                    pubkey,
+                    msg,
+                }
+            }
+        }
+
+        impl SignatureTester {
+            pub fn infinity_sig(mut self) -> Self {
+                self.sig = Signature::deserialize(&INFINITY_SIGNATURE[..]).unwrap();
+                self
+            }
+
+            pub fn infinity_pubkey(mut self) -> Self {
+                self.pubkey = PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap();
+                self
+            }
+
+            pub fn assert_verify(self, is_valid: bool) {
+                assert_eq!(self.sig.verify(&self.pubkey, self.msg), is_valid);
+
+                // Check a single-signature signature set.
+                assert_eq!(
+                    SignatureSet::single_pubkey(&self.sig, Cow::Borrowed(&self.pubkey), self.msg,)
+                        .verify(),
+                    is_valid
+                )
+            }
+        }
+
+        #[test]
+        fn standard_signature_is_valid_with_standard_pubkey() {
+            SignatureTester::default().assert_verify(true)
+        }
+
+        #[test]
+        fn infinity_signature_is_valid_with_infinity_pubkey() {
+            SignatureTester::default()
+                .infinity_sig()
+                .infinity_pubkey()
+                .assert_verify(true)
+        }
+
+        #[test]
+        fn infinity_signature_is_invalid_with_standard_pubkey() {
+            SignatureTester::default()
+                .infinity_sig()
+                .assert_verify(false)
+        }
+
+        #[test]
+        fn standard_signature_is_invalid_with_infinity_pubkey() {
+            SignatureTester::default()
+                .infinity_pubkey()
+                .assert_verify(false)
+        }
+
+        #[test]
+        fn pubkey_with_invalid_flag_bits() {
+            let valid = SignatureTester::default().pubkey.serialize();
+
+            let mut without_c_flag = valid;
+            without_c_flag[0] &= 0x7f;
+
+            let mut infinity_with_b_flag = INFINITY_PUBLIC_KEY;
+            infinity_with_b_flag[0] |= 0x20;
+
+            let mut infinity_with_x_nonzero = INFINITY_PUBLIC_KEY;
+            infinity_with_x_nonzero[47] = 1;
+
+            let mut infinity_with_high_x_bits_nonzero = INFINITY_PUBLIC_KEY;
+            infinity_with_high_x_bits_nonzero[0] |= 0x01;
+
+            let mut infinity_without_c_flag = INFINITY_PUBLIC_KEY;
+            infinity_without_c_flag[0] &= 0x7f;
+
+            for bytes in &[
+                without_c_flag,
+                infinity_with_b_flag,
+                infinity_with_x_nonzero,
+                infinity_with_high_x_bits_nonzero,
+                infinity_without_c_flag,
+                [0; 48],
+            ] {
+                assert_eq!(PublicKey::deserialize(bytes), Err(Error::InvalidFlagBits));
+                assert_eq!(
+                    PublicKey::deserialize_checked(bytes),
+                    Err(Error::InvalidFlagBits)
+                );
+                assert_eq!(
+                    PublicKeyBytes::deserialize(bytes).unwrap().decompress(),
+                    Err(Error::InvalidFlagBits)
+                );
+            }
+
+            assert!(PublicKey::deserialize(&valid).is_ok());
+        }
+
+        #[test]
+        fn standard_pubkey_is_not_infinity() {
+            assert!(!SignatureTester::default().pubkey.is_infinity())
+        }
+
+        #[test]
+        fn verify_with_byte_slice_message() {
+            let tester = AggregateSignatureTester::new_with_single_msg(4);
+            let msg = tester.msgs[0];
+            let msg_bytes: &[u8] = msg.as_bytes();
+            let pubkeys = tester.pubkeys.iter().collect::<Vec<_>>();
+
+            assert!(tester.sig.fast_aggregate_verify(msg_bytes, &pubkeys));
+            assert!(tester
+                .sig
+                .aggregate_verify(&vec![msg_bytes; pubkeys.len()], &pubkeys));
+
+            let tester = SignatureTester::default();
+            assert!(tester.sig.verify(&tester.pubkey, tester.msg.as_bytes()));
+        }
+
+        /// A helper struct for composing tests via the builder pattern.
+        struct AggregateSignatureTester {
+            sig: AggregateSignature,
+            pubkeys: Vec<PublicKey>,
+            msgs: Vec<Hash256>,
+        }
+
+        impl AggregateSignatureTester {
+            fn new_with_single_msg(num_pubkeys: u64) -> Self {
+                let mut pubkeys = Vec::with_capacity(num_pubkeys as usize);
+                let mut sig = AggregateSignature::infinity();
+                let msg = Hash256::from_low_u64_be(42);
+
+                for i in 0..num_pubkeys {
+                    let secret = secret_from_u64(i);
+                    pubkeys.push(secret.public_key());
+                    sig.add_assign(&secret.sign(msg));
+                }
+
+                Self {
+                    sig,
+                    pubkeys,
+                    msgs: vec![msg],
+                }
+            }
+
+            pub fn empty_sig(mut self) -> Self {
+                self.sig = AggregateSignature::empty();
+                self
+            }
+
+            pub fn wrong_sig(mut self) -> Self {
+                let sk = SecretKey::deserialize(&[1; 32]).unwrap();
+                self.sig = AggregateSignature::infinity();
+                self.sig.add_assign(&sk.sign(Hash256::from_low_u64_be(1)));
+                self
+            }
+
+            pub fn infinity_sig(mut self) -> Self {
+                self.sig = AggregateSignature::deserialize(&INFINITY_SIGNATURE[..]).unwrap();
+                self
+            }
+
+            pub fn no_sigs(mut self) -> Self {
+                self.sig = AggregateSignature::aggregate(&Vec::<Signature>::new());
+                self
+            }
+
+            pub fn no_sigs_from_infinity(mut self) -> Self {
+                self.sig = AggregateSignature::infinity();
+                self
+            }
+
+            pub fn aggregate_empty_sig(mut self) -> Self {
+                self.sig.add_assign(&Signature::empty());
+                self
+            }
+
+            pub fn aggregate_empty_agg_sig(mut self) -> Self {
+                self.sig.add_assign_aggregate(&AggregateSignature::empty());
+                self
+            }
+
+            pub fn aggregate_infinity_sig(mut self) -> Self {
+                self.sig
+                    .add_assign(&Signature::deserialize(&INFINITY_SIGNATURE[..]).unwrap());
+                self
+            }
+
+            pub fn single_infinity_pubkey(mut self) -> Self {
+                self.pubkeys = vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap()];
+                self
+            }
+
+            pub fn push_infinity_pubkey(mut self) -> Self {
+                self.pubkeys
+                    .push(PublicKey::deserialize(&INFINITY_PUBLIC_KEY[..]).unwrap());
+                self
+            }
+
+            pub fn assert_single_message_verify(self, is_valid: bool) {
+                assert!(self.msgs.len() == 1);
+                let msg = self.msgs.first().unwrap();
+                let pubkeys = self.pubkeys.iter().collect::<Vec<_>>();
+
+                assert_eq!(
+                    self.sig.fast_aggregate_verify(*msg, &pubkeys),
+                    is_valid,
+                    "fast_aggregate_verify expected {} but got {}",
+                    is_valid,
+                    !is_valid
+                );
+
+                let msgs = pubkeys.iter().map(|_| msg.clone()).collect::<Vec<_>>();
+
+                assert_eq!(
+                    self.sig.aggregate_verify(&msgs, &pubkeys),
+                    is_valid,
+                    "aggregate_verify expected {} but got {}",
+                    is_valid,
+                    !is_valid
+                );
+            }
+        }
+
+        /// An aggregate without any signatures should not verify.
+        #[test]
+        fn fast_aggregate_verify_0_pubkeys() {
+            AggregateSignatureTester::new_with_single_msg(0).assert_single_message_verify(false)
+        }
+
+        /// An aggregate of size 1 should verify.
+        #[test]
+        fn fast_aggregate_verify_1_pubkey() {
+            AggregateSignatureTester::new_with_single_msg(1).assert_single_message_verify(true)
+        }
+
+        /// An aggregate of size 128 should verify.
+        #[test]
+        fn fast_aggregate_verify_128_pubkeys() {
+            AggregateSignatureTester::new_with_single_msg(128).assert_single_message_verify(true)
+        }
+
+        /// The infinity signature should not verify against 1 non-infinity pubkey.
+        #[test]
+        fn fast_aggregate_verify_infinity_signature_with_1_regular_public_key() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .infinity_sig()
+                .assert_single_message_verify(false)
+        }
+
+        /// The infinity signature should not verify against 128 non-infinity pubkeys.
+        #[test]
+        fn fast_aggregate_verify_infinity_signature_with_128_regular_public_keys() {
+            AggregateSignatureTester::new_with_single_msg(128)
+                .infinity_sig()
+                .assert_single_message_verify(false)
+        }
+
+        /// The infinity signature and one infinity pubkey should verify.
+        #[test]
+        fn fast_aggregate_verify_infinity_signature_with_one_infinity_pubkey() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .infinity_sig()
+                .single_infinity_pubkey()
+                .assert_single_message_verify(true)
+        }
+
+        /// Adding a infinity signature (without an infinity pubkey) should verify.
+        #[test]
+        fn fast_aggregate_verify_with_one_aggregated_infinity_sig() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .aggregate_infinity_sig()
+                .assert_single_message_verify(true)
+        }
+
+        /// Adding four infinity signatures (without any infinity pubkeys) should verify.
+        #[test]
+        fn fast_aggregate_verify_with_four_aggregated_infinity_sig() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .aggregate_infinity_sig()
+                .aggregate_infinity_sig()
+                .aggregate_infinity_sig()
+                .aggregate_infinity_sig()
+                .assert_single_message_verify(true)
+        }
+
+        /// Adding a infinity pubkey and an infinity signature should verify.
+        #[test]
+        fn fast_aggregate_verify_with_one_additional_infinity_pubkey_and_matching_sig() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .aggregate_infinity_sig()
+                .push_infinity_pubkey()
+                .assert_single_message_verify(true)
+        }
+
+        /// Adding a single infinity pubkey **without** updating the signature **should verify**.
+        #[test]
+        fn fast_aggregate_verify_with_one_additional_infinity_pubkey() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .push_infinity_pubkey()
+                .assert_single_message_verify(true)
+        }
+
+        /// Adding multiple infinity pubkeys **without** updating the signature **should verify**.
+        #[test]
+        fn fast_aggregate_verify_with_four_additional_infinity_pubkeys() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .push_infinity_pubkey()
+                .push_infinity_pubkey()
+                .push_infinity_pubkey()
+                .push_infinity_pubkey()
+                .assert_single_message_verify(true)
+        }
+
+        /// As per the Eth2 spec, an aggregate of zero signatures should not verify against a
+        /// non-empty set of public keys.
+        #[test]
+        fn fast_aggregate_verify_aggregate_of_zero_signatures() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .no_sigs()
+                .assert_single_message_verify(false)
+        }
+
+        /// Aggregating zero signatures onto the infinity signature should not verify against a
+        /// non-empty set of public keys.
+        #[test]
+        fn fast_aggregate_verify_infinity_aggregate_of_zero_signatures() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .no_sigs_from_infinity()
+                .assert_single_message_verify(false);
+            AggregateSignatureTester::new_with_single_msg(128)
+                .no_sigs_from_infinity()
+                .assert_single_message_verify(false)
+        }
+
+        /// An aggregate of zero signatures should not verify against zero public keys.
+        #[test]
+        fn fast_aggregate_verify_aggregate_of_zero_signatures_with_0_pubkeys() {
+            AggregateSignatureTester::new_with_single_msg(0)
+                .no_sigs()
+                .assert_single_message_verify(false)
+        }
+
+        /// The wrong signature should not verify.
+        #[test]
+        fn fast_aggregate_verify_wrong_signature() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .wrong_sig()
+                .assert_single_message_verify(false)
+        }
+
+        /// An "empty" signature should not verify.
+        #[test]
+        fn fast_aggregate_verify_empty_signature() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .empty_sig()
+                .assert_single_message_verify(false)
+        }
+
+        /// The checked variant should return the reason for a failed verification.
+        #[test]
+        fn verify_checked_errors() {
+            let sk = secret_from_u64(0);
+            let pubkey = sk.public_key();
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = sk.sign(msg);
+
+            assert_eq!(signature.verify_checked(&pubkey, msg), Ok(()));
+            assert_eq!(
+                signature.verify_checked(&pubkey, Hash256::from_low_u64_be(43)),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(
+                Signature::empty().verify_checked(&pubkey, msg),
+                Err(Error::EmptySignature)
+            );
+
+            let infinity_pubkey = PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap();
+            assert_eq!(
+                signature.verify_checked(&infinity_pubkey, msg),
+                Err(Error::InvalidInfinityPublicKey)
+            );
+            assert_eq!(
+                Signature::empty().verify_checked(&infinity_pubkey, msg),
+                Err(Error::InvalidInfinityPublicKey)
+            );
+        }
+
+        #[test]
+        fn public_key_verify_checked_errors() {
+            let sk = secret_from_u64(0);
+            let pubkey = sk.public_key();
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = sk.sign(msg);
+
+            assert!(pubkey.verify(&signature, msg));
+            assert_eq!(pubkey.verify_checked(&signature, msg), Ok(()));
+            assert_eq!(
+                pubkey.verify_checked(&signature, Hash256::from_low_u64_be(43)),
+                Err(Error::InvalidSignature)
+            );
+
+            let empty = Signature::deserialize(&NONE_SIGNATURE).unwrap();
+            assert!(!pubkey.verify(&empty, msg));
+            assert_eq!(
+                pubkey.verify_checked(&empty, msg),
+                Err(Error::EmptySignature)
+            );
+
+            let infinity_sig = Signature::deserialize(&INFINITY_SIGNATURE).unwrap();
+            assert!(!pubkey.verify(&infinity_sig, msg));
+            assert_eq!(
+                pubkey.verify_checked(&infinity_sig, msg),
+                Err(Error::InvalidSignature)
+            );
+
+            let infinity_pubkey = PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap();
+            assert!(infinity_pubkey.verify(&infinity_sig, msg));
+            assert_eq!(infinity_pubkey.verify_checked(&infinity_sig, msg), Ok(()));
+            assert!(!infinity_pubkey.verify(&signature, msg));
+            assert_eq!(
+                infinity_pubkey.verify_checked(&signature, msg),
+                Err(Error::InvalidInfinityPublicKey)
+            );
+        }
+
+        #[test]
+        fn fast_aggregate_verify_checked_errors() {
+            let tester = AggregateSignatureTester::new_with_single_msg(1);
+            let msg = tester.msgs[0];
+            let pubkeys = tester.pubkeys.iter().collect::<Vec<_>>();
+
+            assert_eq!(
+                tester.sig.fast_aggregate_verify_checked(msg, &pubkeys),
+                Ok(())
+            );
+            assert_eq!(
+                tester.sig.fast_aggregate_verify_checked(msg, &[]),
+                Err(Error::NoPublicKeys)
+            );
+            assert_eq!(
+                AggregateSignature::empty().fast_aggregate_verify_checked(msg, &pubkeys),
+                Err(Error::EmptyAggregate)
+            );
+
+            let tester = AggregateSignatureTester::new_with_single_msg(1).wrong_sig();
+            let pubkeys = tester.pubkeys.iter().collect::<Vec<_>>();
+            assert_eq!(
+                tester.sig.fast_aggregate_verify_checked(msg, &pubkeys),
+                Err(Error::InvalidSignature)
+            );
+        }
+
+        #[test]
+        fn fast_aggregate_verify_iter() {
+            let tester = AggregateSignatureTester::new_with_single_msg(4);
+            let msg = tester.msgs[0];
+            let pubkey_refs = tester.pubkeys.iter().collect::<Vec<_>>();
+
+            assert!(tester.sig.fast_aggregate_verify(msg, &pubkey_refs));
+            assert!(tester.sig.fast_aggregate_verify_iter(msg, &tester.pubkeys));
+            assert!(tester
+                .sig
+                .fast_aggregate_verify_iter(msg, tester.pubkeys.iter().rev()));
+            assert!(!tester
+                .sig
+                .fast_aggregate_verify_iter(msg, tester.pubkeys.iter().skip(1)));
+            assert!(!tester
+                .sig
+                .fast_aggregate_verify_iter(msg, std::iter::empty()));
+            assert!(!tester
+                .sig
+                .fast_aggregate_verify_iter(Hash256::from_low_u64_be(1), &tester.pubkeys));
+            assert!(!AggregateSignature::empty().fast_aggregate_verify_iter(msg, &tester.pubkeys));
+        }
+
+        #[test]
+        fn verify_report_for_wrong_pubkey() {
+            let msg = Hash256::from_low_u64_be(42);
+            let signature = AggregateSignature::aggregate(&[secret_from_u64(0).sign(msg)]);
+            let pubkey = secret_from_u64(1).public_key();
+
+            assert_eq!(
+                signature.verify_report(msg, &[&pubkey]),
+                VerifyReport {
+                    num_pubkeys: 1,
+                    has_infinity_pubkey: false,
+                    signature_is_empty: false,
+                    aggregate_pubkey: Some(pubkey.to_hex_string()),
+                    valid: false,
+                }
+            );
+            assert_eq!(
+                AggregateSignature::empty().verify_report(msg, &[]),
+                VerifyReport {
+                    num_pubkeys: 0,
+                    has_infinity_pubkey: false,
+                    signature_is_empty: true,
+                    aggregate_pubkey: None,
+                    valid: false,
+                }
+            );
+            assert!(
+                signature
+                    .verify_report(msg, &[&secret_from_u64(0).public_key()])
+                    .valid
+            );
+        }
+
+        #[test]
+        fn aggregate_verify_checked_errors() {
+            let tester = AggregateSignatureTester::new_with_single_msg(2);
+            let msgs = vec![tester.msgs[0]; 2];
+            let pubkeys = tester.pubkeys.iter().collect::<Vec<_>>();
+
+            assert_eq!(tester.sig.aggregate_verify_checked(&msgs, &pubkeys), Ok(()));
+            assert_eq!(
+                tester.sig.aggregate_verify_checked::<Hash256>(&[], &[]),
+                Err(Error::NoPublicKeys)
+            );
+            assert_eq!(
+                tester.sig.aggregate_verify_checked(&msgs[..1], &pubkeys),
+                Err(Error::InvalidMessageCount {
+                    got: 1,
+                    expected: 2
+                })
+            );
+            assert_eq!(
+                AggregateSignature::empty().aggregate_verify_checked(&msgs, &pubkeys),
+                Err(Error::EmptyAggregate)
+            );
+            assert!(!AggregateSignature::empty().aggregate_verify(&msgs, &pubkeys));
+            let tester = AggregateSignatureTester::new_with_single_msg(2).wrong_sig();
+            let pubkeys = tester.pubkeys.iter().collect::<Vec<_>>();
+            assert_eq!(
+                tester.sig.aggregate_verify_checked(&msgs, &pubkeys),
+                Err(Error::InvalidSignature)
+            );
+        }
+
+        #[test]
+        fn error_display() {
+            assert_eq!(
+                Error::EmptyAggregate.to_string(),
+                "cannot verify the empty aggregate signature (no signatures were added)"
+            );
+        }
+
+        /// Aggregating an "empty" signature should have no effect.
+        #[test]
+        fn fast_aggregate_verify_with_aggregated_empty_sig() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .aggregate_empty_sig()
+                .assert_single_message_verify(true)
+        }
+
+        /// Aggregating an "empty" aggregate signature should have no effect.
+        #[test]
+        fn fast_aggregate_verify_with_aggregated_empty_agg_sig() {
+            AggregateSignatureTester::new_with_single_msg(1)
+                .aggregate_empty_agg_sig()
+                .assert_single_message_verify(true)
+        }
+
+        /// A helper struct to make it easer to deal with `SignatureSet` lifetimes.
+        struct OwnedSignatureSet {
+            signature: AggregateSignature,
+            signing_keys: Vec<PublicKey>,
+            message: Hash256,
+            should_be_valid: bool,
+        }
+
+        impl OwnedSignatureSet {
+            pub fn multiple_pubkeys(&self) -> SignatureSet {
+                let signing_keys = self.signing_keys.iter().map(Cow::Borrowed).collect();
+                SignatureSet::multiple_pubkeys(&self.signature, signing_keys, self.message)
+            }
+
+            pub fn run_checks(&self) {
+                assert_eq!(
+                    self.multiple_pubkeys().verify(),
+                    self.should_be_valid,
+                    "multiple pubkey expected {} but got {}",
+                    self.should_be_valid,
+                    !self.should_be_valid
+                )
+            }
+        }
+
+        /// A helper struct for composing tests via the builder pattern.
+        #[derive(Default)]
+        struct SignatureSetTester {
+            owned_sets: Vec<OwnedSignatureSet>,
+        }
+
+        impl SignatureSetTester {
+            pub fn push_valid_set(mut self, num_signers: usize) -> Self {
+                let mut signature = AggregateSignature::infinity();
+                let message = Hash256::from_low_u64_be(42);
+
+                let signing_keys = (0..num_signers)
+                    .map(|i| {
+                        let secret = secret_from_u64(i as u64);
+                        signature.add_assign(&secret.sign(message));
+
+                        secret.public_key()
+                    })
+                    .collect();
+
+                self.owned_sets.push(OwnedSignatureSet {
+                    signature,
+                    signing_keys,
+                    message,
+                    should_be_valid: true,
+                });
+
+                self
+            }
 
             pub fn push_invalid_set(mut self) -> Self {
                 let mut signature = AggregateSignature::infinity();
                 let message = Hash256::from_low_u64_be(42);
 
-                signature.add_assign(&secret_from_u64(0).sign(message));
+                signature.add_assign(&secret_from_u64(0).sign(message));
+
+                self.owned_sets.push(OwnedSignatureSet {
+                    signature,
+                    signing_keys: vec![secret_from_u64(42).public_key()],
+                    message,
+                    should_be_valid: false,
+                });
+
+                self
+            }
 
+            pub fn push_invalid_sig_infinity_set(mut self) -> Self {
+                let mut signature = AggregateSignature::infinity();
+                signature.add_assign(&secret_from_u64(42).sign(Hash256::zero()));
                 self.owned_sets.push(OwnedSignatureSet {
                     signature,
+                    signing_keys: vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap()],
+                    message: Hash256::zero(),
+                    should_be_valid: false,
+                });
+                self
+            }
+
+            pub fn push_invalid_pubkey_infinity_set(mut self) -> Self {
+                self.owned_sets.push(OwnedSignatureSet {
+                    signature: AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
                     signing_keys: vec![secret_from_u64(42).public_key()],
-                    message,
+                    message: Hash256::zero(),
                     should_be_valid: false,
                 });
+                self
+            }
+
+            pub fn push_valid_infinity_set(mut self) -> Self {
+                self.owned_sets.push(OwnedSignatureSet {
+                    signature: AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
+                    signing_keys: vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap()],
+                    message: Hash256::zero(),
+                    should_be_valid: true,
+                });
+                self
+            }
+
+            pub fn run_checks(self) {
+                assert!(!self.owned_sets.is_empty(), "empty test is meaningless");
+
+                for owned_set in &self.owned_sets {
+                    owned_set.run_checks()
+                }
+
+                let should_be_valid = self
+                    .owned_sets
+                    .iter()
+                    .all(|owned_set| owned_set.should_be_valid);
+
+                let signature_sets = self
+                    .owned_sets
+                    .iter()
+                    .map(|owned_set| owned_set.multiple_pubkeys())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    verify_signature_sets(signature_sets.iter()),
+                    should_be_valid
+                );
+            }
+        }
+
+        #[test]
+        fn signature_set_1_valid_set_with_1_signer() {
+            SignatureSetTester::default().push_valid_set(1).run_checks()
+        }
+
+        #[test]
+        fn signature_set_1_invalid_set() {
+            SignatureSetTester::default()
+                .push_invalid_set()
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_1_valid_set_with_2_signers() {
+            SignatureSetTester::default().push_valid_set(2).run_checks()
+        }
+
+        #[test]
+        fn signature_set_1_valid_set_with_128_signers() {
+            SignatureSetTester::default()
+                .push_valid_set(128)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_2_valid_set_with_one_signer_each() {
+            SignatureSetTester::default()
+                .push_valid_set(1)
+                .push_valid_set(1)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_2_valid_set_with_2_signers_each() {
+            SignatureSetTester::default()
+                .push_valid_set(2)
+                .push_valid_set(2)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_2_valid_set_with_1_invalid_set() {
+            SignatureSetTester::default()
+                .push_valid_set(2)
+                .push_invalid_set()
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_1_valid_set_with_1_infinity_set() {
+            SignatureSetTester::default()
+                .push_valid_infinity_set()
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_3_sets_with_one_valid_infinity_set() {
+            SignatureSetTester::default()
+                .push_valid_set(2)
+                .push_valid_infinity_set()
+                .push_valid_set(2)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_3_sets_with_one_invalid_pubkey_infinity_set() {
+            SignatureSetTester::default()
+                .push_valid_set(2)
+                .push_invalid_pubkey_infinity_set()
+                .push_valid_set(2)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_set_3_sets_with_one_invalid_sig_infinity_set() {
+            SignatureSetTester::default()
+                .push_valid_set(2)
+                .push_invalid_sig_infinity_set()
+                .push_valid_set(2)
+                .run_checks()
+        }
+
+        #[test]
+        fn signature_batch_empty_is_invalid() {
+            assert!(!SignatureBatch::new().verify());
+        }
+
+        #[test]
+        fn signature_batch_attributes_invalid_entries() {
+            let message = Hash256::from_low_u64_be(42);
+            let secrets = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = secrets.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let signing_keys = || pubkeys.iter().map(Cow::Borrowed).collect::<Vec<_>>();
+
+            // All signers sign the same message.
+            let mut common = AggregateSignature::infinity();
+            for secret in &secrets {
+                common.add_assign(&secret.sign(message));
+            }
+
+            // Each signer signs a distinct message.
+            let distinct_messages = (0..4).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+            let mut distinct = AggregateSignature::infinity();
+            for (secret, msg) in secrets.iter().zip(distinct_messages.iter()) {
+                distinct.add_assign(&secret.sign(*msg));
+            }
+
+            let mut batch = SignatureBatch::new();
+            batch.push(&common, vec![message], signing_keys());
+            batch.push(&distinct, distinct_messages, signing_keys());
+            assert_eq!(batch.verify_attributed(), Ok(()));
+            assert!(batch.verify());
+
+            let invalid = secret_from_u64(42).sign(message);
+            batch.push(&invalid, vec![message], vec![Cow::Borrowed(&pubkeys[0])]);
+            assert_eq!(
+                batch.verify_attributed(),
+                Err(Error::InvalidSignatures(vec![2]))
+            );
+            assert!(!batch.verify());
+        }
+
+        #[test]
+        fn signature_batch_max_fallback_items() {
+            let entries = (0..8)
+                .map(|i| {
+                    let sk = secret_from_u64(i);
+                    let message = Hash256::from_low_u64_be(i);
+                    // Half of the entries are signed across the wrong message.
+                    let signed = if i % 2 == 0 {
+                        message
+                    } else {
+                        Hash256::from_low_u64_be(i + 100)
+                    };
+                    (sk.sign(signed), sk.public_key(), message)
+                })
+                .collect::<Vec<_>>();
+            let batch = |max_fallback_items: Option<usize>| {
+                let mut batch = SignatureBatch::new();
+                if let Some(max_fallback_items) = max_fallback_items {
+                    batch = batch.with_max_fallback_items(max_fallback_items);
+                }
+                for (signature, pubkey, message) in &entries {
+                    batch.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+                }
+                batch
+            };
+            let invalid = vec![1, 3, 5, 7];
+
+            assert_eq!(
+                batch(None).verify_attributed(),
+                Err(Error::InvalidSignatures(invalid.clone()))
+            );
+            assert_eq!(
+                batch(Some(4)).verify_attributed(),
+                Err(Error::InvalidSignatures(invalid))
+            );
+            assert_eq!(
+                batch(Some(2)).verify_attributed(),
+                Err(Error::TooManyInvalid)
+            );
+            assert_eq!(
+                batch(Some(0)).verify_attributed(),
+                Err(Error::TooManyInvalid)
+            );
+            assert_eq!(
+                batch(Some(2)).verify_attributed_with_rng(&mut ChaCha20Rng::from_seed([42; 32])),
+                Err(Error::TooManyInvalid)
+            );
+            assert!(!batch(Some(2)).verify());
+
+            let mut valid = SignatureBatch::new().with_max_fallback_items(0);
+            for (signature, pubkey, message) in entries.iter().step_by(2) {
+                valid.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+            }
+            assert_eq!(valid.verify_attributed(), Ok(()));
+            assert!(valid.verify());
+            assert_eq!(
+                SignatureBatch::new()
+                    .with_max_fallback_items(0)
+                    .verify_attributed(),
+                Err(Error::InvalidSignatures(vec![]))
+            );
+        }
+
+        #[test]
+        fn verify_and_report_participation() {
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..5).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let committee = pubkeys.iter().collect::<Vec<_>>();
+            let bitfield =
+                |len: usize, set: &[usize]| (0..len).map(|i| set.contains(&i)).collect::<Vec<_>>();
+            let signature = |set: &[usize]| {
+                AggregateSignature::aggregate(
+                    &set.iter().map(|&i| keys[i].sign(msg)).collect::<Vec<_>>(),
+                )
+            };
+
+            let partial = [0, 2, 3];
+            assert_eq!(
+                signature(&partial).verify_and_report_participation(
+                    &committee,
+                    bitfield(5, &partial),
+                    msg
+                ),
+                Ok(bls::ParticipationReport {
+                    participants: 3,
+                    non_participants: 2,
+                    non_participant_indices: vec![1, 4],
+                })
+            );
+
+            let full = [0, 1, 2, 3, 4];
+            assert_eq!(
+                signature(&full).verify_and_report_participation(
+                    &committee,
+                    bitfield(5, &full),
+                    msg
+                ),
+                Ok(bls::ParticipationReport {
+                    participants: 5,
+                    non_participants: 0,
+                    non_participant_indices: vec![],
+                })
+            );
+
+            // The bits claim a member who did not sign.
+            assert_eq!(
+                signature(&partial).verify_and_report_participation(
+                    &committee,
+                    bitfield(5, &[0, 1, 2, 3]),
+                    msg
+                ),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(
+                signature(&partial).verify_and_report_participation(
+                    &committee,
+                    bitfield(5, &[]),
+                    msg
+                ),
+                Err(Error::NoPublicKeys)
+            );
+            assert_eq!(
+                signature(&partial).verify_and_report_participation(
+                    &committee,
+                    bitfield(4, &partial),
+                    msg
+                ),
+                Err(Error::InvalidBitfieldLength {
+                    got: 4,
+                    expected: 5
+                })
+            );
+        }
+
+        #[test]
+        fn fast_aggregate_verify_selected() {
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let committee = pubkeys.iter().collect::<Vec<_>>();
+            let bitfield = |set: &[usize]| {
+                (0..committee.len())
+                    .map(|i| set.contains(&i))
+                    .collect::<Vec<_>>()
+            };
+            let signature = |set: &[usize]| {
+                AggregateSignature::aggregate(
+                    &set.iter().map(|&i| keys[i].sign(msg)).collect::<Vec<_>>(),
+                )
+            };
+
+            // Empty.
+            assert_eq!(
+                signature(&[0]).fast_aggregate_verify_selected(msg, &committee, bitfield(&[])),
+                Ok(false)
+            );
+
+            // Full.
+            let full = [0, 1, 2, 3];
+            assert_eq!(
+                signature(&full).fast_aggregate_verify_selected(msg, &committee, bitfield(&full)),
+                Ok(true)
+            );
+
+            // Sparse.
+            let sparse = [1, 3];
+            assert_eq!(
+                signature(&sparse).fast_aggregate_verify_selected(
+                    msg,
+                    &committee,
+                    bitfield(&sparse)
+                ),
+                Ok(true)
+            );
+            assert_eq!(
+                signature(&sparse).fast_aggregate_verify_selected(msg, &committee, bitfield(&[1])),
+                Ok(false)
+            );
+
+            // Length mismatch.
+            assert_eq!(
+                signature(&full).fast_aggregate_verify_selected(
+                    msg,
+                    &committee[..3],
+                    bitfield(&full)
+                ),
+                Err(Error::InvalidBitfieldLength {
+                    got: 4,
+                    expected: 3
+                })
+            );
+        }
+
+        #[test]
+        fn aggregate_public_key_display() {
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..3).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &keys.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>(),
+            );
+
+            let agg_pubkey = AggregatePublicKey::aggregate(&pubkey_refs).unwrap();
+            assert!(signature.fast_aggregate_verify_pre_aggregated(msg, &agg_pubkey));
+            assert_eq!(
+                agg_pubkey.to_string(),
+                format!("0x{}", hex::encode(&agg_pubkey.serialize()[..]))
+            );
+            assert_eq!(format!("{:?}", agg_pubkey), agg_pubkey.to_string());
+        }
+
+        #[test]
+        fn verify_sync_committee() {
+            use bls::SYNC_COMMITTEE_SIZE;
+
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..SYNC_COMMITTEE_SIZE as u64)
+                .map(secret_from_u64)
+                .collect::<Vec<_>>();
+            let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let committee = pubkeys.iter().collect::<Vec<_>>();
+
+            // All participating.
+            let participation = vec![true; SYNC_COMMITTEE_SIZE];
+            let signature = AggregateSignature::aggregate(
+                &keys.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>(),
+            );
+            assert_eq!(
+                signature.verify_sync_committee(msg, &committee, participation.iter().copied()),
+                Ok(true)
+            );
+
+            // Single participant.
+            let mut participation = vec![false; SYNC_COMMITTEE_SIZE];
+            participation[7] = true;
+            let signature = AggregateSignature::aggregate(&[keys[7].sign(msg)]);
+            assert_eq!(
+                signature.verify_sync_committee(msg, &committee, participation.iter().copied()),
+                Ok(true)
+            );
+            assert_eq!(
+                signature.verify_sync_committee(
+                    Hash256::from_low_u64_be(43),
+                    &committee,
+                    participation.iter().copied()
+                ),
+                Ok(false)
+            );
+
+            // No participants.
+            let participation = vec![false; SYNC_COMMITTEE_SIZE];
+            assert_eq!(
+                AggregateSignature::infinity().verify_sync_committee(
+                    msg,
+                    &committee,
+                    participation.iter().copied()
+                ),
+                Ok(true)
+            );
+            assert_eq!(
+                signature.verify_sync_committee(msg, &committee, participation.iter().copied()),
+                Ok(false)
+            );
+
+            // Incorrect lengths.
+            assert_eq!(
+                signature.verify_sync_committee(msg, &committee, vec![false; 8]),
+                Err(Error::InvalidParticipationLength {
+                    got: 8,
+                    expected: SYNC_COMMITTEE_SIZE
+                })
+            );
+            assert_eq!(
+                signature.verify_sync_committee(
+                    msg,
+                    &committee[..8],
+                    participation.iter().copied()
+                ),
+                Err(Error::InvalidBitfieldLength {
+                    got: SYNC_COMMITTEE_SIZE,
+                    expected: 8
+                })
+            );
+        }
+
+        #[test]
+        fn fast_aggregate_verify_bytes() {
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..3).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkey_bytes = keys
+                .iter()
+                .map(|sk| sk.public_key().serialize())
+                .collect::<Vec<_>>();
+            let pubkey_byte_refs = pubkey_bytes.iter().map(|b| &b[..]).collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &keys.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>(),
+            );
+
+            assert_eq!(
+                signature.fast_aggregate_verify_bytes(msg, &pubkey_byte_refs),
+                Ok(true)
+            );
+            assert_eq!(
+                signature
+                    .fast_aggregate_verify_bytes(Hash256::from_low_u64_be(43), &pubkey_byte_refs),
+                Ok(false)
+            );
+            assert_eq!(
+                signature.fast_aggregate_verify_bytes(msg, &[&pubkey_bytes[0][..47]]),
+                Err(Error::InvalidByteLength {
+                    got: 47,
+                    expected: 48
+                })
+            );
+            assert_eq!(
+                signature.fast_aggregate_verify_bytes(msg, &[&INFINITY_PUBLIC_KEY[..]]),
+                Ok(false)
+            );
+        }
 
-                self
+        #[test]
+        fn verify_typed() {
+            fn import(verified: VerifiedSignature) -> [u8; 96] {
+                verified.signature().serialize()
             }
 
-            pub fn push_invalid_sig_infinity_set(mut self) -> Self {
-                let mut signature = AggregateSignature::infinity();
-                signature.add_assign(&secret_from_u64(42).sign(Hash256::zero()));
-                self.owned_sets.push(OwnedSignatureSet {
-                    signature,
-                    signing_keys: vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap()],
-                    message: Hash256::zero(),
-                    should_be_valid: false,
-                });
-                self
-            }
+            let msg = Hash256::from_low_u64_be(42);
+            let keys = (0..3).map(secret_from_u64).collect::<Vec<_>>();
+            let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+            let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &keys.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>(),
+            );
 
-            pub fn push_invalid_pubkey_infinity_set(mut self) -> Self {
-                self.owned_sets.push(OwnedSignatureSet {
-                    signature: AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
-                    signing_keys: vec![secret_from_u64(42).public_key()],
-                    message: Hash256::zero(),
-                    should_be_valid: false,
-                });
-                self
-            }
+            let verified = signature
+                .fast_aggregate_verify_typed(msg, &pubkey_refs)
+                .unwrap();
+            assert_eq!(import(verified), signature.serialize());
+            assert_eq!(
+                signature
+                    .fast_aggregate_verify_typed(Hash256::from_low_u64_be(43), &pubkey_refs)
+                    .err(),
+                Some(Error::InvalidSignature)
+            );
 
-            pub fn push_valid_infinity_set(mut self) -> Self {
-                self.owned_sets.push(OwnedSignatureSet {
-                    signature: AggregateSignature::deserialize(&INFINITY_SIGNATURE).unwrap(),
-                    signing_keys: vec![PublicKey::deserialize(&INFINITY_PUBLIC_KEY).unwrap()],
-                    message: Hash256::zero(),
-                    should_be_valid: true,
-                });
-                self
-            }
+            let msgs = (0..3).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &keys
+                    .iter()
+                    .zip(msgs.iter())
+                    .map(|(sk, msg)| sk.sign(*msg))
+                    .collect::<Vec<_>>(),
+            );
+            assert!(signature
+                .aggregate_verify_typed(&msgs, &pubkey_refs)
+                .is_some());
+            assert!(signature
+                .aggregate_verify_typed(&msgs[..2], &pubkey_refs)
+                .is_none());
+        }
 
-            pub fn run_checks(self) {
-                assert!(!self.owned_sets.is_empty(), "empty test is meaningless");
+        #[test]
+        fn blind_signature() {
+            let sk = secret_from_u64(42);
+            let msg = Hash256::from_low_u64_be(42);
+            let blinding = BlindingFactor::random_with_rng(&mut ChaCha20Rng::from_seed([42; 32]));
 
-                for owned_set in &self.owned_sets {
-                    owned_set.run_checks()
-                }
+            // The signer only receives the blinded message, which is not `H(msg)` (i.e., the
+            // signature across `msg` by the secret key `1`).
+            let blinded = blind::blind_message(msg, &blinding);
+            assert!(blinded.serialize() != secret_from_u64(0).sign(msg).serialize());
+            let blinded = BlindedMessage::deserialize(&blinded.serialize()).unwrap();
 
-                let should_be_valid = self
-                    .owned_sets
-                    .iter()
-                    .all(|owned_set| owned_set.should_be_valid);
+            let blinded_sig = sk.sign_blinded(&blinded);
+            assert!(!Signature::deserialize(&blinded_sig.serialize())
+                .unwrap()
+                .verify(&sk.public_key(), msg));
 
-                let signature_sets = self
-                    .owned_sets
-                    .iter()
-                    .map(|owned_set| owned_set.multiple_pubkeys())
-                    .collect::<Vec<_>>();
+            let signature = blind::unblind(&blinded_sig, &blinding);
+            assert_eq!(signature, sk.sign(msg));
+            assert!(signature.verify(&sk.public_key(), msg));
 
-                assert_eq!(
-                    verify_signature_sets(signature_sets.iter()),
-                    should_be_valid
-                );
-            }
+            let other = BlindingFactor::random_with_rng(&mut ChaCha20Rng::from_seed([43; 32]));
+            assert!(!blind::unblind(&blinded_sig, &other).verify(&sk.public_key(), msg));
         }
 
         #[test]
-        fn signature_set_1_valid_set_with_1_signer() {
-            SignatureSetTester::default().push_valid_set(1).run_checks()
+        fn blinding_factor_deserialize() {
+            assert_eq!(
+                BlindingFactor::deserialize(&[0; 32]).err(),
+                Some(Error::InvalidSecretKeyBytes)
+            );
+            assert_eq!(
+                BlindingFactor::deserialize(&[255; 32]).err(),
+                Some(Error::InvalidSecretKeyBytes)
+            );
+            assert_eq!(
+                BlindingFactor::deserialize(&[1; 31]).err(),
+                Some(Error::InvalidByteLength {
+                    got: 31,
+                    expected: 32
+                })
+            );
+            let blinding = BlindingFactor::deserialize(&[1; 32]).unwrap();
+            assert_eq!(blinding.serialize().as_bytes(), &[1; 32][..]);
         }
 
         #[test]
-        fn signature_set_1_invalid_set() {
-            SignatureSetTester::default()
-                .push_invalid_set()
-                .run_checks()
+        fn sign_bytes_and_verify_bytes() {
+            let sk = secret_from_u64(42);
+            let pubkey = sk.public_key();
+            let msg = b"an arbitrary-length payload, longer than thirty-two bytes";
+
+            let sig = sk.sign_bytes(msg);
+            assert!(sig.verify_bytes(&pubkey, msg));
+            assert!(!sig.verify_bytes(&pubkey, &msg[1..]));
+            assert!(!sig.verify_bytes(&secret_from_u64(43).public_key(), msg));
+            assert!(!Signature::empty().verify_bytes(&pubkey, msg));
         }
 
+        /// Signing the bytes of a root must match `SecretKey::sign`, so `sign_bytes` shares the
+        /// Eth2 ciphersuite (and its test vectors).
         #[test]
-        fn signature_set_1_valid_set_with_2_signers() {
-            SignatureSetTester::default().push_valid_set(2).run_checks()
+        fn sign_bytes_matches_sign_for_roots() {
+            let sk = secret_from_u64(42);
+            let root = Hash256::from_low_u64_be(42);
+
+            assert_eq!(sk.sign_bytes(root.as_bytes()), sk.sign(root));
+            assert!(sk
+                .sign(root)
+                .verify_bytes(&sk.public_key(), root.as_bytes()));
         }
 
         #[test]
-        fn signature_set_1_valid_set_with_128_signers() {
-            SignatureSetTester::default()
-                .push_valid_set(128)
-                .run_checks()
+        fn signature_versioned_round_trip() {
+            let sig = secret_from_u64(42).sign(Hash256::from_low_u64_be(42));
+            let bytes = sig.encode_versioned();
+
+            assert_eq!(bytes[0], SIGNATURE_ENVELOPE_VERSION);
+            assert_eq!(&bytes[1..], &sig.serialize()[..]);
+            assert_eq!(Signature::decode_versioned(&bytes), Ok(sig));
         }
 
         #[test]
-        fn signature_set_2_valid_set_with_one_signer_each() {
-            SignatureSetTester::default()
-                .push_valid_set(1)
-                .push_valid_set(1)
-                .run_checks()
+        fn signature_versioned_rejects_unknown_version() {
+            let sig = secret_from_u64(42).sign(Hash256::from_low_u64_be(42));
+            let mut bytes = sig.encode_versioned();
+            bytes[0] = 1;
+
+            assert_eq!(
+                Signature::decode_versioned(&bytes),
+                Err(Error::UnsupportedVersion(1))
+            );
+            assert_eq!(
+                Signature::decode_versioned(&bytes[..10]),
+                Err(Error::UnsupportedVersion(1))
+            );
+            assert_eq!(
+                Signature::decode_versioned(&sig.encode_versioned()[..10]),
+                Err(Error::InvalidByteLength {
+                    got: 10,
+                    expected: SIGNATURE_ENVELOPE_BYTES_LEN
+                })
+            );
+            assert_eq!(
+                Signature::decode_versioned(&[]),
+                Err(Error::InvalidByteLength {
+                    got: 0,
+                    expected: SIGNATURE_ENVELOPE_BYTES_LEN
+                })
+            );
         }
 
         #[test]
-        fn signature_set_2_valid_set_with_2_signers_each() {
-            SignatureSetTester::default()
-                .push_valid_set(2)
-                .push_valid_set(2)
-                .run_checks()
+        fn public_key_from_secret_key() {
+            let sk = secret_from_u64(42);
+            let pubkey = PublicKey::from(&sk);
+            assert_eq!(pubkey, sk.public_key());
+
+            for i in 0..3 {
+                let msg = Hash256::from_low_u64_be(i);
+                assert!(sk.sign(msg).verify(&pubkey, msg));
+            }
         }
 
         #[test]
-        fn signature_set_2_valid_set_with_1_invalid_set() {
-            SignatureSetTester::default()
-                .push_valid_set(2)
-                .push_invalid_set()
-                .run_checks()
+        fn aggregate_public_key_aggregate_many() {
+            let pubkeys = (0..6)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+            let committees = vec![
+                pubkeys[..3].iter().collect::<Vec<_>>(),
+                vec![],
+                pubkeys[3..].iter().collect::<Vec<_>>(),
+            ];
+
+            let results = AggregatePublicKey::aggregate_many(&committees);
+            assert_eq!(results.len(), 3);
+            for &i in &[0, 2] {
+                assert_eq!(
+                    results[i].as_ref().unwrap().serialize(),
+                    AggregatePublicKey::aggregate(&committees[i])
+                        .unwrap()
+                        .serialize()
+                );
+            }
+            assert_eq!(results[1].as_ref().err(), Some(&Error::NoPublicKeys));
+            assert!(AggregatePublicKey::aggregate_many(&[]).is_empty());
         }
 
         #[test]
-        fn signature_set_1_valid_set_with_1_infinity_set() {
-            SignatureSetTester::default()
-                .push_valid_infinity_set()
-                .run_checks()
+        fn public_key_verify_batch() {
+            let sk = secret_from_u64(42);
+            let pubkey = sk.public_key();
+            let msgs = (0..5).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+            let mut signatures = msgs.iter().map(|msg| sk.sign(*msg)).collect::<Vec<_>>();
+
+            fn items<'a>(
+                msgs: &[Hash256],
+                signatures: &'a [Signature],
+            ) -> Vec<(Hash256, &'a Signature)> {
+                msgs.iter().copied().zip(signatures.iter()).collect()
+            }
+
+            assert!(pubkey.verify_batch(&items(&msgs, &signatures)));
+            assert_eq!(pubkey.verify_batch_attributed(&items(&msgs, &signatures)), Ok(()));
+            assert!(!pubkey.verify_batch(&[]));
+
+            signatures[1] = secret_from_u64(43).sign(msgs[1]);
+            signatures[4] = sk.sign(msgs[0]);
+            assert!(!pubkey.verify_batch(&items(&msgs, &signatures)));
+            assert_eq!(
+                pubkey.verify_batch_attributed(&items(&msgs, &signatures)),
+                Err(Error::InvalidSignatures(vec![1, 4]))
+            );
         }
 
         #[test]
-        fn signature_set_3_sets_with_one_valid_infinity_set() {
-            SignatureSetTester::default()
-                .push_valid_set(2)
-                .push_valid_infinity_set()
-                .push_valid_set(2)
-                .run_checks()
+        fn public_key_verify_aggregate() {
+            let sk = secret_from_u64(42);
+            let pubkey = sk.public_key();
+            let msgs = (0..4).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+            let signature = AggregateSignature::aggregate(
+                &msgs.iter().map(|msg| sk.sign(*msg)).collect::<Vec<_>>(),
+            );
+
+            assert!(pubkey.verify_aggregate(&signature, &msgs));
+            assert!(!pubkey.verify_aggregate(&signature, &msgs[1..]));
+            assert!(!secret_from_u64(43)
+                .public_key()
+                .verify_aggregate(&signature, &msgs));
+            assert!(!pubkey.verify_aggregate(&signature, &[]));
+            assert!(!pubkey.verify_aggregate(&AggregateSignature::empty(), &msgs));
         }
 
         #[test]
-        fn signature_set_3_sets_with_one_invalid_pubkey_infinity_set() {
-            SignatureSetTester::default()
-                .push_valid_set(2)
-                .push_invalid_pubkey_infinity_set()
-                .push_valid_set(2)
-                .run_checks()
+        fn threshold_recovery() {
+            use num_bigint_dig::BigUint;
+
+            let r = BigUint::parse_bytes(
+                b"73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+                16,
+            )
+            .unwrap();
+            let to_scalar = |sk: &SecretKey| BigUint::from_bytes_be(sk.serialize().as_bytes());
+            let from_scalar = |scalar: &BigUint| {
+                let bytes = scalar.to_bytes_be();
+                let mut padded = vec![0; 32 - bytes.len()];
+                padded.extend_from_slice(&bytes);
+                SecretKey::deserialize(&padded).unwrap()
+            };
+
+            // A 3-of-5 split of `master`, using the polynomial `f(x) = master + a_1 x + a_2 x^2`.
+            let master = secret_from_u64(1000);
+            let coefficients = vec![
+                to_scalar(&master),
+                to_scalar(&secret_from_u64(2000)),
+                to_scalar(&secret_from_u64(3000)),
+            ];
+            let share = |x: u64| {
+                let mut y = BigUint::from(0_u64);
+                for coefficient in coefficients.iter().rev() {
+                    y = (y * BigUint::from(x) + coefficient) % &r;
+                }
+                from_scalar(&y)
+            };
+
+            let msg = Hash256::from_low_u64_be(42);
+            let signatures = (1..=5).map(|x| (x, share(x).sign(msg))).collect::<Vec<_>>();
+            let pubkeys = (1..=5)
+                .map(|x| (x, share(x).public_key()))
+                .collect::<Vec<_>>();
+
+            let expected_signature = master.sign(msg);
+            assert_eq!(
+                threshold::recover_signature(&signatures[..3], 3),
+                Ok(expected_signature.clone())
+            );
+            assert_eq!(
+                threshold::recover_signature(&signatures[2..], 3),
+                Ok(expected_signature)
+            );
+            assert_eq!(
+                threshold::recover_public_key(&pubkeys[1..4], 3),
+                Ok(master.public_key())
+            );
+
+            // Two shares are insufficient.
+            assert_eq!(
+                threshold::recover_signature(&signatures[..2], 3),
+                Err(Error::InsufficientShares {
+                    got: 2,
+                    threshold: 3
+                })
+            );
+            assert!(threshold::recover_signature(&signatures[..2], 2).unwrap() != master.sign(msg));
+
+            // Indices must be distinct and non-zero.
+            let duplicated = vec![
+                signatures[0].clone(),
+                signatures[1].clone(),
+                signatures[0].clone(),
+            ];
+            assert_eq!(
+                threshold::recover_signature(&duplicated, 3),
+                Err(Error::DuplicateShareIndex(1))
+            );
+            // A duplicate is rejected even when it is beyond the first `threshold` shares.
+            let mut trailing_duplicate = signatures[..3].to_vec();
+            trailing_duplicate.push(signatures[0].clone());
+            assert_eq!(
+                threshold::recover_signature(&trailing_duplicate, 3),
+                Err(Error::DuplicateShareIndex(1))
+            );
+            let mut trailing_duplicate = pubkeys[..3].to_vec();
+            trailing_duplicate.push(pubkeys[2].clone());
+            assert_eq!(
+                threshold::recover_public_key(&trailing_duplicate, 3),
+                Err(Error::DuplicateShareIndex(3))
+            );
+            let zero = vec![(0, signatures[0].1.clone()), signatures[1].clone()];
+            assert_eq!(
+                threshold::recover_signature(&zero, 2),
+                Err(Error::InvalidShareIndex(0))
+            );
+
+            // A stale share signing with another share's index is rejected, rather than
+            // recovering the wrong signature.
+            let signers = vec![
+                threshold::SignerShare::new(1, share(1)).unwrap(),
+                threshold::SignerShare::new(3, share(3)).unwrap(),
+                threshold::SignerShare::new(3, share(4)).unwrap(),
+            ];
+            let shares = signers
+                .iter()
+                .map(|signer| signer.sign(msg))
+                .collect::<Vec<_>>();
+            assert_eq!(signers[1].index(), 3);
+            assert_eq!(
+                threshold::recover_signature(&shares, 3),
+                Err(Error::DuplicateShareIndex(3))
+            );
+            assert_eq!(
+                threshold::recover_signature(&shares[..2], 2).map(|_| ()),
+                Ok(())
+            );
+            assert_eq!(
+                threshold::SignerShare::new(0, share(1)).err(),
+                Some(Error::InvalidShareIndex(0))
+            );
         }
 
         #[test]
-        fn signature_set_3_sets_with_one_invalid_sig_infinity_set() {
-            SignatureSetTester::default()
-                .push_valid_set(2)
-                .push_invalid_sig_infinity_set()
-                .push_valid_set(2)
-                .run_checks()
+        fn aggregate_verifier() {
+            let committee = (0..4)
+                .map(|i| secret_from_u64(i).public_key())
+                .collect::<Vec<_>>();
+            let other = secret_from_u64(4).public_key();
+
+            let sign = |i: u64, msg: u64| secret_from_u64(i).sign(Hash256::from_low_u64_be(msg));
+
+            let mut verifier = AggregateVerifier::new();
+            // The committee signs messages 1 and 2, so its aggregate is re-used.
+            for msg in 1..=2 {
+                for (i, pubkey) in committee.iter().enumerate() {
+                    verifier.push(&sign(i as u64, msg), pubkey, Hash256::from_low_u64_be(msg));
+                }
+            }
+            verifier.push(&sign(4, 3), &other, Hash256::from_low_u64_be(3));
+            assert!(verifier.finish());
+
+            let mut verifier = AggregateVerifier::new();
+            verifier.push(&sign(0, 1), &committee[0], Hash256::from_low_u64_be(1));
+            verifier.push(&sign(1, 2), &committee[1], Hash256::from_low_u64_be(1));
+            assert!(!verifier.finish());
+
+            let mut verifier = AggregateVerifier::new();
+            verifier.push(&sign(0, 1), &committee[0], Hash256::from_low_u64_be(1));
+            verifier.push(
+                &Signature::empty(),
+                &committee[1],
+                Hash256::from_low_u64_be(1),
+            );
+            assert!(!verifier.finish());
+
+            assert!(!AggregateVerifier::new().finish());
+        }
+
+        #[test]
+        fn signature_batch_verify_each() {
+            let items = (0..7)
+                .map(|i| {
+                    let sk = secret_from_u64(i);
+                    let message = Hash256::from_low_u64_be(i);
+                    // Items 1, 4 and 5 are signed across the wrong message.
+                    let signed = if i == 1 || i == 4 || i == 5 {
+                        Hash256::from_low_u64_be(i + 100)
+                    } else {
+                        message
+                    };
+                    (sk.sign(signed), sk.public_key(), message)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                SignatureBatch::verify_each(&items),
+                vec![true, false, true, true, false, false, true]
+            );
+            assert_eq!(SignatureBatch::verify_each(&items[2..4]), vec![true, true]);
+            assert!(SignatureBatch::verify_each(&[]).is_empty());
+        }
+
+        #[test]
+        fn signature_batch_verify_seeded() {
+            let mut items = (0..4)
+                .map(|i| {
+                    let sk = secret_from_u64(i);
+                    let message = Hash256::from_low_u64_be(i);
+                    (sk.sign(message), sk.public_key(), message)
+                })
+                .collect::<Vec<_>>();
+            assert!(SignatureBatch::verify_batch_seeded(&items, [42; 32]));
+            assert!(SignatureBatch::verify_batch_seeded(&items, [43; 32]));
+
+            // The seeded verification reaches the same outcome as an unseeded one, and the same
+            // seed always finds the same invalid entries.
+            items[2].2 = Hash256::from_low_u64_be(100);
+            assert!(!SignatureBatch::verify_batch_seeded(&items, [42; 32]));
+            assert!(!SignatureBatch::verify_batch_seeded(&items, [43; 32]));
+            assert_eq!(
+                SignatureBatch::verify_each(&items),
+                vec![true, true, false, true]
+            );
+
+            let mut batch = SignatureBatch::new();
+            for (signature, pubkey, message) in &items {
+                batch.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+            }
+            for _ in 0..2 {
+                assert_eq!(
+                    batch.verify_attributed_with_rng(&mut ChaCha20Rng::from_seed([42; 32])),
+                    Err(Error::InvalidSignatures(vec![2]))
+                );
+            }
+
+            assert!(!SignatureBatch::verify_batch_seeded(&[], [42; 32]));
+        }
+
+        #[test]
+        fn signature_batch_draws_from_given_rng() {
+            let items = (0..4)
+                .map(|i| {
+                    let sk = secret_from_u64(i);
+                    let message = Hash256::from_low_u64_be(i);
+                    (sk.sign(message), sk.public_key(), message)
+                })
+                .collect::<Vec<_>>();
+            let mut batch = SignatureBatch::new();
+            for (signature, pubkey, message) in &items {
+                batch.push(signature, vec![*message], vec![Cow::Borrowed(pubkey)]);
+            }
+
+            // The random scalars must come from the given RNG, otherwise a seeded verification is
+            // not reproducible.
+            let mut rng = CountingRng::new([42; 32]);
+            assert_eq!(batch.verify_attributed_with_rng(&mut rng), Ok(()));
+            assert!(rng.draws > 0);
         }
     };
 }
 
 mod blst {
-    test_suite!(blst_implementations);
+    test_suite!(blst_implementations, bls::BackendError::PointNotInGroup);
+
+    /// `blst` only checks that a point is on the curve when the subgroup check is skipped, so it
+    /// can show that the "not in subgroup" vectors are on the curve.
+    #[test]
+    fn points_outside_subgroup_are_on_the_curve() {
+        let pubkey_bytes = hex::decode(PUBKEY_NOT_IN_G1).unwrap();
+        assert!(PublicKey::deserialize(&pubkey_bytes).is_ok());
+        assert_eq!(
+            PublicKey::deserialize_checked(&pubkey_bytes),
+            Err(Error::InvalidSubgroup)
+        );
+
+        let signature_bytes = hex::decode(SIGNATURE_NOT_IN_G2).unwrap();
+        assert!(Signature::deserialize(&signature_bytes).is_ok());
+        assert_eq!(
+            Signature::deserialize_checked(&signature_bytes),
+            Err(Error::InvalidSubgroup)
+        );
+    }
+
+    // Milagro does not support uncompressed signatures, so they are only tested against `blst`.
+
+    #[test]
+    fn uncompressed_aggregate_signature_round_trip() {
+        let msg = Hash256::from_low_u64_be(42);
+        let signature = AggregateSignature::aggregate(&[
+            secret_from_u64(0).sign(msg),
+            secret_from_u64(1).sign(msg),
+        ]);
+
+        let bytes = signature.serialize_uncompressed();
+        assert_eq!(
+            AggregateSignature::deserialize_uncompressed(&bytes).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn uncompressed_infinity_aggregate_signature_round_trip() {
+        let signature = AggregateSignature::infinity();
+        let bytes = signature.serialize_uncompressed();
+        let decoded = AggregateSignature::deserialize_uncompressed(&bytes).unwrap();
+        assert_eq!(decoded, signature);
+        assert_eq!(decoded.serialize()[..], INFINITY_SIGNATURE[..]);
+    }
+
+    #[test]
+    fn uncompressed_empty_aggregate_signature_round_trip() {
+        let bytes = AggregateSignature::empty().serialize_uncompressed();
+        assert!(bytes.iter().all(|byte| *byte == 0));
+        assert!(AggregateSignature::deserialize_uncompressed(&bytes)
+            .unwrap()
+            .is_empty());
+    }
+
+    // Milagro does not support custom domain separation tags, so proofs of possession are only
+    // tested against `blst`.
+
+    #[test]
+    fn sign_with_eth2_dst_matches_sign() {
+        let sk = secret_from_u64(42);
+        let msg = Hash256::from_low_u64_be(42);
+        let signature = sk.sign_with_dst(msg.as_bytes(), bls::DST);
+        assert_eq!(signature, sk.sign(msg));
+        assert!(signature.verify(&sk.public_key(), msg));
+        assert!(signature.verify_with_dst(&sk.public_key(), msg.as_bytes(), bls::DST));
+    }
+
+    #[test]
+    fn sign_with_dst_is_domain_separated() {
+        let dst_a = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+        let dst_b = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_";
+        let sk = secret_from_u64(42);
+        let pk = sk.public_key();
+        let msg = b"cross-protocol message";
+
+        let signature = sk.sign_with_dst(msg, dst_a);
+        assert!(signature.verify_with_dst(&pk, msg, dst_a));
+        assert!(!signature.verify_with_dst(&pk, msg, dst_b));
+        assert!(!signature.verify_with_dst(&pk, msg, bls::DST));
+        assert!(!Signature::empty().verify_with_dst(&pk, msg, dst_a));
+    }
+
+    #[test]
+    fn sign_with_dst_rfc_9380_vector() {
+        // RFC 9380, Appendix J.10.1: `BLS12381G2_XMD:SHA-256_SSWU_RO_` with an empty message. The
+        // secret key `1` signs with `H(msg)` itself, so the signature is the RFC's `P`.
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let expected = "a5cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a";
+
+        let mut one = [0; 32];
+        one[31] = 1;
+        let sk = SecretKey::deserialize(&one).unwrap();
+        let signature = sk.sign_with_dst(b"", dst);
+
+        assert_eq!(hex::encode(&signature.serialize()[..]), expected);
+        assert!(signature.verify_with_dst(&sk.public_key(), b"", dst));
+        assert!(!signature.verify_bytes(&sk.public_key(), b""));
+    }
+
+    #[test]
+    fn fast_aggregate_verify_prepared_with_other_dst() {
+        let msg = Hash256::from_low_u64_be(42);
+        let secret_keys = (0..4).map(secret_from_u64).collect::<Vec<_>>();
+        let pubkeys = secret_keys
+            .iter()
+            .map(|sk| sk.public_key())
+            .collect::<Vec<_>>();
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+
+        // The signature and prepared message are consistent with each other, but neither uses the
+        // Eth2 `DST`, so the signature must not verify.
+        let signature = AggregateSignature::aggregate(
+            &secret_keys
+                .iter()
+                .map(|sk| sk.sign_with_dst(msg.as_bytes(), bls::POP_DST))
+                .collect::<Vec<_>>(),
+        );
+        let prepared = bls::PreparedMessage::new(msg, bls::POP_DST);
+        assert!(!signature.fast_aggregate_verify_prepared(&prepared, &pubkey_refs));
+    }
+
+    #[test]
+    fn proof_of_possession() {
+        let sk = secret_from_u64(42);
+        let pk = sk.public_key();
+        let pop = sk.sign_proof_of_possession();
+        assert!(pk.verify_proof_of_possession(&pop));
+    }
+
+    #[test]
+    fn proof_of_possession_wrong_pubkey() {
+        let pop = secret_from_u64(42).sign_proof_of_possession();
+        let other_pk = secret_from_u64(43).public_key();
+        assert!(!other_pk.verify_proof_of_possession(&pop));
+    }
+
+    #[test]
+    fn proof_of_possession_is_not_a_message_signature() {
+        let sk = secret_from_u64(42);
+        let pk = sk.public_key();
+        let pubkey_bytes = pk.serialize();
+
+        let pop = sk.sign_proof_of_possession();
+        assert!(!pop.verify(&pk, &pubkey_bytes[..]));
+
+        let sig = sk.sign(Hash256::from_low_u64_be(42));
+        assert!(!pk.verify_proof_of_possession(&sig));
+    }
+
+    #[test]
+    fn backend_error_for_each_malformed_encoding() {
+        use bls::BackendError;
+
+        let backend_error = |bytes: &[u8]| {
+            Signature::deserialize_checked(bytes)
+                .unwrap_err()
+                .backend_error()
+        };
+
+        // A non-canonical signature is rejected before it reaches the backend.
+        let mut without_c_flag = secret_from_u64(42)
+            .sign(Hash256::from_low_u64_be(42))
+            .serialize();
+        without_c_flag[0] &= 0x7f;
+        assert_eq!(backend_error(&without_c_flag), None);
+
+        // `x` is equal to the field modulus.
+        let mut x_equal_to_p = [0; 48];
+        x_equal_to_p.copy_from_slice(
+            &hex::decode("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab")
+                .unwrap(),
+        );
+        x_equal_to_p[0] |= 0x80;
+        assert_eq!(
+            PublicKey::deserialize(&x_equal_to_p)
+                .unwrap_err()
+                .backend_error(),
+            Some(BackendError::BadEncoding)
+        );
+
+        // `x = 1` is not the x-coordinate of a point on either curve.
+        let mut not_on_curve = [0; 96];
+        not_on_curve[0] = 0x80;
+        not_on_curve[95] = 1;
+        assert_eq!(
+            backend_error(&not_on_curve),
+            Some(BackendError::PointNotOnCurve)
+        );
+        let mut pubkey_not_on_curve = [0; 48];
+        pubkey_not_on_curve[0] = 0x80;
+        pubkey_not_on_curve[47] = 1;
+        assert_eq!(
+            PublicKey::deserialize(&pubkey_not_on_curve)
+                .unwrap_err()
+                .backend_error(),
+            Some(BackendError::PointNotOnCurve)
+        );
+
+        let not_in_group = hex::decode(SIGNATURE_NOT_IN_G2).unwrap();
+        assert_eq!(
+            backend_error(&not_in_group),
+            Some(BackendError::PointNotInGroup)
+        );
+        assert_eq!(
+            Signature::deserialize_checked(&not_in_group)
+                .unwrap_err()
+                .backend_message(),
+            Some("point is not in the prime-order subgroup")
+        );
+
+        assert_eq!(Error::NoPublicKeys.backend_error(), None);
+        assert_eq!(Error::NoPublicKeys.backend_message(), None);
+    }
+
+    #[test]
+    fn message_augmentation() {
+        use bls::scheme::{sign_augmented, verify_augmented, AUG_DST};
+
+        let sk = secret_from_u64(42);
+        let pk = sk.public_key();
+        let other_pk = secret_from_u64(43).public_key();
+        let msg = b"partner protocol message";
+
+        let signature = sign_augmented(&sk, msg);
+        assert!(verify_augmented(&signature, &pk, msg));
+        assert!(!verify_augmented(&signature, &pk, b"other message"));
+        assert!(!verify_augmented(&signature, &other_pk, msg));
+        assert!(!verify_augmented(&Signature::empty(), &pk, msg));
+
+        // The augmented signature is not valid under the other schemes.
+        assert!(!signature.verify_with_dst(&pk, msg, AUG_DST));
+        assert!(!signature.verify_with_dst(&pk, msg, bls::DST));
+        assert_ne!(signature, sk.sign_with_dst(msg, bls::DST));
+    }
+
+    #[test]
+    fn message_augmentation_known_answer() {
+        // The IRTF BLS signature draft does not publish vectors for the `AUG` ciphersuite, so this
+        // was computed with an independent implementation of RFC 9380 hash-to-curve which
+        // reproduces Appendix J.10.1 and the EF `sign` vectors.
+        let pk = "8ce3b57b791798433fd323753489cac9bca43b98deaafaed91f4cb010730ae1e38b186ccd37a09b8aed62ce23b699c48";
+        let expected = "a76bb236a3e3704f5bd0357d5ce898a1f0a21e5beca2abdeec73a4dcab9f72af56571c75999fb132aa30fd74ab53353c03716ce558e2f5d623f11140755a9efe7401db2b04c17c390617c816e02ffbbabfb840fa624b7fb16495bb927ee535a9";
+        let mut secret_bytes = [0; 32];
+        secret_bytes[31] = 42;
+        let sk = SecretKey::deserialize(&secret_bytes).unwrap();
+        let msg = b"abc";
+
+        assert_eq!(hex::encode(&sk.public_key().serialize()[..]), pk);
+        let signature = bls::scheme::sign_augmented(&sk, msg);
+        assert_eq!(hex::encode(&signature.serialize()[..]), expected);
+        assert!(bls::scheme::verify_augmented(
+            &signature,
+            &sk.public_key(),
+            msg
+        ));
+    }
+}
+
+#[test]
+fn signing_root_vectors() {
+    let vectors = [
+        (
+            Hash256::zero(),
+            Hash256::zero(),
+            "f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b",
+        ),
+        (
+            Hash256::repeat_byte(1),
+            Hash256::repeat_byte(2),
+            "f818afd37a6dc3bc92fb44731011277006db4efa6e9023cd7468c02335d22a4d",
+        ),
+    ];
+
+    for (object_root, domain, expected) in &vectors {
+        assert_eq!(
+            hex::encode(bls::signing_root(*object_root, *domain)),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn verify_deposit_signature() {
+    use bls::blst_implementations::{SecretKey, Signature};
+
+    // The mainnet deposit domain, i.e., `ChainSpec::mainnet().get_deposit_domain()`.
+    let domain = Hash256::from_slice(
+        &hex::decode("03000000f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9").unwrap(),
+    );
+    let mut sk_bytes = [0; 32];
+    sk_bytes[31] = 42;
+    let sk = SecretKey::deserialize(&sk_bytes).unwrap();
+    let pubkey = sk.public_key();
+    let deposit_message_root = Hash256::repeat_byte(7);
+
+    let signature = sk.sign(bls::signing_root(deposit_message_root, domain));
+    assert_eq!(
+        bls::verify_deposit(&pubkey, &signature, deposit_message_root, domain),
+        Ok(())
+    );
+    assert_eq!(
+        bls::verify_deposit(&pubkey, &signature, Hash256::repeat_byte(8), domain),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        bls::verify_deposit(&pubkey, &signature, deposit_message_root, Hash256::zero()),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        bls::verify_deposit(&pubkey, &Signature::empty(), deposit_message_root, domain),
+        Err(Error::EmptySignature)
+    );
+}
+
+#[test]
+fn public_key_fingerprint_is_stable_across_backends() {
+    let sk_bytes = [42; 32];
+    let blst_pubkey = bls::blst_implementations::SecretKey::deserialize(&sk_bytes)
+        .unwrap()
+        .public_key();
+    let milagro_pubkey = bls::milagro_implementations::SecretKey::deserialize(&sk_bytes)
+        .unwrap()
+        .public_key();
+
+    assert_eq!(blst_pubkey.fingerprint(), milagro_pubkey.fingerprint());
+    assert_eq!(
+        blst_pubkey.fingerprint_hex(),
+        milagro_pubkey.fingerprint_hex()
+    );
+}
+
+#[test]
+fn active_backend_matches_features() {
+    let info = bls::active_backend();
+    assert_eq!(info.portable, cfg!(feature = "supranational-portable"));
+    if cfg!(feature = "fake_crypto") {
+        assert_eq!(info.name, "fake_crypto");
+    } else if cfg!(feature = "milagro") {
+        assert_eq!(info.name, "milagro");
+    } else if cfg!(feature = "mock_crypto") {
+        assert_eq!(info.name, "mock_crypto");
+    } else if info.portable {
+        assert_eq!(info.name, "blst-portable");
+    } else {
+        assert_eq!(info.name, "blst");
+    }
+    assert_eq!(bls::enabled_backend(), info.name);
+}
+
+#[test]
+fn hash_to_g2_rfc_9380_vector() {
+    // RFC 9380, Appendix J.10.1: `BLS12381G2_XMD:SHA-256_SSWU_RO_` with an empty message.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let expected = "a5cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a";
+
+    let point = bls::hash_to_g2(b"", dst);
+    assert_eq!(hex::encode(&point.serialize()[..]), expected);
+    assert_eq!(
+        bls::G2Point::deserialize(&point.serialize()).unwrap(),
+        point
+    );
+}
+
+#[test]
+fn hash_to_g2_matches_signing_with_one() {
+    let mut one = [0; 32];
+    one[31] = 1;
+    let msg = b"hash to curve";
+    let signature = bls::blst_implementations::SecretKey::deserialize(&one)
+        .unwrap()
+        .sign_bytes(msg);
+
+    assert_eq!(
+        &bls::hash_to_g2(msg, bls::DST).serialize()[..],
+        &signature.serialize()[..]
+    );
 }
 
 #[cfg(not(debug_assertions))]
 mod milagro {
-    test_suite!(milagro_implementations);
+    test_suite!(milagro_implementations, bls::BackendError::InvalidPoint);
+
+    /// `milagro` raises `AmclError::InvalidPoint` for every point it cannot decode, rather than
+    /// distinguishing the reason.
+    #[test]
+    fn backend_error_for_each_malformed_encoding() {
+        use bls::BackendError;
+        use milagro_bls::AmclError;
+
+        let pubkey_error = |bytes: &[u8]| PublicKey::deserialize(bytes).unwrap_err();
+        let signature_error = |bytes: &[u8]| Signature::deserialize_checked(bytes).unwrap_err();
+
+        // A non-canonical signature is rejected before it reaches the backend.
+        let mut without_c_flag = secret_from_u64(42)
+            .sign(Hash256::from_low_u64_be(42))
+            .serialize();
+        without_c_flag[0] &= 0x7f;
+        assert_eq!(signature_error(&without_c_flag).backend_error(), None);
+
+        // `x` is equal to the field modulus.
+        let mut x_equal_to_p = [0; 48];
+        x_equal_to_p.copy_from_slice(
+            &hex::decode("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab")
+                .unwrap(),
+        );
+        x_equal_to_p[0] |= 0x80;
+
+        // `x = 1` is not the x-coordinate of a point on either curve.
+        let mut pubkey_not_on_curve = [0; 48];
+        pubkey_not_on_curve[0] = 0x80;
+        pubkey_not_on_curve[47] = 1;
+        let mut signature_not_on_curve = [0; 96];
+        signature_not_on_curve[0] = 0x80;
+        signature_not_on_curve[95] = 1;
+
+        let pubkey_not_in_group = hex::decode(PUBKEY_NOT_IN_G1).unwrap();
+        let signature_not_in_group = hex::decode(SIGNATURE_NOT_IN_G2).unwrap();
+
+        for error in &[
+            pubkey_error(&x_equal_to_p),
+            pubkey_error(&pubkey_not_on_curve),
+            pubkey_error(&pubkey_not_in_group),
+            signature_error(&signature_not_on_curve),
+            signature_error(&signature_not_in_group),
+        ] {
+            assert_eq!(*error, Error::MilagroError(AmclError::InvalidPoint));
+            assert_eq!(error.backend_error(), Some(BackendError::InvalidPoint));
+            assert_eq!(
+                error.backend_message(),
+                Some("point is not on the curve or not in the subgroup")
+            );
+        }
+    }
+}
+
+#[cfg(feature = "test_backends")]
+mod test_backends {
+    use super::*;
+    use bls::blst_implementations::SecretKey;
+    use bls::test_backends::assert_backends_agree;
+
+    #[test]
+    fn backends_agree() {
+        let sk = SecretKey::deserialize(&[42; 32]).unwrap();
+        let pubkey = sk.public_key().serialize();
+        let msg = Hash256::from_low_u64_be(42);
+        let signature = sk.sign(msg).serialize();
+
+        assert!(assert_backends_agree(&signature, msg, &pubkey));
+        assert!(!assert_backends_agree(
+            &signature,
+            Hash256::from_low_u64_be(43),
+            &pubkey
+        ));
+        assert!(!assert_backends_agree(&[0; 96], msg, &pubkey));
+        assert!(!assert_backends_agree(
+            &signature,
+            msg,
+            &INFINITY_PUBLIC_KEY
+        ));
+    }
+}
+
+#[cfg(feature = "arbitrary-valid")]
+mod arbitrary_valid {
+    use arbitrary::{Arbitrary, Unstructured};
+    use bls::blst_implementations::{
+        AggregateSignature, PublicKey, Signature, ValidAggregateSignature, ValidPublicKey,
+        ValidSignature,
+    };
+
+    #[test]
+    fn arbitrary_points_are_valid() {
+        let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        let u = &mut Unstructured::new(&data);
+
+        for _ in 0..8 {
+            let pubkey = ValidPublicKey::arbitrary(u).unwrap().0;
+            assert!(PublicKey::deserialize_checked(&pubkey.serialize()).is_ok());
+
+            let signature = ValidSignature::arbitrary(u).unwrap().0;
+            assert!(!signature.is_empty());
+            assert!(Signature::deserialize_checked(&signature.serialize()).is_ok());
+
+            let aggregate = ValidAggregateSignature::arbitrary(u).unwrap().0;
+            assert!(!aggregate.is_empty());
+            assert!(AggregateSignature::deserialize_checked(&aggregate.serialize()).is_ok());
+        }
+    }
+
+    #[test]
+    fn arbitrary_bytes_are_unchanged() {
+        // Enabling `arbitrary-valid` must not change the byte-based impls used by other crates.
+        let data = [0xff; 96];
+        assert!(PublicKey::arbitrary(&mut Unstructured::new(&data)).is_err());
+        assert!(Signature::arbitrary(&mut Unstructured::new(&data)).is_err());
+    }
+}
+
+#[cfg(feature = "lenient-hex")]
+mod lenient_hex {
+    use bls::blst_implementations::{AggregateSignature, PublicKey, SecretKey, Signature};
+    use bls::Hash256;
+
+    fn json(bytes: &[u8], prefix: &str) -> String {
+        format!("\"{}{}\"", prefix, hex::encode(bytes))
+    }
+
+    #[test]
+    fn accepts_optional_prefix() {
+        let sk = SecretKey::deserialize(&[42; 32]).unwrap();
+        let pubkey = sk.public_key();
+        let signature = sk.sign(Hash256::from_low_u64_be(42));
+        let mut aggregate = AggregateSignature::empty();
+        aggregate.add_assign(&signature);
+
+        for prefix in &["0x", ""] {
+            let json_pubkey = json(&pubkey.serialize(), prefix);
+            let json_signature = json(&signature.serialize(), prefix);
+            let json_aggregate = json(&aggregate.serialize(), prefix);
+
+            assert_eq!(
+                serde_json::from_str::<PublicKey>(&json_pubkey).unwrap(),
+                pubkey
+            );
+            assert_eq!(
+                serde_json::from_str::<Signature>(&json_signature).unwrap(),
+                signature
+            );
+            assert_eq!(
+                serde_json::from_str::<AggregateSignature>(&json_aggregate).unwrap(),
+                aggregate
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_hex() {
+        let signature = SecretKey::deserialize(&[42; 32])
+            .unwrap()
+            .sign(Hash256::from_low_u64_be(42));
+        let bare = hex::encode(&signature.serialize()[..]);
+
+        // Odd-length hex.
+        assert!(serde_json::from_str::<Signature>(&format!("\"{}0\"", bare)).is_err());
+        // Incorrect byte length.
+        assert!(serde_json::from_str::<Signature>(&format!("\"{}\"", &bare[2..])).is_err());
+        // Only a single prefix is stripped.
+        assert!(serde_json::from_str::<Signature>(&format!("\"0x0x{}\"", bare)).is_err());
+    }
+}
+
+#[cfg(feature = "pubkey-cache")]
+mod pubkey_cache {
+    use bls::blst_implementations::{
+        AggregatePublicKey, AggregatePublicKeyCache, PublicKey, SecretKey,
+    };
+    use bls::Error;
+    use std::borrow::Cow;
+
+    fn pubkeys(count: u8) -> Vec<PublicKey> {
+        (0..count)
+            .map(|i| SecretKey::deserialize(&[i + 1; 32]).unwrap().public_key())
+            .collect()
+    }
+
+    #[test]
+    fn cached_matches_fresh() {
+        let cache = AggregatePublicKeyCache::new(2);
+        let pubkeys = pubkeys(12);
+        let get_pubkey = |i: usize| pubkeys.get(i).map(Cow::Borrowed);
+        let committees = vec![
+            (0..4).collect::<Vec<_>>(),
+            (4..8).collect(),
+            (8..12).collect(),
+        ];
+
+        for _ in 0..2 {
+            for committee in &committees {
+                let refs = committee.iter().map(|&i| &pubkeys[i]).collect::<Vec<_>>();
+                let fresh = AggregatePublicKey::aggregate(&refs).unwrap();
+                assert_eq!(
+                    cache
+                        .get_or_aggregate(committee, get_pubkey)
+                        .unwrap()
+                        .serialize(),
+                    fresh.serialize()
+                );
+
+                // The order of the indices does not matter.
+                let reversed = committee.iter().rev().copied().collect::<Vec<_>>();
+                assert_eq!(
+                    cache
+                        .get_or_aggregate(&reversed, get_pubkey)
+                        .unwrap()
+                        .serialize(),
+                    fresh.serialize()
+                );
+            }
+        }
+
+        // The least-recently used committee was evicted.
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn cache_hit_does_not_read_pubkeys() {
+        let cache = AggregatePublicKeyCache::new(1);
+        let pubkeys = pubkeys(4);
+        let fresh = cache
+            .get_or_aggregate(&[0, 1, 2, 3], |i| pubkeys.get(i).map(Cow::Borrowed))
+            .unwrap();
+
+        let cached = cache
+            .get_or_aggregate(&[3, 2, 1, 0], |_| -> Option<Cow<'static, PublicKey>> {
+                panic!("public keys should not be read on a cache hit")
+            })
+            .unwrap();
+        assert_eq!(cached.serialize(), fresh.serialize());
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let cache = AggregatePublicKeyCache::new(2);
+        let pubkeys = pubkeys(1);
+        let get_pubkey = |i: usize| pubkeys.get(i).map(Cow::Borrowed);
+
+        assert_eq!(
+            cache.get_or_aggregate(&[], get_pubkey).err(),
+            Some(Error::NoPublicKeys)
+        );
+        assert_eq!(
+            cache.get_or_aggregate(&[0, 1], get_pubkey).err(),
+            Some(Error::UnknownValidator(1))
+        );
+        assert!(cache.is_empty());
+    }
+}
+
+#[cfg(feature = "mock_crypto")]
+mod mock_crypto {
+    use bls::mock_crypto_implementations::{AggregateSignature, SecretKey, Signature};
+    use bls::Hash256;
+
+    fn secret_key(i: u8) -> SecretKey {
+        SecretKey::deserialize(&[i; 32]).unwrap()
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let msg = Hash256::from_low_u64_be(42);
+        let signature = secret_key(1).sign(msg);
+
+        assert_eq!(signature, secret_key(1).sign(msg));
+        assert!(signature.verify(&secret_key(1).public_key(), msg));
+        assert!(!signature.verify(&secret_key(2).public_key(), msg));
+        assert!(!signature.verify(&secret_key(1).public_key(), Hash256::from_low_u64_be(43)));
+
+        let bytes = signature.serialize();
+        assert_eq!(Signature::deserialize(&bytes).unwrap(), signature);
+    }
+
+    #[test]
+    fn aggregate_verify() {
+        let msg = Hash256::from_low_u64_be(42);
+        let keys = (1..4).map(secret_key).collect::<Vec<_>>();
+        let pubkeys = keys.iter().map(|sk| sk.public_key()).collect::<Vec<_>>();
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+        let signatures = keys.iter().map(|sk| sk.sign(msg)).collect::<Vec<_>>();
+        let aggregate = AggregateSignature::aggregate(&signatures);
+
+        assert!(aggregate.fast_aggregate_verify(msg, &pubkey_refs));
+        assert!(!aggregate.fast_aggregate_verify(msg, &pubkey_refs[..2]));
+        assert!(!aggregate.fast_aggregate_verify(Hash256::from_low_u64_be(43), &pubkey_refs));
+        assert!(!AggregateSignature::aggregate(&signatures[..2])
+            .fast_aggregate_verify(msg, &pubkey_refs));
+
+        let msgs = (0..3).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+        let aggregate = AggregateSignature::aggregate(
+            &keys
+                .iter()
+                .zip(msgs.iter())
+                .map(|(sk, msg)| sk.sign(*msg))
+                .collect::<Vec<_>>(),
+        );
+        assert!(aggregate.aggregate_verify(&msgs, &pubkey_refs));
+        assert!(!aggregate.aggregate_verify(&msgs[..2], &pubkey_refs[..2]));
+    }
+
+    #[test]
+    fn duplicate_signatures_do_not_cancel() {
+        let msg = Hash256::from_low_u64_be(42);
+        let pubkey = secret_key(1).public_key();
+        let signature = secret_key(1).sign(msg);
+        let mut aggregate = AggregateSignature::aggregate(&[signature.clone(), signature.clone()]);
+
+        assert!(!aggregate.is_infinity());
+        assert!(aggregate.fast_aggregate_verify(msg, &[&pubkey, &pubkey]));
+        assert!(!aggregate.fast_aggregate_verify(msg, &[&pubkey]));
+
+        aggregate.sub_assign(&signature);
+        assert_eq!(aggregate, AggregateSignature::aggregate(&[signature]));
+    }
+
+    #[test]
+    fn random_secret_key_is_valid_scalar() {
+        for _ in 0..32 {
+            let bytes = SecretKey::random().serialize();
+            assert!(SecretKey::deserialize(bytes.as_bytes()).is_ok());
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec {
+    use bls::blst_implementations::SecretKey;
+    use bls::Hash256;
+
+    #[test]
+    fn serialize_smallvec_matches_serialize() {
+        let signature = SecretKey::deserialize(&[42; 32])
+            .unwrap()
+            .sign(Hash256::from_low_u64_be(42));
+        let bytes = signature.serialize_smallvec();
+
+        assert!(!bytes.spilled());
+        assert_eq!(&bytes[..], &signature.serialize()[..]);
+    }
+}
+
+#[cfg(feature = "track-count")]
+mod track_count {
+    use bls::blst_implementations::{AggregateSignatureWithCount, SecretKey, Signature};
+    use bls::Hash256;
+
+    #[test]
+    fn count_matches_add_assign_calls() {
+        let msg = Hash256::from_low_u64_be(42);
+        let signatures = (1..=5)
+            .map(|i| SecretKey::deserialize(&[i; 32]).unwrap().sign(msg))
+            .collect::<Vec<_>>();
+
+        let mut counted = AggregateSignatureWithCount::infinity();
+        for (i, signature) in signatures.iter().enumerate() {
+            counted.add_assign(signature);
+            assert_eq!(counted.count(), i + 1);
+        }
+
+        counted.add_assign(&Signature::empty());
+        assert_eq!(counted.count(), signatures.len());
+
+        let mut other = AggregateSignatureWithCount::empty();
+        other.add_assign(&signatures[0]);
+        counted.add_assign_aggregate(&other);
+        assert_eq!(counted.count(), signatures.len() + 1);
+    }
+
+    #[test]
+    fn count_does_not_affect_serialization() {
+        let msg = Hash256::from_low_u64_be(42);
+        let secret_keys = (1..=3)
+            .map(|i| SecretKey::deserialize(&[i; 32]).unwrap())
+            .collect::<Vec<_>>();
+        let pubkeys = secret_keys
+            .iter()
+            .map(|sk| sk.public_key())
+            .collect::<Vec<_>>();
+        let pubkey_refs = pubkeys.iter().collect::<Vec<_>>();
+
+        let mut counted = AggregateSignatureWithCount::infinity();
+        for sk in &secret_keys {
+            counted.add_assign(&sk.sign(msg));
+        }
+
+        assert!(counted.fast_aggregate_verify(msg, &pubkey_refs));
+        let bytes = counted.serialize();
+        assert_eq!(counted.into_inner().serialize(), bytes);
+    }
+}
+
+mod serde_byte_array {
+    use bls::blst_implementations::{AggregateSignature, SecretKey, Signature, SignatureBytes};
+    use bls::Hash256;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Container {
+        #[serde(with = "bls::serde_byte_array")]
+        signature: Signature,
+        #[serde(with = "bls::serde_byte_array")]
+        aggregate: AggregateSignature,
+        #[serde(with = "bls::serde_byte_array")]
+        signature_bytes: SignatureBytes,
+        // Fields without the attribute are unaffected.
+        hex_signature: Signature,
+    }
+
+    fn container() -> Container {
+        let signature = SecretKey::deserialize(&[42; 32])
+            .unwrap()
+            .sign(Hash256::from_low_u64_be(42));
+        Container {
+            aggregate: AggregateSignature::aggregate(&[signature.clone()]),
+            signature_bytes: SignatureBytes::from(signature.clone()),
+            hex_signature: signature.clone(),
+            signature,
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let container = container();
+        let bytes = container.signature.serialize();
+
+        let json = serde_json::to_value(&container).unwrap();
+        let byte_array = serde_json::to_value(&bytes.to_vec()).unwrap();
+        assert_eq!(json["signature"], byte_array);
+        assert_eq!(json["aggregate"], byte_array);
+        assert_eq!(json["signature_bytes"], byte_array);
+        assert_eq!(
+            json["hex_signature"],
+            serde_json::Value::String(format!("0x{}", hex::encode(&bytes[..])))
+        );
+        assert_eq!(
+            serde_json::from_value::<Container>(json).unwrap(),
+            container
+        );
+    }
+
+    #[test]
+    fn accepts_hex() {
+        let container = container();
+        let hex = serde_json::Value::String(format!(
+            "0x{}",
+            hex::encode(&container.signature.serialize()[..])
+        ));
+
+        let mut json = serde_json::to_value(&container).unwrap();
+        json["signature"] = hex.clone();
+        json["aggregate"] = hex.clone();
+        json["signature_bytes"] = hex;
+        assert_eq!(
+            serde_json::from_value::<Container>(json).unwrap(),
+            container
+        );
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let container = container();
+        let encoded = bincode::serialize(&container).unwrap();
+        assert_eq!(encoded.len(), 4 * (8 + 96));
+        assert_eq!(
+            bincode::deserialize::<Container>(&encoded).unwrap(),
+            container
+        );
+    }
+
+    #[test]
+    fn rejects_incorrect_length() {
+        let mut json = serde_json::to_value(&container()).unwrap();
+        json["signature"] = serde_json::to_value(&vec![0_u8; 95]).unwrap();
+        assert!(serde_json::from_value::<Container>(json.clone()).is_err());
+        json["signature"] = serde_json::to_value(&vec![0_u8; 97]).unwrap();
+        assert!(serde_json::from_value::<Container>(json).is_err());
+    }
 }
@@ -278,8 +278,7 @@ impl Keystore {
 /// - If `secret` does not represent a point in the BLS curve.
 pub fn keypair_from_secret(secret: &[u8]) -> Result<Keypair, Error> {
     let sk = SecretKey::deserialize(secret).map_err(Error::InvalidSecretKeyBytes)?;
-    let pk = sk.public_key();
-    Ok(Keypair::from_components(pk, sk))
+    Ok(Keypair::from_secret_key(sk))
 }
 
 /// Returns `Kdf` used by default when creating keystores.